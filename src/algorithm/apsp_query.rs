@@ -0,0 +1,58 @@
+use super::{
+    distance_matrix::DistanceMatrix, measure::run_measure::Measure,
+    sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+};
+use crate::{graph::sp_graph::SpGraph, Weight};
+use std::rc::Rc;
+
+/// Answers the queries by looking up the distance matrix computed by an all-pairs shortest paths algorithm.
+pub struct ApspQuery {
+    matrix: Rc<DistanceMatrix>,
+}
+
+impl ApspQuery {
+    pub fn new<G: SpGraph>(graph: &G, matrix: Rc<DistanceMatrix>) -> Self {
+        assert_eq!(graph.num_nodes(), matrix.num_nodes());
+        Self { matrix }
+    }
+}
+
+impl<G: SpGraph> ShortestDistanceAlgorithm<G> for ApspQuery {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let solution = Solution::new(graph, source, sink);
+        if self.matrix.has_negative_cycle_from(source) {
+            return solution.negative_cycle();
+        }
+        match self.matrix.distance(source, sink) {
+            Weight::MAX => solution.not_connected(),
+            distance => solution.reached(distance),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let solution = Solution::new_one_to_all(graph, source);
+        match self.matrix.has_negative_cycle_from(source) {
+            false => solution.all_reached(self.matrix.row(source)),
+            true => solution.negative_cycle(),
+        }
+    }
+    fn run_cached_many_to_many<M: Measure>(
+        &mut self,
+        graph: &G,
+        sources: &[usize],
+        targets: &[usize],
+    ) -> Solution<M> {
+        let solution = Solution::new_many_to_many(graph, sources, targets);
+        if sources
+            .iter()
+            .any(|&source| self.matrix.has_negative_cycle_from(source))
+        {
+            return solution.negative_cycle();
+        }
+        let table: Vec<_> = sources
+            .iter()
+            .flat_map(|&source| targets.iter().map(move |&target| (source, target)))
+            .map(|(source, target)| self.matrix.distance(source, target))
+            .collect();
+        solution.table_reached(&table)
+    }
+}
@@ -0,0 +1,112 @@
+use super::{
+    measure::distances::Distances,
+    partition::Partition,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+    std_pq_dijkstra::StdPqDijkstra,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+
+/// Arc flags of the edges on a partition of the nodes into cells;
+/// the flag of an edge for a cell is set if the edge is on a shortest path to a node of the cell.
+///
+/// Edges are identified by their positions in the out edges of the graph, such that the `i`-th out edge of `node`
+/// is `edges_begin_index[node] + i`; flags of each edge are stored as a bitset of `words_per_edge` words.
+pub struct ArcFlags {
+    partition: Partition,
+    edges_begin_index: Vec<usize>,
+    words_per_edge: usize,
+    flags: Vec<u64>,
+}
+
+impl ArcFlags {
+    /// Flags of the edges within each cell are set; the remaining flags are set by a backward search from every boundary node,
+    /// a node with an incoming edge from another cell, flagging the edges on the shortest paths to it.
+    pub fn new<G: SpGraph>(graph: &G, partition: Partition) -> Self {
+        let num_nodes = graph.num_nodes();
+        let mut edges_begin_index = Vec::with_capacity(num_nodes + 1);
+        edges_begin_index.push(0);
+        for node in 0..num_nodes {
+            let num_edges = graph.out_edges(node).count_edges();
+            edges_begin_index.push(edges_begin_index[node] + num_edges);
+        }
+        let words_per_edge = partition.num_cells().div_ceil(64);
+        let mut arc_flags = Self {
+            edges_begin_index,
+            words_per_edge,
+            flags: vec![0; words_per_edge * graph.num_edges()],
+            partition,
+        };
+
+        let mut is_boundary = vec![false; num_nodes];
+        for tail in 0..num_nodes {
+            let cell = arc_flags.partition.cell(tail);
+            let mut out_edges = graph.out_edges(tail);
+            let mut edge_id = arc_flags.edges_begin_index[tail];
+            while let Some(edge) = out_edges.next_edge() {
+                match arc_flags.partition.cell(edge.head()) == cell {
+                    true => arc_flags.set(edge_id, cell),
+                    false => is_boundary[edge.head()] = true,
+                }
+                edge_id += 1;
+            }
+        }
+
+        let reversed = graph.reversed();
+        let mut backward = StdPqDijkstra::new(&reversed);
+        for boundary_node in (0..num_nodes).filter(|&node| is_boundary[node]) {
+            let solution: Solution<Distances> =
+                backward.run_cached_one_to_all(&reversed, boundary_node);
+            let distances = (solution.measure.distances).expect("dijkstra keeps distances");
+            arc_flags.set_tight_edges(graph, &distances, arc_flags.partition.cell(boundary_node));
+        }
+
+        arc_flags
+    }
+
+    pub fn cell(&self, node: usize) -> usize {
+        self.partition.cell(node)
+    }
+    pub fn first_edge(&self, node: usize) -> usize {
+        self.edges_begin_index[node]
+    }
+    #[inline(always)]
+    pub fn is_flagged(&self, edge_id: usize, cell: usize) -> bool {
+        let word = self.flags[edge_id * self.words_per_edge + cell / 64];
+        word & (1 << (cell % 64)) != 0
+    }
+    pub fn memory_bytes(&self) -> usize {
+        self.partition.memory_bytes()
+            + self.edges_begin_index.len() * std::mem::size_of::<usize>()
+            + self.flags.len() * std::mem::size_of::<u64>()
+    }
+
+    // helpers
+    fn set(&mut self, edge_id: usize, cell: usize) {
+        self.flags[edge_id * self.words_per_edge + cell / 64] |= 1 << (cell % 64);
+    }
+    // edge (u, v) is on a shortest path to the boundary node if d(u, b) = w(u, v) + d(v, b)
+    fn set_tight_edges<G: SpGraph>(&mut self, graph: &G, distances: &[Weight], cell: usize) {
+        for tail in 0..graph.num_nodes() {
+            if distances[tail] == Weight::MAX {
+                continue;
+            }
+            let mut out_edges = graph.out_edges(tail);
+            let mut edge_id = self.edges_begin_index[tail];
+            while let Some(edge) = out_edges.next_edge() {
+                let head_distance = distances[edge.head()];
+                if head_distance != Weight::MAX && head_distance + edge.weight() == distances[tail]
+                {
+                    self.set(edge_id, cell);
+                }
+                edge_id += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use super::{
+    arc_flags::ArcFlags, measure::run_measure::Measure, path::Predecessors,
+    sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use orx_priority_queue::PriorityQueue;
+use std::rc::Rc;
+
+/// Dijkstra's algorithm relaxing only the edges flagged for the cell of the sink;
+/// all edges are relaxed in one-to-all queries.
+pub struct ArcFlagsDijkstra<Pq>
+where
+    Pq: PriorityQueue<usize, Weight>,
+{
+    arc_flags: Rc<ArcFlags>,
+    queue: Pq,
+    distances: Vec<Weight>,
+    predecessors: Predecessors,
+}
+
+impl<Pq> ArcFlagsDijkstra<Pq>
+where
+    Pq: PriorityQueue<usize, Weight> + Default,
+{
+    pub fn new<G: SpGraph>(_: &G, arc_flags: Rc<ArcFlags>) -> Self {
+        Self {
+            arc_flags,
+            queue: Default::default(),
+            distances: Default::default(),
+            predecessors: Default::default(),
+        }
+    }
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: Option<usize>,
+        solution: &mut Solution<M>,
+    ) -> Option<Weight> {
+        // reset
+        self.distances
+            .iter_mut()
+            .take(graph.num_nodes())
+            .for_each(|x| *x = Weight::MAX);
+        if self.distances.len() < graph.num_nodes() {
+            self.distances
+                .extend((0..graph.num_nodes() - self.distances.len()).map(|_| Weight::MAX));
+        }
+        self.queue.clear();
+
+        // init
+        let sink_cell = sink.map(|sink| self.arc_flags.cell(sink));
+        self.distances[source] = 0;
+        self.queue.push(source, Weight::default());
+
+        // iterate
+        while let Some((position, cost)) = self.queue.pop() {
+            if Some(position) == sink {
+                return Some(cost);
+            }
+
+            if cost > self.distances[position] {
+                continue;
+            }
+
+            let mut out_edges = graph.out_edges(position);
+            let mut edge_id = self.arc_flags.first_edge(position);
+            while let Some(edge) = out_edges.next_edge() {
+                let flagged = sink_cell.is_none_or(|cell| self.arc_flags.is_flagged(edge_id, cell));
+                edge_id += 1;
+                if !flagged {
+                    continue;
+                }
+
+                let (head, weight) = (edge.head(), edge.weight());
+                let next_cost = cost + weight;
+                if next_cost < self.distances[head] {
+                    self.queue.push(head, next_cost);
+                    self.distances[head] = next_cost;
+                    self.predecessors.set(head, position, weight);
+                }
+            }
+
+            solution.iterate(self.queue.len(), self.queue.capacity());
+        }
+
+        None
+    }
+}
+
+impl<G, Pq> ShortestDistanceAlgorithm<G> for ArcFlagsDijkstra<Pq>
+where
+    G: SpGraph,
+    Pq: PriorityQueue<usize, Weight> + Default,
+{
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        match self.run_cached_core(graph, source, Some(sink), &mut solution) {
+            Some(distance) => solution.reached(distance),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let distance = self.run_cached_core(graph, source, Some(sink), &mut solution);
+        self.predecessors.stop_tracking();
+        match distance {
+            Some(distance) => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance, path)
+            }
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.run_cached_core(graph, source, None, &mut solution);
+        solution.all_reached(&self.distances[..graph.num_nodes()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::{
+        partition::Partition,
+        test_utils::{
+            assert_same_distances, assert_same_one_to_all_distances, random_coordinates,
+            random_graph, weights,
+        },
+    };
+    use orx_priority_queue::DaryHeap;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn arc_flags_dijkstra_matches_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+            num_cells in 1..80usize,
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let partitions = [
+                Partition::grid(&random_coordinates(seed, num_nodes), num_cells),
+                Partition::bfs_grown(&graph, num_cells),
+            ];
+            for partition in partitions {
+                let arc_flags = Rc::new(ArcFlags::new(&graph, partition));
+                let dijkstra = &mut ArcFlagsDijkstra::<DaryHeap<usize, Weight, 2>>::new(&graph, arc_flags);
+                assert_same_distances(&graph, queries_seed, dijkstra);
+                assert_same_one_to_all_distances(&graph, queries_seed, dijkstra);
+            }
+        }
+    }
+}
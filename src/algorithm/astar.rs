@@ -0,0 +1,123 @@
+use super::{
+    dijkstra_queue::DijkstraQueue, measure::run_measure::Measure, path::Predecessors,
+    potential::Potential, sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::rc::Rc;
+
+/// A* search where nodes are prioritized by the distance from the source
+/// plus the potential of the node which is a lower bound on the distance to the sink.
+pub struct AStar<Q: DijkstraQueue, P: Potential> {
+    potential: Rc<P>,
+    queue: Q,
+    distances: Vec<Weight>,
+    predecessors: Predecessors,
+}
+
+impl<Q: DijkstraQueue, P: Potential> AStar<Q, P> {
+    pub fn new<G: SpGraph>(graph: &G, potential: Rc<P>) -> Self {
+        Self {
+            potential,
+            queue: Q::for_graph(graph),
+            distances: Default::default(),
+            predecessors: Default::default(),
+        }
+    }
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: Option<usize>,
+        solution: &mut Solution<M>,
+    ) -> Option<Weight> {
+        // reset
+        self.distances
+            .iter_mut()
+            .take(graph.num_nodes())
+            .for_each(|x| *x = Weight::MAX);
+        if self.distances.len() < graph.num_nodes() {
+            self.distances
+                .extend((0..graph.num_nodes() - self.distances.len()).map(|_| Weight::MAX));
+        }
+        self.queue.clear();
+
+        // without a sink, the potential is zero and the search reduces to dijkstra's algorithm
+        let potential = |node| sink.map_or(0, |sink| self.potential.potential(node, sink));
+
+        // init
+        self.distances[source] = 0;
+        self.queue.relax(source, potential(source));
+
+        // iterate
+        while let Some((position, key)) = self.queue.pop() {
+            if Some(position) == sink {
+                return Some(self.distances[position]);
+            }
+
+            let cost = self.distances[position];
+            if key > cost + potential(position) {
+                continue;
+            }
+
+            let mut out_edges = graph.out_edges(position);
+            while let Some(edge) = out_edges.next_edge() {
+                let (head, weight) = (edge.head(), edge.weight());
+                let next_cost = cost + weight;
+                if next_cost < self.distances[head] {
+                    self.distances[head] = next_cost;
+                    self.predecessors.set(head, position, weight);
+                    self.queue.relax(head, next_cost + potential(head));
+                }
+            }
+
+            solution.iterate(self.queue.len(), self.queue.capacity());
+        }
+
+        None
+    }
+}
+
+// impl
+impl<G, Q, P> ShortestDistanceAlgorithm<G> for AStar<Q, P>
+where
+    G: SpGraph,
+    Q: DijkstraQueue,
+    P: Potential,
+{
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        match self.run_cached_core(graph, source, Some(sink), &mut solution) {
+            Some(distance) => solution.reached(distance),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let distance = self.run_cached_core(graph, source, Some(sink), &mut solution);
+        self.predecessors.stop_tracking();
+        match distance {
+            Some(distance) => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance, path)
+            }
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.run_cached_core(graph, source, None, &mut solution);
+        solution.all_reached(&self.distances[..graph.num_nodes()])
+    }
+}
@@ -0,0 +1,122 @@
+use super::{
+    measure::run_measure::Measure,
+    path::Predecessors,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+
+/// Bellman-Ford algorithm relaxing all edges of the reached nodes in rounds until no distance changes.
+///
+/// Without a negative cycle reachable from the source distances converge within `n - 1` rounds;
+/// hence, a change in the `n`-th round reveals a negative cycle.
+#[derive(Default)]
+pub struct BellmanFord {
+    distances: Vec<Weight>,
+    predecessors: Predecessors,
+}
+
+impl BellmanFord {
+    // computes the distances from the source to all nodes; returns false if a negative cycle is reachable
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        solution: &mut Solution<M>,
+    ) -> bool {
+        // reset
+        self.distances
+            .iter_mut()
+            .take(graph.num_nodes())
+            .for_each(|x| *x = Weight::MAX);
+        if self.distances.len() < graph.num_nodes() {
+            self.distances
+                .extend((0..graph.num_nodes() - self.distances.len()).map(|_| Weight::MAX));
+        }
+
+        // init
+        self.distances[source] = 0;
+
+        // iterate
+        for _ in 0..graph.num_nodes() {
+            let mut updated = false;
+            for position in 0..graph.num_nodes() {
+                let cost = self.distances[position];
+                if cost == Weight::MAX {
+                    continue;
+                }
+
+                let mut out_edges = graph.out_edges(position);
+                while let Some(edge) = out_edges.next_edge() {
+                    let (head, weight) = (edge.head(), edge.weight());
+                    let next_cost = cost + weight;
+                    if next_cost < self.distances[head] {
+                        self.distances[head] = next_cost;
+                        self.predecessors.set(head, position, weight);
+                        updated = true;
+                    }
+                }
+
+                solution.iterate(0, 0);
+            }
+
+            if !updated {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl<G: SpGraph> PureShortestDistanceAlgorithm<G> for BellmanFord {
+    fn new(_: &G) -> Self {
+        Self::default()
+    }
+}
+impl<G: SpGraph> ShortestDistanceAlgorithm<G> for BellmanFord {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        if !self.run_cached_core(graph, source, &mut solution) {
+            return solution.negative_cycle();
+        }
+        match self.distances[sink] {
+            Weight::MAX => solution.not_connected(),
+            distance => solution.reached(distance),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let converged = self.run_cached_core(graph, source, &mut solution);
+        self.predecessors.stop_tracking();
+        if !converged {
+            return solution.negative_cycle();
+        }
+        match self.distances[sink] {
+            Weight::MAX => solution.not_connected(),
+            distance => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance, path)
+            }
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        match self.run_cached_core(graph, source, &mut solution) {
+            true => solution.all_reached(&self.distances[..graph.num_nodes()]),
+            false => solution.negative_cycle(),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::{
+    measure::run_measure::Measure,
+    path::Predecessors,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::collections::VecDeque;
+
+/// Breadth-first search; nodes are settled in the order of their number of edges from the source,
+/// which is in the order of their distances only if all weights are equal.
+///
+/// Hence, only valid for graphs with unit weights.
+#[derive(Default)]
+pub struct Bfs {
+    queue: VecDeque<usize>,
+    distances: Vec<Weight>,
+    predecessors: Predecessors,
+}
+
+impl Bfs {
+    // runs until the sink is reached, or all reachable nodes are reached when the sink is None
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: Option<usize>,
+        solution: &mut Solution<M>,
+    ) -> Option<Weight> {
+        // reset
+        self.distances.clear();
+        self.distances.resize(graph.num_nodes(), Weight::MAX);
+        self.queue.clear();
+
+        // init
+        self.distances[source] = 0;
+        self.queue.push_back(source);
+        if Some(source) == sink {
+            return Some(0);
+        }
+
+        // iterate
+        while let Some(position) = self.queue.pop_front() {
+            let cost = self.distances[position];
+
+            let mut out_edges = graph.out_edges(position);
+            while let Some(edge) = out_edges.next_edge() {
+                let (head, weight) = (edge.head(), edge.weight());
+                if self.distances[head] == Weight::MAX {
+                    self.distances[head] = cost + weight;
+                    self.predecessors.set(head, position, weight);
+                    if Some(head) == sink {
+                        return Some(self.distances[head]);
+                    }
+                    self.queue.push_back(head);
+                }
+            }
+
+            solution.iterate(self.queue.len(), self.queue.capacity());
+        }
+
+        None
+    }
+}
+
+impl<G: SpGraph> PureShortestDistanceAlgorithm<G> for Bfs {
+    fn new(_: &G) -> Self {
+        Self::default()
+    }
+}
+impl<G: SpGraph> ShortestDistanceAlgorithm<G> for Bfs {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        match self.run_cached_core(graph, source, Some(sink), &mut solution) {
+            Some(distance) => solution.reached(distance),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let distance = self.run_cached_core(graph, source, Some(sink), &mut solution);
+        self.predecessors.stop_tracking();
+        match distance {
+            Some(distance) => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance, path)
+            }
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.run_cached_core(graph, source, None, &mut solution);
+        solution.all_reached(&self.distances[..graph.num_nodes()])
+    }
+}
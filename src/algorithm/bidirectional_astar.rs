@@ -0,0 +1,210 @@
+use super::{
+    bidirectional_dijkstra::{Search, SearchKey},
+    dijkstra_queue::DijkstraQueue,
+    measure::run_measure::Measure,
+    potential::Potential,
+    sd_algorithm::ShortestDistanceAlgorithm,
+    solution::Solution,
+};
+use crate::{
+    graph::{reversed_graph::ReversedGraph, sp_graph::SpGraph},
+    Weight,
+};
+use std::rc::Rc;
+
+/// Bidirectional A* search with the average potentials of Ikeda et al. & Goldberg and Harrelson.
+///
+/// With the lower bounds `pi_t(v)` on d(v, t) and `pi_s(v)` on d(s, v), the forward search uses the potential
+/// `p(v) = (pi_t(v) - pi_s(v)) / 2` and the backward search `-p(v)`; both are consistent and sum up to zero,
+/// so that the searches run Dijkstra's algorithm on the same reduced graph and the bidirectional Dijkstra
+/// stopping criterion applies: stop once `min key_f + min key_b >= best`, keys being distances plus potentials.
+///
+/// Keys are doubled to keep the halved potentials integral.
+pub struct BiAStar<Q: DijkstraQueue, P: Potential> {
+    reversed: ReversedGraph,
+    forward: Search<Q, AverageKey<P>>,
+    backward: Search<Q, AverageKey<P>>,
+}
+
+impl<Q: DijkstraQueue, P: Potential> BiAStar<Q, P> {
+    pub fn new<G: SpGraph>(graph: &G, potential: Rc<P>) -> Self {
+        let key = |forward| AverageKey {
+            potential: potential.clone(),
+            forward,
+            endpoints: None,
+        };
+        Self {
+            reversed: graph.reversed(),
+            forward: Search::with_key(graph, key(true)),
+            backward: Search::with_key(graph, key(false)),
+        }
+    }
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        // reset
+        let solution = Solution::new(graph, source, sink);
+        self.forward.reset(graph.num_nodes());
+        self.backward.reset(graph.num_nodes());
+
+        // init
+        if source == sink {
+            return solution.reached(Weight::default());
+        }
+        self.forward.key.endpoints = Some((source, sink));
+        self.backward.key.endpoints = Some((source, sink));
+        self.forward.init(source);
+        self.backward.init(sink);
+
+        // iterate
+        let mut solution = solution;
+        let mut best = Weight::MAX;
+        while let (Some(forward_min), Some(backward_min)) = (
+            self.forward.queue.peek_key(),
+            self.backward.queue.peek_key(),
+        ) {
+            if forward_min.saturating_add(backward_min) >= best.saturating_mul(2) {
+                break;
+            }
+
+            let settled = if forward_min <= backward_min {
+                self.forward
+                    .settle_next(graph, &self.backward.distances, &mut best)
+            } else {
+                self.backward
+                    .settle_next(&self.reversed, &self.forward.distances, &mut best)
+            };
+
+            if settled.is_some() {
+                solution.iterate(
+                    self.forward.queue.len() + self.backward.queue.len(),
+                    self.forward.queue.capacity() + self.backward.queue.capacity(),
+                );
+            }
+        }
+
+        match best {
+            Weight::MAX => solution.not_connected(),
+            distance => solution.reached(distance),
+        }
+    }
+}
+
+// impl
+impl<G, Q, P> ShortestDistanceAlgorithm<G> for BiAStar<Q, P>
+where
+    G: SpGraph,
+    Q: DijkstraQueue,
+    P: Potential,
+{
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        self.run_cached_core(graph, source, sink)
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        // without a sink, the forward search with zero potentials reduces to dijkstra's algorithm
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.forward.reset(graph.num_nodes());
+        self.backward.reset(graph.num_nodes());
+        self.forward.key.endpoints = None;
+        self.forward.init(source);
+        self.forward
+            .settle_all(graph, &self.backward.distances, &mut solution, |_, _| {});
+        solution.all_reached(&self.forward.distances[..graph.num_nodes()])
+    }
+}
+
+// doubled average potential keys of the forward or backward search between the endpoints (source, sink);
+// zero potentials without endpoints
+struct AverageKey<P: Potential> {
+    potential: Rc<P>,
+    forward: bool,
+    endpoints: Option<(usize, usize)>,
+}
+impl<P: Potential> SearchKey for AverageKey<P> {
+    fn key(&self, node: usize, distance: Weight) -> Weight {
+        // 2 d(v) >= d(v) + pi_s(v) in the forward search & d(v) + pi_t(v) in the backward search; hence, keys are non-negative
+        let Some((source, sink)) = self.endpoints else {
+            return distance;
+        };
+        let (to_sink, from_source) = (
+            self.potential.potential(node, sink),
+            self.potential.potential(source, node),
+        );
+        match self.forward {
+            true => 2 * distance + to_sink - from_source,
+            false => 2 * distance + from_source - to_sink,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::{
+            coordinate_potential::CoordinatePotential,
+            landmarks::Landmarks,
+            test_utils::{assert_same_distances, random_graph, weights},
+        },
+        graph::{
+            adjlist_jagged_vec::AdjListJaggedVec,
+            coordinates::{Coordinates, Point},
+        },
+    };
+    use orx_priority_queue::DaryHeap;
+    use proptest::prelude::*;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    type OrxBinary = DaryHeap<usize, Weight, 2>;
+
+    fn random_coordinates(seed: u64, num_nodes: usize) -> Coordinates {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let points = (0..num_nodes)
+            .map(|_| Point::new(rng.gen_range(-80.0..-70.0), rng.gen_range(35.0..45.0)))
+            .collect();
+        Coordinates::new(points)
+    }
+    fn assert_same_distances_with<P: Potential>(
+        graph: &AdjListJaggedVec,
+        pairs_seed: u64,
+        potential: P,
+    ) {
+        let mut bi_astar = BiAStar::<OrxBinary, _>::new(graph, Rc::new(potential));
+        assert_same_distances(graph, pairs_seed, &mut bi_astar);
+    }
+
+    proptest! {
+        #[test]
+        fn landmarks_match_dijkstra(
+            seed in any::<u64>(),
+            pairs_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+            num_landmarks in 1..8usize,
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            assert_same_distances_with(&graph, pairs_seed, Landmarks::random(&graph, num_landmarks));
+            assert_same_distances_with(&graph, pairs_seed, Landmarks::farthest(&graph, num_landmarks));
+            assert_same_distances_with(&graph, pairs_seed, Landmarks::avoid(&graph, num_landmarks));
+        }
+
+        #[test]
+        fn coordinates_match_dijkstra(
+            seed in any::<u64>(),
+            pairs_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let coordinates = || random_coordinates(seed, num_nodes);
+            assert_same_distances_with(&graph, pairs_seed, CoordinatePotential::euclidean(&graph, coordinates()));
+            assert_same_distances_with(&graph, pairs_seed, CoordinatePotential::great_circle(&graph, coordinates()));
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use super::{
+    dijkstra_queue::DijkstraQueue,
+    measure::run_measure::Measure,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        reversed_graph::ReversedGraph,
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+
+/// Bidirectional Dijkstra's algorithm alternating between
+/// a forward search from the source on the graph and
+/// a backward search from the sink on the reversed graph.
+pub struct BiDijkstra<Q: DijkstraQueue> {
+    reversed: ReversedGraph,
+    forward: Search<Q>,
+    backward: Search<Q>,
+}
+
+impl<Q: DijkstraQueue> BiDijkstra<Q> {
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        // reset
+        let solution = Solution::new(graph, source, sink);
+        self.forward.reset(graph.num_nodes());
+        self.backward.reset(graph.num_nodes());
+
+        // init
+        if source == sink {
+            return solution.reached(Weight::default());
+        }
+        self.forward.init(source);
+        self.backward.init(sink);
+
+        // iterate
+        let mut solution = solution;
+        let mut best = Weight::MAX;
+        while let (Some(forward_key), Some(backward_key)) = (
+            self.forward.queue.peek_key(),
+            self.backward.queue.peek_key(),
+        ) {
+            if forward_key.saturating_add(backward_key) >= best {
+                break;
+            }
+
+            let settled = if forward_key <= backward_key {
+                self.forward
+                    .settle_next(graph, &self.backward.distances, &mut best)
+            } else {
+                self.backward
+                    .settle_next(&self.reversed, &self.forward.distances, &mut best)
+            };
+
+            if settled.is_some() {
+                solution.iterate(
+                    self.forward.queue.len() + self.backward.queue.len(),
+                    self.forward.queue.capacity() + self.backward.queue.capacity(),
+                );
+            }
+        }
+
+        match best {
+            Weight::MAX => solution.not_connected(),
+            distance => solution.reached(distance),
+        }
+    }
+}
+
+// impl
+impl<G, Q> PureShortestDistanceAlgorithm<G> for BiDijkstra<Q>
+where
+    G: SpGraph,
+    Q: DijkstraQueue,
+{
+    fn new(graph: &G) -> Self {
+        Self {
+            reversed: graph.reversed(),
+            forward: Search::new(graph),
+            backward: Search::new(graph),
+        }
+    }
+}
+impl<G, Q> ShortestDistanceAlgorithm<G> for BiDijkstra<Q>
+where
+    G: SpGraph,
+    Q: DijkstraQueue,
+{
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        self.run_cached_core(graph, source, sink)
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        // the forward search alone; backward distances are never reached
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.forward.reset(graph.num_nodes());
+        self.backward.reset(graph.num_nodes());
+        self.forward.init(source);
+        self.forward
+            .settle_all(graph, &self.backward.distances, &mut solution, |_, _| {});
+        solution.all_reached(&self.forward.distances[..graph.num_nodes()])
+    }
+}
+
+// key of a node in the queue of a search given its distance; the potential shifts the distances of A* searches
+pub(super) trait SearchKey {
+    fn key(&self, node: usize, distance: Weight) -> Weight;
+}
+// keys of dijkstra's algorithm, the distances themselves
+pub(super) struct DistanceKey;
+impl SearchKey for DistanceKey {
+    fn key(&self, _: usize, distance: Weight) -> Weight {
+        distance
+    }
+}
+
+// search in one direction; also used by the bidirectional upward search of contraction hierarchies
+// and, prioritized by potentials, by the bidirectional A* search
+pub(super) struct Search<Q: DijkstraQueue, K: SearchKey = DistanceKey> {
+    pub(super) queue: Q,
+    pub(super) distances: Vec<Weight>,
+    pub(super) key: K,
+}
+impl<Q: DijkstraQueue> Search<Q> {
+    pub(super) fn new<G: SpGraph>(graph: &G) -> Self {
+        Self::with_key(graph, DistanceKey)
+    }
+}
+impl<Q: DijkstraQueue, K: SearchKey> Search<Q, K> {
+    pub(super) fn with_key<G: SpGraph>(graph: &G, key: K) -> Self {
+        Self {
+            queue: Q::for_graph(graph),
+            distances: Default::default(),
+            key,
+        }
+    }
+    pub(super) fn reset(&mut self, num_nodes: usize) {
+        self.distances
+            .iter_mut()
+            .take(num_nodes)
+            .for_each(|x| *x = Weight::MAX);
+        if self.distances.len() < num_nodes {
+            self.distances
+                .extend((0..num_nodes - self.distances.len()).map(|_| Weight::MAX));
+        }
+        self.queue.clear();
+    }
+    pub(super) fn init(&mut self, node: usize) {
+        self.distances[node] = Weight::default();
+        self.queue
+            .relax(node, self.key.key(node, Weight::default()));
+    }
+    // settles all nodes reachable from the initialized nodes, passing each settled node to `on_settled`
+    pub(super) fn settle_all<G: SpGraph, M: Measure, F: FnMut(usize, Weight)>(
+        &mut self,
+        graph: &G,
+        opposite_distances: &[Weight],
+        solution: &mut Solution<M>,
+        mut on_settled: F,
+    ) {
+        let mut best = Weight::MAX;
+        while self.queue.len() > 0 {
+            if let Some(node) = self.settle_next(graph, opposite_distances, &mut best) {
+                on_settled(node, self.distances[node]);
+                solution.iterate(self.queue.len(), self.queue.capacity());
+            }
+        }
+    }
+    pub(super) fn settle_next<G: SpGraph>(
+        &mut self,
+        graph: &G,
+        opposite_distances: &[Weight],
+        best: &mut Weight,
+    ) -> Option<usize> {
+        let (position, position_key) = self.queue.pop()?;
+        let cost = self.distances[position];
+        if position_key > self.key.key(position, cost) {
+            return None;
+        }
+
+        let mut out_edges = graph.out_edges(position);
+        while let Some(edge) = out_edges.next_edge() {
+            let (head, weight) = (edge.head(), edge.weight());
+            let next_cost = cost + weight;
+            if next_cost < self.distances[head] {
+                self.queue.relax(head, self.key.key(head, next_cost));
+                self.distances[head] = next_cost;
+            }
+            if opposite_distances[head] != Weight::MAX {
+                *best = (*best).min(next_cost + opposite_distances[head]);
+            }
+        }
+        Some(position)
+    }
+}
@@ -0,0 +1,126 @@
+use super::dijkstra_queue::DijkstraQueue;
+use crate::{graph::sp_graph::SpGraph, Weight};
+
+/// Dial's bucket queue: a monotone integer priority queue with one bucket per key.
+///
+/// Buckets are used circularly such that all keys in the queue lie in
+/// `[cursor, cursor + num_buckets)`; in Dijkstra's algorithm, the keys in the queue span
+/// at most the maximum edge weight, hence the circular array has `max_weight + 1` buckets.
+/// Peeking & popping advance the cursor to the minimum key, such that a peek followed by a pop scans the buckets once.
+///
+/// * `len` is the number of entries including stale ones,
+/// * `capacity` is the number of buckets plus the number of entry slots allocated by the buckets.
+pub struct BucketQueue {
+    buckets: Vec<Vec<usize>>,
+    cursor: Weight,
+    len: usize,
+    num_slots: usize,
+}
+
+impl BucketQueue {
+    pub fn with_max_weight(max_weight: Weight) -> Self {
+        Self {
+            buckets: vec![vec![]; max_weight as usize + 1],
+            cursor: 0,
+            len: 0,
+            num_slots: 0,
+        }
+    }
+    fn bucket_of(&self, key: Weight) -> usize {
+        (key % self.buckets.len() as Weight) as usize
+    }
+    fn advance_cursor(&mut self) {
+        while self.buckets[self.bucket_of(self.cursor)].is_empty() {
+            self.cursor += 1;
+        }
+    }
+}
+
+impl DijkstraQueue for BucketQueue {
+    fn for_graph<G: SpGraph>(graph: &G) -> Self {
+        Self::with_max_weight(graph.max_weight())
+    }
+    fn clear(&mut self) {
+        if self.len > 0 {
+            self.buckets.iter_mut().for_each(Vec::clear);
+        }
+        self.cursor = 0;
+        self.len = 0;
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn capacity(&self) -> usize {
+        self.buckets.len() + self.num_slots
+    }
+    fn peek_key(&mut self) -> Option<Weight> {
+        if self.len == 0 {
+            return None;
+        }
+        self.advance_cursor();
+        Some(self.cursor)
+    }
+    fn pop(&mut self) -> Option<(usize, Weight)> {
+        if self.len == 0 {
+            return None;
+        }
+        self.advance_cursor();
+        let bucket = self.bucket_of(self.cursor);
+        self.len -= 1;
+        self.buckets[bucket].pop().map(|node| (node, self.cursor))
+    }
+    #[inline(always)]
+    fn relax(&mut self, node: usize, key: Weight) {
+        debug_assert!(key >= self.cursor, "keys must be monotone");
+        debug_assert!(
+            ((key - self.cursor) as usize) < self.buckets.len(),
+            "keys must not exceed the minimum key by more than the maximum edge weight"
+        );
+
+        let bucket = self.bucket_of(key);
+        let bucket = &mut self.buckets[bucket];
+        let capacity = bucket.capacity();
+        bucket.push(node);
+        self.num_slots += bucket.capacity() - capacity;
+        self.len += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::{
+        bidirectional_dijkstra::BiDijkstra,
+        queue_dijkstra::QueueDijkstra,
+        sd_algorithm::PureShortestDistanceAlgorithm,
+        test_utils::{
+            assert_same_distances, assert_same_keys_as_model, assert_same_one_to_all_distances,
+            queue_ops, random_graph, weights, QueueKind,
+        },
+    };
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn pops_keys_in_order(
+            (max_weight, ops) in (0..50 as Weight).prop_flat_map(|w| (Just(w), queue_ops(40, w))),
+        ) {
+            let queue = &mut BucketQueue::with_max_weight(max_weight);
+            assert_same_keys_as_model(queue, &ops, QueueKind::Monotone);
+        }
+
+        #[test]
+        fn dijkstra_matches_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            assert_same_distances(&graph, queries_seed, &mut QueueDijkstra::<BucketQueue>::new(&graph));
+            assert_same_one_to_all_distances(&graph, queries_seed, &mut QueueDijkstra::<BucketQueue>::new(&graph));
+            assert_same_distances(&graph, queries_seed, &mut BiDijkstra::<BucketQueue>::new(&graph));
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use super::{
+    bidirectional_dijkstra::Search, contraction_hierarchy::ContractionHierarchy,
+    dijkstra_queue::DijkstraQueue, measure::run_measure::Measure,
+    sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::rc::Rc;
+
+/// Bidirectional upward query on a contraction hierarchy;
+/// the forward search from the source and the backward search from the sink
+/// only relax edges towards higher ranked nodes and meet at the highest ranked node of the shortest path.
+pub struct ChQuery<Q: DijkstraQueue> {
+    hierarchy: Rc<ContractionHierarchy>,
+    forward: Search<Q>,
+    backward: Search<Q>,
+    // (target index, distance) pairs left at the nodes by the backward searches of many-to-many queries
+    buckets: Vec<Vec<(usize, Weight)>>,
+    bucket_nodes: Vec<usize>,
+}
+
+impl<Q: DijkstraQueue> ChQuery<Q> {
+    pub fn new<G: SpGraph>(graph: &G, hierarchy: Rc<ContractionHierarchy>) -> Self {
+        Self {
+            hierarchy,
+            forward: Search::new(graph),
+            backward: Search::new(graph),
+            buckets: vec![],
+            bucket_nodes: vec![],
+        }
+    }
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        // reset
+        let solution = Solution::new(graph, source, sink);
+        self.forward.reset(graph.num_nodes());
+        self.backward.reset(graph.num_nodes());
+
+        // init
+        if source == sink {
+            return solution.reached(Weight::default());
+        }
+        self.forward.init(source);
+        self.backward.init(sink);
+
+        // iterate: unlike bidirectional dijkstra, each direction continues until its own minimum key reaches best
+        let mut solution = solution;
+        let mut best = Weight::MAX;
+        loop {
+            let forward_key = self.forward.queue.peek_key().filter(|&x| x < best);
+            let backward_key = self.backward.queue.peek_key().filter(|&x| x < best);
+            let settled = match (forward_key, backward_key) {
+                (None, None) => break,
+                (Some(f), Some(b)) if f > b => self.settle_backward(&mut best),
+                (Some(_), _) => self.settle_forward(&mut best),
+                (None, Some(_)) => self.settle_backward(&mut best),
+            };
+
+            if settled.is_some() {
+                solution.iterate(
+                    self.forward.queue.len() + self.backward.queue.len(),
+                    self.forward.queue.capacity() + self.backward.queue.capacity(),
+                );
+            }
+        }
+
+        match best {
+            Weight::MAX => solution.not_connected(),
+            distance => solution.reached(distance),
+        }
+    }
+    fn settle_forward(&mut self, best: &mut Weight) -> Option<usize> {
+        self.forward
+            .settle_next(self.hierarchy.upward(), &self.backward.distances, best)
+    }
+    fn settle_backward(&mut self, best: &mut Weight) -> Option<usize> {
+        self.backward
+            .settle_next(self.hierarchy.downward(), &self.forward.distances, best)
+    }
+}
+
+// impl
+impl<G, Q> ShortestDistanceAlgorithm<G> for ChQuery<Q>
+where
+    G: SpGraph,
+    Q: DijkstraQueue,
+{
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        self.run_cached_core(graph, source, sink)
+    }
+    /// PHAST: an upward search from the source followed by a sweep over the nodes in decreasing rank,
+    /// where each node takes the minimum over its downward edges from the higher ranked nodes.
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        // upward
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.forward.reset(graph.num_nodes());
+        self.backward.reset(graph.num_nodes());
+        self.forward.init(source);
+        self.forward.settle_all(
+            self.hierarchy.upward(),
+            &self.backward.distances,
+            &mut solution,
+            |_, _| {},
+        );
+
+        // downward sweep
+        let distances = &mut self.forward.distances;
+        for &node in self.hierarchy.nodes_by_rank().iter().rev() {
+            let mut in_edges = self.hierarchy.downward().out_edges(node);
+            while let Some(edge) = in_edges.next_edge() {
+                let tail_distance = distances[edge.head()];
+                if tail_distance != Weight::MAX {
+                    distances[node] = distances[node].min(tail_distance + edge.weight());
+                }
+            }
+        }
+
+        solution.all_reached(&self.forward.distances[..graph.num_nodes()])
+    }
+    /// Bucket-based many-to-many: the backward upward search from each target leaves its distance
+    /// in the bucket of each settled node; the forward upward search from each source then scans
+    /// the buckets of its settled nodes, which contain all targets whose shortest paths meet there.
+    fn run_cached_many_to_many<M: Measure>(
+        &mut self,
+        graph: &G,
+        sources: &[usize],
+        targets: &[usize],
+    ) -> Solution<M> {
+        // reset
+        let mut solution = Solution::new_many_to_many(graph, sources, targets);
+        for node in self.bucket_nodes.drain(..) {
+            self.buckets[node].clear();
+        }
+        if self.buckets.len() < graph.num_nodes() {
+            self.buckets.resize_with(graph.num_nodes(), Vec::new);
+        }
+        self.forward.reset(graph.num_nodes());
+
+        // backward: fill buckets
+        let (buckets, bucket_nodes) = (&mut self.buckets, &mut self.bucket_nodes);
+        for (j, &target) in targets.iter().enumerate() {
+            self.backward.reset(graph.num_nodes());
+            self.backward.init(target);
+            self.backward.settle_all(
+                self.hierarchy.downward(),
+                &self.forward.distances,
+                &mut solution,
+                |node, distance| {
+                    if buckets[node].is_empty() {
+                        bucket_nodes.push(node);
+                    }
+                    buckets[node].push((j, distance));
+                },
+            );
+        }
+
+        // forward: scan buckets
+        let mut table = vec![Weight::MAX; sources.len() * targets.len()];
+        for (i, &source) in sources.iter().enumerate() {
+            let row = &mut table[i * targets.len()..(i + 1) * targets.len()];
+            self.forward.reset(graph.num_nodes());
+            self.forward.init(source);
+            self.forward.settle_all(
+                self.hierarchy.upward(),
+                &self.backward.distances,
+                &mut solution,
+                |node, distance| {
+                    for &(j, target_distance) in &buckets[node] {
+                        row[j] = row[j].min(distance + target_distance);
+                    }
+                },
+            );
+        }
+
+        solution.table_reached(&table)
+    }
+}
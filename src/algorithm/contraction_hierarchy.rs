@@ -0,0 +1,437 @@
+use super::std_pq_dijkstra::State;
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        out_edges_std_vec::{OutEdge, OutEdgesStdVec},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Result of contracting the nodes of a graph one by one in the order of their importance.
+///
+/// * `upward` contains the edges, including the shortcuts, from lower ranked nodes to higher ranked nodes;
+/// * `downward` contains the edges from higher ranked nodes to lower ranked nodes, reversed,
+///   so that a backward search from the sink also only moves upwards in the hierarchy;
+/// * `nodes_by_rank` lists the nodes in the order they are contracted.
+pub struct ContractionHierarchy {
+    upward: HierarchyGraph,
+    downward: HierarchyGraph,
+    nodes_by_rank: Vec<usize>,
+    num_shortcuts: usize,
+}
+
+impl ContractionHierarchy {
+    /// Nodes are ordered lazily by their edge difference, the number of shortcuts required
+    /// to contract the node minus the number of its remaining edges, plus the number of its
+    /// already contracted neighbors to spread the contraction uniformly over the graph.
+    pub fn new<G: SpGraph>(graph: &G) -> Self {
+        let mut contraction = Contraction::new(graph);
+        contraction.contract_all();
+        contraction.into_hierarchy()
+    }
+
+    pub fn upward(&self) -> &HierarchyGraph {
+        &self.upward
+    }
+    pub fn downward(&self) -> &HierarchyGraph {
+        &self.downward
+    }
+    pub fn nodes_by_rank(&self) -> &[usize] {
+        &self.nodes_by_rank
+    }
+    pub fn num_shortcuts(&self) -> usize {
+        self.num_shortcuts
+    }
+    pub fn memory_bytes(&self) -> usize {
+        self.upward.memory_bytes()
+            + self.downward.memory_bytes()
+            + self.nodes_by_rank.len() * std::mem::size_of::<usize>()
+    }
+}
+
+/// One direction of the hierarchy stored as a compressed sparse row graph.
+pub struct HierarchyGraph {
+    edges: Vec<OutEdge>,
+    out_edges_begin_index: Vec<usize>,
+}
+
+impl HierarchyGraph {
+    fn from_edges(num_nodes: usize, edges: &[(usize, usize, Weight)]) -> Self {
+        let mut out_degrees = vec![0; num_nodes];
+        for &(tail, _, _) in edges {
+            out_degrees[tail] += 1;
+        }
+
+        let mut cumulative = 0;
+        let mut out_edges_begin_index = Vec::with_capacity(num_nodes + 1);
+        out_edges_begin_index.push(cumulative);
+        for out_degree in &out_degrees {
+            cumulative += out_degree;
+            out_edges_begin_index.push(cumulative);
+        }
+
+        let mut next_position = out_edges_begin_index.clone();
+        let mut out_edges = vec![OutEdge::new(0, 0); cumulative];
+        for &(tail, head, weight) in edges {
+            out_edges[next_position[tail]] = OutEdge::new(head, weight);
+            next_position[tail] += 1;
+        }
+
+        Self {
+            edges: out_edges,
+            out_edges_begin_index,
+        }
+    }
+    fn memory_bytes(&self) -> usize {
+        self.edges.len() * std::mem::size_of::<OutEdge>()
+            + self.out_edges_begin_index.len() * std::mem::size_of::<usize>()
+    }
+}
+
+impl SpGraph for HierarchyGraph {
+    type W = Weight;
+    type OutEdges<'a> = OutEdgesStdVec<'a>;
+    fn name() -> &'static str {
+        "HierarchyGraph"
+    }
+    fn num_nodes(&self) -> usize {
+        self.out_edges_begin_index.len() - 1
+    }
+    fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
+        let begin = self.out_edges_begin_index[node];
+        let end = self.out_edges_begin_index[node + 1];
+        self.edges[begin..end].iter()
+    }
+}
+
+// contraction
+struct Contraction {
+    out_edges: Vec<Vec<(usize, Weight)>>,
+    in_edges: Vec<Vec<(usize, Weight)>>,
+    rank: Vec<usize>,
+    num_contracted_neighbors: Vec<usize>,
+    num_shortcuts: usize,
+    witness: WitnessSearch,
+}
+
+impl Contraction {
+    fn new<G: SpGraph>(graph: &G) -> Self {
+        let num_nodes = graph.num_nodes();
+        let mut contraction = Self {
+            out_edges: vec![vec![]; num_nodes],
+            in_edges: vec![vec![]; num_nodes],
+            rank: vec![NOT_CONTRACTED; num_nodes],
+            num_contracted_neighbors: vec![0; num_nodes],
+            num_shortcuts: 0,
+            witness: WitnessSearch::new(num_nodes),
+        };
+        for tail in 0..num_nodes {
+            let mut out_edges = graph.out_edges(tail);
+            while let Some(edge) = out_edges.next_edge() {
+                if edge.head() != tail {
+                    contraction.add_edge(tail, edge.head(), edge.weight());
+                }
+            }
+        }
+        contraction
+    }
+
+    fn is_contracted(&self, node: usize) -> bool {
+        self.rank[node] != NOT_CONTRACTED
+    }
+
+    // returns whether a new edge is created rather than an existing parallel edge being updated
+    fn add_edge(&mut self, tail: usize, head: usize, weight: Weight) -> bool {
+        match self.out_edges[tail].iter().position(|x| x.0 == head) {
+            Some(i) => {
+                if weight < self.out_edges[tail][i].1 {
+                    self.out_edges[tail][i].1 = weight;
+                    let j = self.in_edges[head]
+                        .iter()
+                        .position(|x| x.0 == tail)
+                        .expect("in & out edges are symmetric");
+                    self.in_edges[head][j].1 = weight;
+                }
+                false
+            }
+            None => {
+                self.out_edges[tail].push((head, weight));
+                self.in_edges[head].push((tail, weight));
+                true
+            }
+        }
+    }
+
+    fn remaining(&self, edges: &[(usize, Weight)]) -> Vec<(usize, Weight)> {
+        edges
+            .iter()
+            .filter(|x| !self.is_contracted(x.0))
+            .copied()
+            .collect()
+    }
+
+    // shortcuts (u, w, weight) required to preserve the distances once `node` is contracted
+    fn shortcuts(&mut self, node: usize) -> Vec<(usize, usize, Weight)> {
+        let in_edges = self.remaining(&self.in_edges[node]);
+        let out_edges = self.remaining(&self.out_edges[node]);
+
+        let mut shortcuts = vec![];
+        for &(u, weight_uv) in &in_edges {
+            let max_weight_vw = out_edges.iter().filter(|x| x.0 != u).map(|x| x.1).max();
+            let Some(max_weight_vw) = max_weight_vw else {
+                continue;
+            };
+
+            self.witness.run(
+                &self.out_edges,
+                &self.rank,
+                u,
+                node,
+                weight_uv + max_weight_vw,
+            );
+            for &(w, weight_vw) in out_edges.iter().filter(|x| x.0 != u) {
+                let via_node = weight_uv + weight_vw;
+                if self.witness.distance(w) > via_node {
+                    shortcuts.push((u, w, via_node));
+                }
+            }
+        }
+        shortcuts
+    }
+
+    fn priority(&self, node: usize, num_shortcuts: usize) -> i64 {
+        let num_edges = self.remaining(&self.in_edges[node]).len()
+            + self.remaining(&self.out_edges[node]).len();
+        num_shortcuts as i64 - num_edges as i64 + self.num_contracted_neighbors[node] as i64
+    }
+
+    fn contract_all(&mut self) {
+        let mut queue = BinaryHeap::new();
+        for node in 0..self.rank.len() {
+            let num_shortcuts = self.shortcuts(node).len();
+            queue.push(Reverse((self.priority(node, num_shortcuts), node)));
+        }
+
+        let mut next_rank = 0;
+        while let Some(Reverse((_, node))) = queue.pop() {
+            // lazy update: priority might have increased since the node is pushed
+            let shortcuts = self.shortcuts(node);
+            let priority = self.priority(node, shortcuts.len());
+            if let Some(Reverse((next_priority, _))) = queue.peek() {
+                if priority > *next_priority {
+                    queue.push(Reverse((priority, node)));
+                    continue;
+                }
+            }
+
+            self.contract(node, next_rank, shortcuts);
+            next_rank += 1;
+        }
+    }
+
+    fn contract(&mut self, node: usize, rank: usize, shortcuts: Vec<(usize, usize, Weight)>) {
+        self.rank[node] = rank;
+        for (u, w, weight) in shortcuts {
+            if self.add_edge(u, w, weight) {
+                self.num_shortcuts += 1;
+            }
+        }
+
+        let neighbors = self.in_edges[node].iter().chain(&self.out_edges[node]);
+        for &(neighbor, _) in neighbors {
+            if self.rank[neighbor] == NOT_CONTRACTED {
+                self.num_contracted_neighbors[neighbor] += 1;
+            }
+        }
+    }
+
+    fn into_hierarchy(self) -> ContractionHierarchy {
+        let num_nodes = self.rank.len();
+        let (mut upward, mut downward) = (vec![], vec![]);
+        for (tail, out_edges) in self.out_edges.iter().enumerate() {
+            for &(head, weight) in out_edges {
+                if self.rank[tail] < self.rank[head] {
+                    upward.push((tail, head, weight));
+                } else {
+                    downward.push((head, tail, weight));
+                }
+            }
+        }
+
+        let mut nodes_by_rank = vec![0; num_nodes];
+        for (node, &rank) in self.rank.iter().enumerate() {
+            nodes_by_rank[rank] = node;
+        }
+
+        ContractionHierarchy {
+            upward: HierarchyGraph::from_edges(num_nodes, &upward),
+            downward: HierarchyGraph::from_edges(num_nodes, &downward),
+            nodes_by_rank,
+            num_shortcuts: self.num_shortcuts,
+        }
+    }
+}
+
+// local dijkstra among the remaining nodes searching for paths which make shortcuts redundant
+struct WitnessSearch {
+    heap: BinaryHeap<State>,
+    distances: Vec<Weight>,
+    touched: Vec<usize>,
+}
+
+impl WitnessSearch {
+    fn new(num_nodes: usize) -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            distances: vec![Weight::MAX; num_nodes],
+            touched: vec![],
+        }
+    }
+
+    fn distance(&self, node: usize) -> Weight {
+        self.distances[node]
+    }
+
+    fn run(
+        &mut self,
+        out_edges: &[Vec<(usize, Weight)>],
+        rank: &[usize],
+        source: usize,
+        avoid: usize,
+        max_cost: Weight,
+    ) {
+        for node in self.touched.drain(..) {
+            self.distances[node] = Weight::MAX;
+        }
+        self.heap.clear();
+
+        self.distances[source] = 0;
+        self.touched.push(source);
+        self.heap.push(State {
+            cost: 0,
+            position: source,
+        });
+
+        let mut num_settled = 0;
+        while let Some(State { cost, position }) = self.heap.pop() {
+            if cost > max_cost || num_settled == MAX_WITNESS_SETTLED {
+                break;
+            }
+            if cost > self.distances[position] {
+                continue;
+            }
+            num_settled += 1;
+
+            for &(head, weight) in &out_edges[position] {
+                if head == avoid || rank[head] != NOT_CONTRACTED {
+                    continue;
+                }
+                let next_cost = cost + weight;
+                if next_cost < self.distances[head] {
+                    if self.distances[head] == Weight::MAX {
+                        self.touched.push(head);
+                    }
+                    self.distances[head] = next_cost;
+                    self.heap.push(State {
+                        cost: next_cost,
+                        position: head,
+                    });
+                }
+            }
+        }
+    }
+}
+
+const NOT_CONTRACTED: usize = usize::MAX;
+// witness searches are limited; an unnecessary shortcut might be added but never a required one is skipped
+const MAX_WITNESS_SETTLED: usize = 500;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::{
+        ch_query::ChQuery,
+        test_utils::{
+            assert_same_distances, assert_same_one_to_all_distances, assert_same_tables,
+            random_graph, weights,
+        },
+    };
+    use orx_priority_queue::DaryHeap;
+    use proptest::prelude::*;
+    use std::rc::Rc;
+
+    type OrxBinary = DaryHeap<usize, Weight, 2>;
+
+    fn contraction_of(edges: &[(usize, usize, Weight)], num_nodes: usize) -> Contraction {
+        let mut contraction = Contraction {
+            out_edges: vec![vec![]; num_nodes],
+            in_edges: vec![vec![]; num_nodes],
+            rank: vec![NOT_CONTRACTED; num_nodes],
+            num_contracted_neighbors: vec![0; num_nodes],
+            num_shortcuts: 0,
+            witness: WitnessSearch::new(num_nodes),
+        };
+        for &(tail, head, weight) in edges {
+            contraction.add_edge(tail, head, weight);
+        }
+        contraction
+    }
+
+    #[test]
+    fn witness_makes_shortcut_redundant() {
+        // 0 -> 1 -> 2 is as short as 0 -> 3 -> 2
+        let contraction = &mut contraction_of(&[(0, 1, 1), (1, 2, 1), (0, 3, 1), (3, 2, 1)], 4);
+        assert!(contraction.shortcuts(1).is_empty());
+    }
+
+    #[test]
+    fn longer_witness_requires_shortcut() {
+        // 0 -> 1 -> 2 is shorter than 0 -> 3 -> 2
+        let contraction = &mut contraction_of(&[(0, 1, 1), (1, 2, 1), (0, 3, 1), (3, 2, 2)], 4);
+        assert_eq!(contraction.shortcuts(1), vec![(0, 2, 2)]);
+    }
+
+    #[test]
+    fn witness_avoids_contracted_nodes() {
+        let contraction = &mut contraction_of(&[(0, 1, 1), (1, 2, 1), (0, 3, 1), (3, 2, 1)], 4);
+        contraction.contract(3, 0, vec![]);
+        assert_eq!(contraction.shortcuts(1), vec![(0, 2, 2)]);
+    }
+
+    fn assert_only_moves_upwards(hierarchy: &ContractionHierarchy) {
+        let num_nodes = hierarchy.nodes_by_rank().len();
+        let mut rank = vec![0; num_nodes];
+        for (r, &node) in hierarchy.nodes_by_rank().iter().enumerate() {
+            rank[node] = r;
+        }
+        for direction in [hierarchy.upward(), hierarchy.downward()] {
+            for tail in 0..num_nodes {
+                let mut out_edges = direction.out_edges(tail);
+                while let Some(edge) = out_edges.next_edge() {
+                    assert!(rank[tail] < rank[edge.head()], "{}-{}", tail, edge.head());
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn queries_match_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.1,
+            weights in weights(),
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let hierarchy = Rc::new(ContractionHierarchy::new(&graph));
+            assert_only_moves_upwards(&hierarchy);
+            let mut ch = ChQuery::<OrxBinary>::new(&graph, hierarchy);
+            assert_same_distances(&graph, queries_seed, &mut ch);
+            assert_same_one_to_all_distances(&graph, queries_seed, &mut ch);
+            assert_same_tables(&graph, queries_seed, &mut ch);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use super::potential::Potential;
+use crate::{
+    graph::{
+        coordinates::{Coordinates, Point},
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+
+/// Potential computed as a scaled geometric distance between the node and the sink.
+///
+/// The scale is the minimum weight-to-distance ratio over all edges of the graph;
+/// therefore, the bound is admissible and consistent for any edge weights,
+/// including the travel distances of the Dimacs instances.
+pub struct CoordinatePotential {
+    coordinates: Coordinates,
+    distance: fn(&Point, &Point) -> f64,
+    scale: f64,
+}
+
+impl CoordinatePotential {
+    pub fn euclidean<G: SpGraph>(graph: &G, coordinates: Coordinates) -> Self {
+        Self::new(graph, coordinates, Point::euclidean_distance)
+    }
+    pub fn great_circle<G: SpGraph>(graph: &G, coordinates: Coordinates) -> Self {
+        Self::new(graph, coordinates, Point::great_circle_distance)
+    }
+    fn new<G: SpGraph>(
+        graph: &G,
+        coordinates: Coordinates,
+        distance: fn(&Point, &Point) -> f64,
+    ) -> Self {
+        assert_eq!(graph.num_nodes(), coordinates.num_nodes());
+
+        let mut scale = f64::INFINITY;
+        for tail in 0..graph.num_nodes() {
+            let mut out_edges = graph.out_edges(tail);
+            while let Some(edge) = out_edges.next_edge() {
+                let length = distance(coordinates.point(tail), coordinates.point(edge.head()));
+                if length > 0.0 {
+                    scale = scale.min(edge.weight() as f64 / length);
+                }
+            }
+        }
+        // safety margin against floating point errors
+        let scale = if scale.is_finite() {
+            scale * (1.0 - 1e-9)
+        } else {
+            0.0
+        };
+
+        Self {
+            coordinates,
+            distance,
+            scale,
+        }
+    }
+}
+
+impl Potential for CoordinatePotential {
+    #[inline(always)]
+    fn potential(&self, node: usize, sink: usize) -> Weight {
+        let length = (self.distance)(self.coordinates.point(node), self.coordinates.point(sink));
+        (self.scale * length).floor() as Weight
+    }
+}
@@ -0,0 +1,299 @@
+use super::{
+    measure::run_measure::Measure, sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::sync::atomic::Ordering;
+
+#[cfg(not(feature = "signed-weights"))]
+type AtomicWeight = std::sync::atomic::AtomicU64;
+#[cfg(feature = "signed-weights")]
+type AtomicWeight = std::sync::atomic::AtomicI64;
+
+/// Delta-stepping of Meyer & Sanders.
+///
+/// Nodes are kept in buckets of width `delta` by their tentative distances.
+/// The lowest non-empty bucket is emptied in phases relaxing the light edges, `weight <= delta`,
+/// of all of its nodes in parallel until no node re-enters the bucket;
+/// then the heavy edges of the nodes removed from the bucket are relaxed once.
+///
+/// Buckets are used circularly such that all tentative distances in the buckets lie within
+/// `max_weight + delta` of the start of the current bucket, hence the circular array has `max_weight / delta + 2` buckets.
+///
+/// Relaxations are split among `num_threads` scoped threads and distances are updated by atomic minimum;
+/// the search stops once the bucket of the sink is emptied since its distance is then final.
+pub struct DeltaStepping {
+    delta: Weight,
+    num_threads: usize,
+    distances: Vec<AtomicWeight>,
+    // plain copy of the atomic distances returned by one-to-all queries
+    loaded_distances: Vec<Weight>,
+    buckets: Vec<Vec<usize>>,
+    // index of the current bucket, not wrapped around the circular array
+    cursor: usize,
+    num_entries: usize,
+    num_slots: usize,
+    requests: Vec<Vec<usize>>,
+    frontier: Vec<usize>,
+    removed: Vec<usize>,
+    // rounds are never reset so that stamps of earlier rounds and queries are stale by construction
+    frontier_stamp: Vec<usize>,
+    removed_stamp: Vec<usize>,
+    frontier_round: usize,
+    bucket_round: usize,
+}
+
+impl DeltaStepping {
+    pub fn new<G: SpGraph>(graph: &G, delta: Weight, num_threads: usize) -> Self {
+        let num_nodes = graph.num_nodes();
+        Self {
+            delta,
+            num_threads,
+            distances: (0..num_nodes)
+                .map(|_| AtomicWeight::new(Weight::MAX))
+                .collect(),
+            loaded_distances: vec![],
+            buckets: vec![vec![]; (graph.max_weight() / delta) as usize + 2],
+            cursor: 0,
+            num_entries: 0,
+            num_slots: 0,
+            requests: vec![vec![]; num_threads],
+            frontier: vec![],
+            removed: vec![],
+            frontier_stamp: vec![0; num_nodes],
+            removed_stamp: vec![0; num_nodes],
+            frontier_round: 0,
+            bucket_round: 0,
+        }
+    }
+
+    fn distance(&self, node: usize) -> Weight {
+        self.distances[node].load(Ordering::Relaxed)
+    }
+    fn bucket_of(&self, distance: Weight) -> usize {
+        (distance / self.delta) as usize
+    }
+    fn slot_of(&self, bucket: usize) -> usize {
+        bucket % self.buckets.len()
+    }
+    fn push_to_bucket(&mut self, node: usize) {
+        let bucket = self.bucket_of(self.distance(node));
+        debug_assert!(
+            bucket >= self.cursor && bucket - self.cursor < self.buckets.len(),
+            "distances must not exceed the current bucket by more than the maximum edge weight"
+        );
+
+        let slot = self.slot_of(bucket);
+        let bucket = &mut self.buckets[slot];
+        let capacity = bucket.capacity();
+        bucket.push(node);
+        self.num_slots += bucket.capacity() - capacity;
+        self.num_entries += 1;
+    }
+    fn reset(&mut self, num_nodes: usize) {
+        if self.distances.len() < num_nodes {
+            self.distances
+                .resize_with(num_nodes, || AtomicWeight::new(Weight::MAX));
+            self.frontier_stamp.resize(num_nodes, 0);
+            self.removed_stamp.resize(num_nodes, 0);
+        }
+        self.distances
+            .iter_mut()
+            .take(num_nodes)
+            .for_each(|x| *x.get_mut() = Weight::MAX);
+        self.buckets.iter_mut().for_each(Vec::clear);
+        self.cursor = 0;
+        self.num_entries = 0;
+        self.removed.clear();
+    }
+
+    // moves the valid nodes of the current bucket, which have not been taken in this phase, to the frontier
+    fn take_frontier(&mut self) {
+        self.frontier_round += 1;
+        let (i, slot) = (self.cursor, self.slot_of(self.cursor));
+        let mut bucket = std::mem::take(&mut self.buckets[slot]);
+        self.num_entries -= bucket.len();
+        self.frontier.clear();
+        for &node in &bucket {
+            let round = self.frontier_round;
+            if self.bucket_of(self.distance(node)) == i && self.frontier_stamp[node] != round {
+                self.frontier_stamp[node] = round;
+                self.frontier.push(node);
+            }
+        }
+        bucket.clear();
+        self.buckets[slot] = bucket;
+    }
+
+    // relaxes light or heavy edges of the nodes in parallel and buckets the improved heads
+    fn relax<G: SpGraph + Sync>(&mut self, graph: &G, nodes: &[usize], light: bool) {
+        let (delta, distances) = (self.delta, &self.distances);
+        let relax_chunk = |chunk: &[usize], requests: &mut Vec<usize>| {
+            for &node in chunk {
+                let cost = distances[node].load(Ordering::Relaxed);
+                let mut out_edges = graph.out_edges(node);
+                while let Some(edge) = out_edges.next_edge() {
+                    let (head, weight) = (edge.head(), edge.weight());
+                    if (weight <= delta) != light {
+                        continue;
+                    }
+                    let next_cost = cost + weight;
+                    if next_cost < distances[head].fetch_min(next_cost, Ordering::Relaxed) {
+                        requests.push(head);
+                    }
+                }
+            }
+        };
+
+        let chunk_len = nodes
+            .len()
+            .div_ceil(self.num_threads)
+            .max(MIN_NODES_PER_THREAD);
+        if self.num_threads == 1 || nodes.len() <= chunk_len {
+            relax_chunk(nodes, &mut self.requests[0]);
+        } else {
+            std::thread::scope(|s| {
+                for (chunk, requests) in nodes.chunks(chunk_len).zip(self.requests.iter_mut()) {
+                    s.spawn(|| relax_chunk(chunk, requests));
+                }
+            });
+        }
+
+        let mut requests = std::mem::take(&mut self.requests);
+        for requests in &mut requests {
+            for node in requests.drain(..) {
+                self.push_to_bucket(node);
+            }
+        }
+        self.requests = requests;
+    }
+
+    fn run_cached_core<G: SpGraph + Sync, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: Option<usize>,
+        solution: &mut Solution<M>,
+    ) -> Option<Weight> {
+        // reset
+        self.reset(graph.num_nodes());
+
+        // init
+        *self.distances[source].get_mut() = 0;
+        self.push_to_bucket(source);
+
+        // iterate
+        while self.num_entries > 0 {
+            while self.buckets[self.slot_of(self.cursor)].is_empty() {
+                self.cursor += 1;
+            }
+            let slot = self.slot_of(self.cursor);
+
+            self.removed.clear();
+            self.bucket_round += 1;
+            while !self.buckets[slot].is_empty() {
+                self.take_frontier();
+                for &node in &self.frontier {
+                    if self.removed_stamp[node] != self.bucket_round {
+                        self.removed_stamp[node] = self.bucket_round;
+                        self.removed.push(node);
+                    }
+                    solution.iterate(self.num_entries, self.buckets.len() + self.num_slots);
+                }
+                let frontier = std::mem::take(&mut self.frontier);
+                self.relax(graph, &frontier, true);
+                self.frontier = frontier;
+            }
+
+            if let Some(sink) = sink {
+                if self.bucket_of(self.distance(sink)) <= self.cursor {
+                    return Some(self.distance(sink));
+                }
+            }
+
+            let removed = std::mem::take(&mut self.removed);
+            self.relax(graph, &removed, false);
+            self.removed = removed;
+        }
+
+        None
+    }
+}
+
+// impl
+// graphs are shared among the relaxing threads
+impl<G: SpGraph + Sync> ShortestDistanceAlgorithm<G> for DeltaStepping {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        match self.run_cached_core(graph, source, Some(sink), &mut solution) {
+            Some(distance) => solution.reached(distance),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.run_cached_core(graph, source, None, &mut solution);
+        let mut distances = std::mem::take(&mut self.loaded_distances);
+        distances.clear();
+        distances.extend((0..graph.num_nodes()).map(|node| self.distance(node)));
+        let solution = solution.all_reached(&distances);
+        self.loaded_distances = distances;
+        solution
+    }
+}
+
+// threads are spawned per phase; small frontiers are relaxed on the calling thread since spawning costs more
+const MIN_NODES_PER_THREAD: usize = 64;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::test_utils::{
+            assert_same_distances, assert_same_one_to_all_distances, random_graph, weights,
+        },
+        experimentation::factors::graph_data::random::RandomWeights,
+    };
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn delta_stepping_matches_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+            delta in 1..2000 as Weight,
+            num_threads in 1..5usize,
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let delta_stepping = &mut DeltaStepping::new(&graph, delta, num_threads);
+            assert_same_distances(&graph, queries_seed, delta_stepping);
+            assert_same_one_to_all_distances(&graph, queries_seed, delta_stepping);
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        // frontiers are large enough to be relaxed by more than one thread
+        #[test]
+        fn parallel_relaxations_match_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            delta in 1..2000 as Weight,
+            num_threads in 2..5usize,
+        ) {
+            let graph = random_graph(seed, 3000, 0.002, RandomWeights::Uniform);
+            let delta_stepping = &mut DeltaStepping::new(&graph, delta, num_threads);
+            assert_same_distances(&graph, queries_seed, delta_stepping);
+            assert_same_one_to_all_distances(&graph, queries_seed, delta_stepping);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use super::std_pq_dijkstra::State;
+use crate::{
+    graph::{sp_graph::SpGraph, weight_type::WeightType},
+    Weight,
+};
+use orx_priority_queue::{
+    DaryHeap, DaryHeapOfIndices, DaryHeapWithMap, NodeKeyRef, PriorityQueue, PriorityQueueDecKey,
+};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Common interface of the queues so that a single algorithm implementation can be run with any of them.
+///
+/// * `relax` is called whenever a node is reached with a strictly smaller cost than before;
+///   basic priority queues push a new entry while decrease-key queues update the existing one.
+/// * Basic priority queues might pop stale entries; algorithms are expected to skip them
+///   by comparing the popped cost with the current distance of the node.
+/// * `peek_key` might reorganize the queue as the next `pop` would, such as the monotone
+///   bucket queue and radix heap advancing to their minimum key.
+///
+/// Keys are of the crate wide `Weight` type unless the queue is used with a graph of another weight type.
+pub trait DijkstraQueue<K = Weight> {
+    fn for_graph<G: SpGraph>(graph: &G) -> Self;
+    fn clear(&mut self);
+    fn len(&self) -> usize;
+    fn capacity(&self) -> usize;
+    fn peek_key(&mut self) -> Option<K>;
+    fn pop(&mut self) -> Option<(usize, K)>;
+    fn relax(&mut self, node: usize, key: K);
+}
+
+// std
+impl<K: WeightType> DijkstraQueue<K> for BinaryHeap<State<K>> {
+    fn for_graph<G: SpGraph>(_: &G) -> Self {
+        Self::new()
+    }
+    fn clear(&mut self) {
+        BinaryHeap::clear(self)
+    }
+    fn len(&self) -> usize {
+        BinaryHeap::len(self)
+    }
+    fn capacity(&self) -> usize {
+        BinaryHeap::capacity(self)
+    }
+    fn peek_key(&mut self) -> Option<K> {
+        self.peek().map(|x| x.cost)
+    }
+    fn pop(&mut self) -> Option<(usize, K)> {
+        BinaryHeap::pop(self).map(|x| (x.position, x.cost))
+    }
+    #[inline(always)]
+    fn relax(&mut self, node: usize, key: K) {
+        self.push(State {
+            cost: key,
+            position: node,
+        })
+    }
+}
+
+// orx
+impl<K: WeightType, const D: usize> DijkstraQueue<K> for DaryHeap<usize, K, D> {
+    fn for_graph<G: SpGraph>(_: &G) -> Self {
+        Self::default()
+    }
+    fn clear(&mut self) {
+        PriorityQueue::clear(self)
+    }
+    fn len(&self) -> usize {
+        PriorityQueue::len(self)
+    }
+    fn capacity(&self) -> usize {
+        PriorityQueue::capacity(self)
+    }
+    fn peek_key(&mut self) -> Option<K> {
+        self.peek().map(|x| *x.key())
+    }
+    fn pop(&mut self) -> Option<(usize, K)> {
+        PriorityQueue::pop(self)
+    }
+    #[inline(always)]
+    fn relax(&mut self, node: usize, key: K) {
+        self.push(node, key)
+    }
+}
+impl<K: WeightType, const D: usize> DijkstraQueue<K> for DaryHeapOfIndices<usize, K, D> {
+    fn for_graph<G: SpGraph>(graph: &G) -> Self {
+        Self::with_index_bound(graph.num_nodes())
+    }
+    fn clear(&mut self) {
+        PriorityQueue::clear(self)
+    }
+    fn len(&self) -> usize {
+        PriorityQueue::len(self)
+    }
+    fn capacity(&self) -> usize {
+        PriorityQueue::capacity(self)
+    }
+    fn peek_key(&mut self) -> Option<K> {
+        self.peek().map(|x| *x.key())
+    }
+    fn pop(&mut self) -> Option<(usize, K)> {
+        PriorityQueue::pop(self)
+    }
+    #[inline(always)]
+    fn relax(&mut self, node: usize, key: K) {
+        self.decrease_key_or_push(&node, key);
+    }
+}
+impl<K: WeightType, const D: usize> DijkstraQueue<K> for DaryHeapWithMap<usize, K, D> {
+    fn for_graph<G: SpGraph>(_: &G) -> Self {
+        Self::default()
+    }
+    fn clear(&mut self) {
+        PriorityQueue::clear(self)
+    }
+    fn len(&self) -> usize {
+        PriorityQueue::len(self)
+    }
+    fn capacity(&self) -> usize {
+        PriorityQueue::capacity(self)
+    }
+    fn peek_key(&mut self) -> Option<K> {
+        self.peek().map(|x| *x.key())
+    }
+    fn pop(&mut self) -> Option<(usize, K)> {
+        PriorityQueue::pop(self)
+    }
+    #[inline(always)]
+    fn relax(&mut self, node: usize, key: K) {
+        self.decrease_key_or_push(&node, key);
+    }
+}
+
+// priority_queue: a max-priority queue, hence, the keys are reversed
+impl DijkstraQueue for priority_queue::PriorityQueue<usize, Reverse<Weight>> {
+    fn for_graph<G: SpGraph>(graph: &G) -> Self {
+        Self::with_capacity(graph.num_nodes())
+    }
+    fn clear(&mut self) {
+        priority_queue::PriorityQueue::clear(self)
+    }
+    fn len(&self) -> usize {
+        priority_queue::PriorityQueue::len(self)
+    }
+    fn capacity(&self) -> usize {
+        priority_queue::PriorityQueue::capacity(self)
+    }
+    fn peek_key(&mut self) -> Option<Weight> {
+        self.peek().map(|(_, key)| key.0)
+    }
+    fn pop(&mut self) -> Option<(usize, Weight)> {
+        priority_queue::PriorityQueue::pop(self).map(|(node, key)| (node, key.0))
+    }
+    #[inline(always)]
+    fn relax(&mut self, node: usize, key: Weight) {
+        self.push_increase(node, Reverse(key));
+    }
+}
@@ -0,0 +1,350 @@
+use super::{
+    measure::distances::Distances,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+    spfa::Spfa,
+    std_pq_dijkstra::State,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::collections::BinaryHeap;
+
+/// Row-major matrix of the shortest distances between all pairs of nodes,
+/// such that `d(source, sink)` is at `source * num_nodes + sink`; `Weight::MAX` represents an unreachable pair.
+///
+/// Sources from which a negative cycle is reachable are flagged, and their rows are not meaningful.
+pub struct DistanceMatrix {
+    num_nodes: usize,
+    distances: Vec<Weight>,
+    negative_cycle_sources: Vec<bool>,
+}
+
+impl DistanceMatrix {
+    /// Floyd-Warshall algorithm relaxing all pairs through each intermediate node in turn.
+    pub fn floyd_warshall<G: SpGraph>(graph: &G) -> Self {
+        let mut matrix = Self::from_edges(graph);
+        let n = matrix.num_nodes;
+        matrix.relax_block(0..n, 0..n, 0..n);
+        matrix.set_negative_cycle_sources();
+        matrix
+    }
+
+    /// Floyd-Warshall algorithm on square blocks of `block_size` nodes; each round of intermediate nodes
+    /// first relaxes the diagonal block, then the blocks in its row & column, and finally all remaining blocks.
+    pub fn blocked_floyd_warshall<G: SpGraph>(graph: &G, block_size: usize) -> Self {
+        let mut matrix = Self::from_edges(graph);
+        let n = matrix.num_nodes;
+        let block = |b: usize| b * block_size..((b + 1) * block_size).min(n);
+        let num_blocks = n.div_ceil(block_size);
+
+        for k in 0..num_blocks {
+            matrix.relax_block(block(k), block(k), block(k));
+            for b in (0..num_blocks).filter(|&b| b != k) {
+                matrix.relax_block(block(k), block(k), block(b));
+                matrix.relax_block(block(k), block(b), block(k));
+            }
+            for i in (0..num_blocks).filter(|&i| i != k) {
+                for j in (0..num_blocks).filter(|&j| j != k) {
+                    matrix.relax_block(block(k), block(i), block(j));
+                }
+            }
+        }
+        matrix.set_negative_cycle_sources();
+        matrix
+    }
+
+    /// Johnson's algorithm: Bellman-Ford distances from a virtual source connected to all nodes
+    /// are node potentials making all reduced weights non-negative, with which Dijkstra's algorithm is run from every node.
+    ///
+    /// Potentials do not exist with a negative cycle; then, Bellman-Ford is run from every node instead.
+    pub fn johnson<G: SpGraph>(graph: &G) -> Self {
+        match johnson_potentials(graph) {
+            Some(potentials) => {
+                let mut dijkstra = ReducedDijkstra::default();
+                let mut matrix = Self::unreached(graph.num_nodes());
+                for source in 0..graph.num_nodes() {
+                    let row = matrix.row_mut(source);
+                    dijkstra.run(graph, &potentials, source, row);
+                }
+                matrix
+            }
+            None => {
+                let mut spfa = Spfa::new(graph);
+                let mut matrix = Self::unreached(graph.num_nodes());
+                for source in 0..graph.num_nodes() {
+                    let solution: Solution<Distances> = spfa.run_cached_one_to_all(graph, source);
+                    match solution.measure.distances {
+                        Some(distances) => matrix.row_mut(source).copy_from_slice(&distances),
+                        None => matrix.negative_cycle_sources[source] = true,
+                    }
+                }
+                matrix
+            }
+        }
+    }
+
+    pub fn memory_bytes(&self) -> usize {
+        std::mem::size_of::<Weight>() * self.distances.len()
+            + std::mem::size_of::<bool>() * self.negative_cycle_sources.len()
+    }
+    pub fn num_nodes(&self) -> usize {
+        self.num_nodes
+    }
+    pub fn distance(&self, source: usize, sink: usize) -> Weight {
+        self.distances[source * self.num_nodes + sink]
+    }
+    pub fn row(&self, source: usize) -> &[Weight] {
+        &self.distances[source * self.num_nodes..(source + 1) * self.num_nodes]
+    }
+    pub fn has_negative_cycle_from(&self, source: usize) -> bool {
+        self.negative_cycle_sources[source]
+    }
+
+    // helpers
+    fn unreached(num_nodes: usize) -> Self {
+        Self {
+            num_nodes,
+            distances: vec![Weight::MAX; num_nodes * num_nodes],
+            negative_cycle_sources: vec![false; num_nodes],
+        }
+    }
+    fn from_edges<G: SpGraph>(graph: &G) -> Self {
+        let mut matrix = Self::unreached(graph.num_nodes());
+        let n = matrix.num_nodes;
+        for tail in 0..n {
+            matrix.distances[tail * n + tail] = 0;
+            let mut out_edges = graph.out_edges(tail);
+            while let Some(edge) = out_edges.next_edge() {
+                let d = &mut matrix.distances[tail * n + edge.head()];
+                *d = (*d).min(edge.weight());
+            }
+        }
+        matrix
+    }
+    fn row_mut(&mut self, source: usize) -> &mut [Weight] {
+        &mut self.distances[source * self.num_nodes..(source + 1) * self.num_nodes]
+    }
+    // relaxes the pairs (i, j) of the given ranges through the intermediate nodes k
+    fn relax_block(
+        &mut self,
+        intermediates: std::ops::Range<usize>,
+        rows: std::ops::Range<usize>,
+        columns: std::ops::Range<usize>,
+    ) {
+        let n = self.num_nodes;
+        for k in intermediates {
+            for i in rows.clone() {
+                let d_ik = self.distances[i * n + k];
+                if d_ik == Weight::MAX {
+                    continue;
+                }
+                for j in columns.clone() {
+                    let d_kj = self.distances[k * n + j];
+                    if d_kj != Weight::MAX && d_ik + d_kj < self.distances[i * n + j] {
+                        self.distances[i * n + j] = d_ik + d_kj;
+                    }
+                }
+            }
+        }
+    }
+    // a negative cycle is reachable from the source if it reaches a node with a negative distance to itself
+    fn set_negative_cycle_sources(&mut self) {
+        let n = self.num_nodes;
+        let on_negative_cycle: Vec<_> = (0..n)
+            .map(|k| self.distance(k, k) < Weight::default())
+            .collect();
+        for source in 0..n {
+            self.negative_cycle_sources[source] =
+                (0..n).any(|k| on_negative_cycle[k] && self.distance(source, k) != Weight::MAX);
+        }
+    }
+}
+
+// johnson
+fn johnson_potentials<G: SpGraph>(graph: &G) -> Option<Vec<Weight>> {
+    // distances from the virtual source, which has a zero weight edge to every node
+    let mut potentials = vec![0; graph.num_nodes()];
+    for _ in 0..=graph.num_nodes() {
+        let mut updated = false;
+        for tail in 0..graph.num_nodes() {
+            let mut out_edges = graph.out_edges(tail);
+            while let Some(edge) = out_edges.next_edge() {
+                let next = potentials[tail] + edge.weight();
+                if next < potentials[edge.head()] {
+                    potentials[edge.head()] = next;
+                    updated = true;
+                }
+            }
+        }
+        if !updated {
+            return Some(potentials);
+        }
+    }
+    None
+}
+
+#[derive(Default)]
+struct ReducedDijkstra {
+    heap: BinaryHeap<State>,
+    reduced_distances: Vec<Weight>,
+}
+impl ReducedDijkstra {
+    // reduced weight of (i, j) is w(i, j) + p(i) - p(j) >= 0; the distances are shifted back into the row
+    fn run<G: SpGraph>(
+        &mut self,
+        graph: &G,
+        potentials: &[Weight],
+        source: usize,
+        row: &mut [Weight],
+    ) {
+        self.reduced_distances.clear();
+        self.reduced_distances
+            .resize(graph.num_nodes(), Weight::MAX);
+        self.heap.clear();
+
+        self.reduced_distances[source] = 0;
+        self.heap.push(State {
+            cost: 0,
+            position: source,
+        });
+
+        while let Some(State { cost, position }) = self.heap.pop() {
+            if cost > self.reduced_distances[position] {
+                continue;
+            }
+            row[position] = cost + potentials[position] - potentials[source];
+
+            let mut out_edges = graph.out_edges(position);
+            while let Some(edge) = out_edges.next_edge() {
+                let head = edge.head();
+                let reduced_weight = edge.weight() + potentials[position] - potentials[head];
+                let next_cost = cost + reduced_weight;
+                if next_cost < self.reduced_distances[head] {
+                    self.reduced_distances[head] = next_cost;
+                    self.heap.push(State {
+                        cost: next_cost,
+                        position: head,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::{
+        apsp_query::ApspQuery,
+        test_utils::{
+            assert_same_distances, assert_same_one_to_all_distances, assert_same_tables,
+            random_graph, weights,
+        },
+    };
+    use proptest::prelude::*;
+    use std::rc::Rc;
+
+    fn assert_same_matrices(expected: &DistanceMatrix, found: &DistanceMatrix) {
+        assert_eq!(expected.num_nodes(), found.num_nodes());
+        for source in 0..expected.num_nodes() {
+            assert_eq!(
+                expected.has_negative_cycle_from(source),
+                found.has_negative_cycle_from(source),
+                "{}",
+                source
+            );
+            if !expected.has_negative_cycle_from(source) {
+                assert_eq!(expected.row(source), found.row(source), "{}", source);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn matrices_match_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..60usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+            block_size in 1..20usize,
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let floyd_warshall = DistanceMatrix::floyd_warshall(&graph);
+            assert_same_matrices(&floyd_warshall, &DistanceMatrix::blocked_floyd_warshall(&graph, block_size));
+            assert_same_matrices(&floyd_warshall, &DistanceMatrix::johnson(&graph));
+
+            let query = &mut ApspQuery::new(&graph, Rc::new(floyd_warshall));
+            assert_same_distances(&graph, queries_seed, query);
+            assert_same_one_to_all_distances(&graph, queries_seed, query);
+            assert_same_tables(&graph, queries_seed, query);
+        }
+    }
+
+    #[cfg(feature = "signed-weights")]
+    mod signed {
+        use super::*;
+        use crate::{
+            experimentation::factors::graph_data::random::{
+                GraphRandom, RandomResources, RandomWeights,
+            },
+            graph::{adjlist_jagged_vec::AdjListJaggedVec, sp_graph_builder::SpGraphBuilder},
+        };
+
+        #[test]
+        fn negative_cycle_sources() {
+            let mut builder = <AdjListJaggedVec as SpGraphBuilder>::new(Some(4), None);
+            (0..4).for_each(|node| builder.add_node(node, None));
+            builder.add_edge(0, 1, 1);
+            builder.add_edge(1, 2, -3);
+            builder.add_edge(2, 1, 1);
+            builder.add_edge(0, 3, 4);
+            let graph = builder.build();
+
+            for matrix in [
+                DistanceMatrix::floyd_warshall(&graph),
+                DistanceMatrix::blocked_floyd_warshall(&graph, 3),
+                DistanceMatrix::johnson(&graph),
+            ] {
+                let flagged: Vec<_> = (0..4).map(|s| matrix.has_negative_cycle_from(s)).collect();
+                assert_eq!(flagged, [true, true, true, false]);
+                assert_eq!(matrix.row(3), [Weight::MAX, Weight::MAX, Weight::MAX, 0]);
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn matrices_match_spfa_with_negative_weights(
+                seed in any::<u64>(),
+                num_nodes in 4..60usize,
+                density in 0.005f32..0.2,
+                block_size in 1..20usize,
+            ) {
+                let random = GraphRandom {
+                    seed,
+                    num_nodes,
+                    density,
+                    negative_weights: true,
+                    weights: RandomWeights::Uniform,
+                    resources: RandomResources::None,
+                };
+                let graph: AdjListJaggedVec = random.create_graph_builder::<AdjListJaggedVec>().build();
+
+                let mut spfa = Spfa::new(&graph);
+                let floyd_warshall = DistanceMatrix::floyd_warshall(&graph);
+                for source in 0..num_nodes {
+                    let solution: Solution<Distances> = spfa.run_cached_one_to_all(&graph, source);
+                    let expected = solution.measure.distances.expect("no negative cycle");
+                    assert_eq!(floyd_warshall.row(source), expected.as_slice(), "{}", source);
+                }
+                assert_same_matrices(&floyd_warshall, &DistanceMatrix::blocked_floyd_warshall(&graph, block_size));
+                assert_same_matrices(&floyd_warshall, &DistanceMatrix::johnson(&graph));
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use super::dijkstra_queue::DijkstraQueue;
+use crate::{
+    graph::{sp_graph::SpGraph, weight_type::WeightType},
+    Weight,
+};
+
+/// Fibonacci heap with decrease-key, stored in arrays indexed by the nodes of the graph.
+///
+/// Roots and siblings are kept in circular doubly linked lists; pop consolidates
+/// the roots by linking trees of equal degree, and decrease-key cuts the node
+/// from its parent followed by cascading cuts of the marked ancestors.
+///
+/// * `len` is the number of nodes in the heap,
+/// * `capacity` is the number of node slots of the arrays, which is the number of nodes of the graph.
+pub struct FibonacciHeap<K = Weight> {
+    key: Vec<K>,
+    parent: Vec<usize>,
+    child: Vec<usize>,
+    left: Vec<usize>,
+    right: Vec<usize>,
+    degree: Vec<usize>,
+    marked: Vec<bool>,
+    in_heap: Vec<bool>,
+    min: usize,
+    len: usize,
+    touched: Vec<usize>,
+    buffer: Vec<usize>,
+    roots_by_degree: Vec<usize>,
+}
+
+impl<K: WeightType> FibonacciHeap<K> {
+    // circular lists
+    fn unlink(&mut self, node: usize) {
+        let (left, right) = (self.left[node], self.right[node]);
+        self.right[left] = right;
+        self.left[right] = left;
+        self.left[node] = node;
+        self.right[node] = node;
+    }
+    fn insert_after(&mut self, position: usize, node: usize) {
+        let right = self.right[position];
+        self.left[node] = position;
+        self.right[node] = right;
+        self.left[right] = node;
+        self.right[position] = node;
+    }
+    fn add_root(&mut self, node: usize) {
+        self.parent[node] = NIL;
+        self.marked[node] = false;
+        match self.min {
+            NIL => {
+                self.left[node] = node;
+                self.right[node] = node;
+                self.min = node;
+            }
+            min => {
+                self.insert_after(min, node);
+                if self.key[node] < self.key[min] {
+                    self.min = node;
+                }
+            }
+        }
+    }
+
+    // trees
+    fn link(&mut self, child: usize, parent: usize) {
+        self.unlink(child);
+        match self.child[parent] {
+            NIL => self.child[parent] = child,
+            first_child => self.insert_after(first_child, child),
+        }
+        self.parent[child] = parent;
+        self.marked[child] = false;
+        self.degree[parent] += 1;
+    }
+    fn cut(&mut self, node: usize, parent: usize) {
+        if self.child[parent] == node {
+            self.child[parent] = match self.right[node] {
+                right if right == node => NIL,
+                right => right,
+            };
+        }
+        self.unlink(node);
+        self.degree[parent] -= 1;
+        self.add_root(node);
+    }
+    fn cascading_cut(&mut self, mut node: usize) {
+        loop {
+            let parent = self.parent[node];
+            if parent == NIL {
+                break;
+            }
+            if !self.marked[node] {
+                self.marked[node] = true;
+                break;
+            }
+            self.cut(node, parent);
+            node = parent;
+        }
+    }
+    fn consolidate(&mut self) {
+        let mut roots = std::mem::take(&mut self.buffer);
+        roots.clear();
+        let first = self.min;
+        let mut root = first;
+        loop {
+            roots.push(root);
+            root = self.right[root];
+            if root == first {
+                break;
+            }
+        }
+
+        for &root in &roots {
+            let mut x = root;
+            let mut degree = self.degree[x];
+            while self.roots_by_degree[degree] != NIL {
+                let mut y = self.roots_by_degree[degree];
+                if self.key[y] < self.key[x] {
+                    std::mem::swap(&mut x, &mut y);
+                }
+                self.link(y, x);
+                self.roots_by_degree[degree] = NIL;
+                degree += 1;
+            }
+            self.roots_by_degree[degree] = x;
+        }
+
+        self.min = NIL;
+        for degree in 0..self.roots_by_degree.len() {
+            let root = self.roots_by_degree[degree];
+            if root != NIL {
+                if self.min == NIL || self.key[root] < self.key[self.min] {
+                    self.min = root;
+                }
+                self.roots_by_degree[degree] = NIL;
+            }
+        }
+        self.buffer = roots;
+    }
+
+    fn push(&mut self, node: usize, key: K) {
+        self.key[node] = key;
+        self.child[node] = NIL;
+        self.degree[node] = 0;
+        self.in_heap[node] = true;
+        self.touched.push(node);
+        self.len += 1;
+        self.add_root(node);
+    }
+    fn decrease_key(&mut self, node: usize, key: K) {
+        self.key[node] = key;
+        let parent = self.parent[node];
+        if parent != NIL && key < self.key[parent] {
+            self.cut(node, parent);
+            self.cascading_cut(parent);
+        }
+        if key < self.key[self.min] {
+            self.min = node;
+        }
+    }
+}
+
+impl<K: WeightType> DijkstraQueue<K> for FibonacciHeap<K> {
+    fn for_graph<G: SpGraph>(graph: &G) -> Self {
+        let num_nodes = graph.num_nodes();
+        Self {
+            key: vec![K::INFINITY; num_nodes],
+            parent: vec![NIL; num_nodes],
+            child: vec![NIL; num_nodes],
+            left: vec![NIL; num_nodes],
+            right: vec![NIL; num_nodes],
+            degree: vec![0; num_nodes],
+            marked: vec![false; num_nodes],
+            in_heap: vec![false; num_nodes],
+            min: NIL,
+            len: 0,
+            touched: vec![],
+            buffer: vec![],
+            roots_by_degree: vec![NIL; MAX_DEGREE],
+        }
+    }
+    fn clear(&mut self) {
+        for &node in &self.touched {
+            self.in_heap[node] = false;
+        }
+        self.touched.clear();
+        self.min = NIL;
+        self.len = 0;
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn capacity(&self) -> usize {
+        self.key.len()
+    }
+    fn peek_key(&mut self) -> Option<K> {
+        match self.min {
+            NIL => None,
+            min => Some(self.key[min]),
+        }
+    }
+    fn pop(&mut self) -> Option<(usize, K)> {
+        let min = self.min;
+        if min == NIL {
+            return None;
+        }
+
+        // children become roots
+        let mut children = std::mem::take(&mut self.buffer);
+        children.clear();
+        let first = self.child[min];
+        if first != NIL {
+            let mut child = first;
+            loop {
+                children.push(child);
+                child = self.right[child];
+                if child == first {
+                    break;
+                }
+            }
+        }
+        for &child in &children {
+            self.parent[child] = NIL;
+            self.marked[child] = false;
+            self.insert_after(min, child);
+        }
+        self.buffer = children;
+        self.child[min] = NIL;
+
+        match self.right[min] {
+            right if right == min => self.min = NIL,
+            right => {
+                self.unlink(min);
+                self.min = right;
+                self.consolidate();
+            }
+        }
+
+        self.in_heap[min] = false;
+        self.len -= 1;
+        Some((min, self.key[min]))
+    }
+    #[inline(always)]
+    fn relax(&mut self, node: usize, key: K) {
+        match self.in_heap[node] {
+            true if key < self.key[node] => self.decrease_key(node, key),
+            true => {}
+            false => self.push(node, key),
+        }
+    }
+}
+
+const NIL: usize = usize::MAX;
+// degree of a node with n descendants is at most log_phi(n), which is below 1.45 * 64 for any n
+const MAX_DEGREE: usize = 96;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::{
+            queue_dijkstra::QueueDijkstra,
+            sd_algorithm::PureShortestDistanceAlgorithm,
+            test_utils::{
+                assert_same_distances, assert_same_keys_as_model, assert_same_one_to_all_distances,
+                queue_ops, random_graph, weights, QueueKind,
+            },
+        },
+        graph::adjlist_jagged_vec::AdjListJaggedVec,
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn relax_decreases_key() {
+        let graph: AdjListJaggedVec = vec![vec![]; 3];
+        let mut heap = FibonacciHeap::<Weight>::for_graph(&graph);
+        heap.relax(0, 10);
+        heap.relax(1, 7);
+        heap.relax(0, 5);
+        heap.relax(1, 9);
+        assert_eq!(heap.len(), 2);
+        assert_eq!(heap.pop(), Some((0, 5)));
+        assert_eq!(heap.pop(), Some((1, 7)));
+        assert_eq!(heap.pop(), None);
+    }
+
+    proptest! {
+        #[test]
+        fn pops_keys_in_order(ops in queue_ops(40, 1_000)) {
+            let graph: AdjListJaggedVec = vec![vec![]; 40];
+            let heap = &mut FibonacciHeap::<Weight>::for_graph(&graph);
+            assert_same_keys_as_model(heap, &ops, QueueKind::DecreaseKey);
+            heap.clear();
+            assert_same_keys_as_model(heap, &ops, QueueKind::DecreaseKey);
+        }
+
+        #[test]
+        fn dijkstra_matches_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let dijkstra = &mut QueueDijkstra::<FibonacciHeap, _>::new(&graph);
+            assert_same_distances(&graph, queries_seed, dijkstra);
+            assert_same_one_to_all_distances(&graph, queries_seed, dijkstra);
+        }
+    }
+}
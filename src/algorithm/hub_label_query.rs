@@ -0,0 +1,110 @@
+use super::{
+    hub_labels::HubLabels, measure::run_measure::Measure, sd_algorithm::ShortestDistanceAlgorithm,
+    solution::Solution,
+};
+use crate::{graph::sp_graph::SpGraph, Weight};
+use std::rc::Rc;
+
+/// Answers the queries by merging the forward label of the source with the backward labels of the sinks;
+/// no graph search is performed.
+pub struct HubLabelQuery {
+    labels: Rc<HubLabels>,
+    distances: Vec<Weight>,
+}
+
+impl HubLabelQuery {
+    pub fn new<G: SpGraph>(_: &G, labels: Rc<HubLabels>) -> Self {
+        Self {
+            labels,
+            distances: vec![],
+        }
+    }
+}
+
+impl<G: SpGraph> ShortestDistanceAlgorithm<G> for HubLabelQuery {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let solution = Solution::new(graph, source, sink);
+        match self.labels.distance(source, sink) {
+            Some(distance) => solution.reached(distance),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let solution = Solution::new_one_to_all(graph, source);
+        self.distances.clear();
+        self.distances.extend(
+            (0..graph.num_nodes())
+                .map(|sink| self.labels.distance(source, sink).unwrap_or(Weight::MAX)),
+        );
+        solution.all_reached(&self.distances)
+    }
+    fn run_cached_many_to_many<M: Measure>(
+        &mut self,
+        graph: &G,
+        sources: &[usize],
+        targets: &[usize],
+    ) -> Solution<M> {
+        let solution = Solution::new_many_to_many(graph, sources, targets);
+        self.distances.clear();
+        for &source in sources {
+            self.distances.extend(
+                targets
+                    .iter()
+                    .map(|&target| self.labels.distance(source, target).unwrap_or(Weight::MAX)),
+            );
+        }
+        solution.table_reached(&self.distances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::test_utils::{
+            assert_same_distances, assert_same_one_to_all_distances, assert_same_tables,
+            random_graph, weights,
+        },
+        graph::{adjlist_jagged_vec::AdjListJaggedVec, sp_graph_builder::SpGraphBuilder},
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn hub_covers_pruned_entries() {
+        // star with the center 0 which has the largest degree and hence is the first hub
+        let mut builder = <AdjListJaggedVec as SpGraphBuilder>::new(Some(6), None);
+        (0..6).for_each(|node| builder.add_node(node, None));
+        for leaf in 1..6 {
+            builder.add_edge(0, leaf, 1);
+            builder.add_edge(leaf, 0, 1);
+        }
+        let graph = builder.build();
+        let labels = HubLabels::by_degree(&graph);
+
+        for leaf in 1..6 {
+            assert_eq!(labels.forward_label_size(leaf), 2);
+            assert_eq!(labels.backward_label_size(leaf), 2);
+        }
+        assert_eq!(labels.distance(1, 2), Some(2));
+        assert_eq!(labels.distance(3, 0), Some(1));
+    }
+
+    proptest! {
+        #[test]
+        fn queries_match_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.1,
+            weights in weights(),
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            for labels in [HubLabels::by_degree(&graph), HubLabels::by_contraction_order(&graph)] {
+                let query = &mut HubLabelQuery::new(&graph, Rc::new(labels));
+                assert_same_distances(&graph, queries_seed, query);
+                assert_same_one_to_all_distances(&graph, queries_seed, query);
+                assert_same_tables(&graph, queries_seed, query);
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use super::{contraction_hierarchy::ContractionHierarchy, std_pq_dijkstra::State};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::collections::BinaryHeap;
+
+/// 2-hop hub labels computed by pruned landmark labeling (Akiba, Iwata & Yoshida).
+///
+/// Every node has a forward label of (hub, d(node, hub)) and a backward label of (hub, d(hub, node)) entries;
+/// d(s, t) is the minimum of d(s, hub) + d(hub, t) over the hubs common to the forward label of s and the backward label of t.
+///
+/// Hubs are identified by their positions in the node ordering, most important first;
+/// labels are sorted by hub, so that the labels of two nodes are merged in linear time.
+pub struct HubLabels {
+    forward: Labels,
+    backward: Labels,
+}
+
+impl HubLabels {
+    /// Nodes with larger total in & out degrees are processed first.
+    pub fn by_degree<G: SpGraph>(graph: &G) -> Self {
+        let reversed = graph.reversed();
+        let degree =
+            |node| graph.out_edges(node).count_edges() + reversed.out_edges(node).count_edges();
+        let mut order: Vec<_> = (0..graph.num_nodes()).collect();
+        order.sort_by_key(|&node| std::cmp::Reverse(degree(node)));
+        Self::new(graph, &order)
+    }
+
+    /// Nodes are processed in the reverse order of their contraction in the contraction hierarchy,
+    /// which ranks nodes covering many shortest paths higher.
+    pub fn by_contraction_order<G: SpGraph>(graph: &G) -> Self {
+        let hierarchy = ContractionHierarchy::new(graph);
+        let order: Vec<_> = hierarchy.nodes_by_rank().iter().rev().copied().collect();
+        Self::new(graph, &order)
+    }
+
+    pub fn distance(&self, source: usize, sink: usize) -> Option<Weight> {
+        let (forward_hubs, forward_distances) = self.forward.label(source);
+        let (backward_hubs, backward_distances) = self.backward.label(sink);
+
+        let mut distance: Option<Weight> = None;
+        let (mut i, mut j) = (0, 0);
+        while i < forward_hubs.len() && j < backward_hubs.len() {
+            match forward_hubs[i].cmp(&backward_hubs[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    let via_hub = forward_distances[i] + backward_distances[j];
+                    distance = Some(distance.map_or(via_hub, |d| d.min(via_hub)));
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        distance
+    }
+    pub fn forward_label_size(&self, node: usize) -> usize {
+        self.forward.label(node).0.len()
+    }
+    pub fn backward_label_size(&self, node: usize) -> usize {
+        self.backward.label(node).0.len()
+    }
+    /// Average and maximum number of forward & backward label entries of a node.
+    pub fn label_sizes(&self) -> (f32, usize) {
+        let num_nodes = self.forward.begin_index.len() - 1;
+        let size = |node| self.forward_label_size(node) + self.backward_label_size(node);
+        let total: usize = (0..num_nodes).map(size).sum();
+        let max = (0..num_nodes).map(size).max().unwrap_or(0);
+        let average = match num_nodes {
+            0 => 0.0,
+            _ => total as f32 / num_nodes as f32,
+        };
+        (average, max)
+    }
+    pub fn memory_bytes(&self) -> usize {
+        self.forward.memory_bytes() + self.backward.memory_bytes()
+    }
+
+    // construction
+    fn new<G: SpGraph>(graph: &G, order: &[usize]) -> Self {
+        let num_nodes = graph.num_nodes();
+        let reversed = graph.reversed();
+        let mut forward = vec![vec![]; num_nodes];
+        let mut backward = vec![vec![]; num_nodes];
+        let mut search = PrunedSearch::new(num_nodes);
+
+        for (hub, &node) in order.iter().enumerate() {
+            // d(hub, v) are added to the backward labels unless already covered by higher ranked hubs
+            search.run(graph, hub, node, &forward[node], &mut backward);
+            // d(v, hub) are added to the forward labels unless already covered by higher ranked hubs
+            search.run(&reversed, hub, node, &backward[node], &mut forward);
+        }
+
+        Self {
+            forward: Labels::new(forward),
+            backward: Labels::new(backward),
+        }
+    }
+}
+
+// labels
+struct Labels {
+    begin_index: Vec<usize>,
+    hubs: Vec<usize>,
+    distances: Vec<Weight>,
+}
+impl Labels {
+    fn new(labels: Vec<Vec<(usize, Weight)>>) -> Self {
+        let mut begin_index = Vec::with_capacity(labels.len() + 1);
+        begin_index.push(0);
+        let mut hubs = vec![];
+        let mut distances = vec![];
+        for label in labels {
+            for (hub, distance) in label {
+                hubs.push(hub);
+                distances.push(distance);
+            }
+            begin_index.push(hubs.len());
+        }
+        Self {
+            begin_index,
+            hubs,
+            distances,
+        }
+    }
+    fn label(&self, node: usize) -> (&[usize], &[Weight]) {
+        let range = self.begin_index[node]..self.begin_index[node + 1];
+        (&self.hubs[range.clone()], &self.distances[range])
+    }
+    fn memory_bytes(&self) -> usize {
+        (self.begin_index.len() + self.hubs.len()) * std::mem::size_of::<usize>()
+            + self.distances.len() * std::mem::size_of::<Weight>()
+    }
+}
+
+// pruned dijkstra
+struct PrunedSearch {
+    heap: BinaryHeap<State>,
+    distances: Vec<Weight>,
+    reached: Vec<usize>,
+    // distances between the root & the hubs of its own label, indexed by hub
+    root_label: Vec<Weight>,
+}
+impl PrunedSearch {
+    fn new(num_nodes: usize) -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            distances: vec![Weight::MAX; num_nodes],
+            reached: vec![],
+            root_label: vec![Weight::MAX; num_nodes],
+        }
+    }
+    // a node is pruned if the labels of higher ranked hubs already provide a distance which is at least as short
+    fn run<G: SpGraph>(
+        &mut self,
+        graph: &G,
+        hub: usize,
+        root: usize,
+        root_label: &[(usize, Weight)],
+        labels: &mut [Vec<(usize, Weight)>],
+    ) {
+        for &(h, d) in root_label {
+            self.root_label[h] = d;
+        }
+
+        self.distances[root] = 0;
+        self.reached.push(root);
+        self.heap.push(State {
+            cost: 0,
+            position: root,
+        });
+        while let Some(State { cost, position }) = self.heap.pop() {
+            if cost > self.distances[position] {
+                continue;
+            }
+
+            let covered = labels[position]
+                .iter()
+                .any(|&(h, d)| self.root_label[h] != Weight::MAX && self.root_label[h] + d <= cost);
+            if covered {
+                continue;
+            }
+            labels[position].push((hub, cost));
+
+            let mut out_edges = graph.out_edges(position);
+            while let Some(edge) = out_edges.next_edge() {
+                let head = edge.head();
+                let next_cost = cost + edge.weight();
+                if next_cost < self.distances[head] {
+                    if self.distances[head] == Weight::MAX {
+                        self.reached.push(head);
+                    }
+                    self.distances[head] = next_cost;
+                    self.heap.push(State {
+                        cost: next_cost,
+                        position: head,
+                    });
+                }
+            }
+        }
+
+        // reset
+        for &node in &self.reached {
+            self.distances[node] = Weight::MAX;
+        }
+        self.reached.clear();
+        for &(h, _) in root_label {
+            self.root_label[h] = Weight::MAX;
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::{
+    measure::run_measure::Measure, sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+};
+use crate::{
+    graph::{out_edges::OutEdgeData, resource_graph::ResourceGraph},
+    Weight,
+};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Label-setting algorithm for the resource-constrained shortest path problem, where the total resource
+/// of the path must not exceed the budget.
+///
+/// A label is a (distance, resource) pair of a path from the source to its node; labels are settled in
+/// the lexicographic order of their distances & resources, such that the first settled label of the sink is optimal.
+/// A new label is discarded if it exceeds the budget or if it is dominated by a label of its node with both
+/// a smaller or equal distance & resource; otherwise, the labels that it dominates are discarded.
+pub struct LabelSetting {
+    budget: Weight,
+    heap: BinaryHeap<Reverse<(Weight, Weight, usize)>>,
+    labels: Vec<Label>,
+    node_labels: Vec<Vec<usize>>,
+}
+
+struct Label {
+    node: usize,
+    distance: Weight,
+    resource: Weight,
+    dominated: bool,
+}
+
+impl LabelSetting {
+    pub fn new<G: ResourceGraph>(_: &G, budget: Weight) -> Self {
+        Self {
+            budget,
+            heap: Default::default(),
+            labels: Default::default(),
+            node_labels: Default::default(),
+        }
+    }
+    fn run_cached_core<G: ResourceGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+        solution: &mut Solution<M>,
+    ) -> Option<Weight> {
+        // reset
+        self.heap.clear();
+        self.labels.clear();
+        self.node_labels.iter_mut().for_each(|x| x.clear());
+        if self.node_labels.len() < graph.num_nodes() {
+            self.node_labels.resize(graph.num_nodes(), vec![]);
+        }
+
+        // init
+        self.push_label(source, 0, 0, solution);
+
+        // iterate
+        while let Some(Reverse((distance, resource, label))) = self.heap.pop() {
+            if self.labels[label].dominated {
+                continue;
+            }
+            let position = self.labels[label].node;
+            if position == sink {
+                return Some(distance);
+            }
+
+            for (edge, edge_resource) in graph.resource_out_edges(position) {
+                let next_resource = resource + edge_resource;
+                if next_resource > self.budget {
+                    continue;
+                }
+                let next_distance = distance + edge.weight();
+                if !self.is_dominated(edge.head(), next_distance, next_resource, solution) {
+                    self.push_label(edge.head(), next_distance, next_resource, solution);
+                }
+            }
+
+            solution.iterate(self.heap.len(), self.heap.capacity());
+        }
+
+        None
+    }
+
+    // helpers
+    fn is_dominated<M: Measure>(
+        &self,
+        node: usize,
+        distance: Weight,
+        resource: Weight,
+        solution: &mut Solution<M>,
+    ) -> bool {
+        self.node_labels[node].iter().any(|&label| {
+            solution.check_dominance();
+            let label = &self.labels[label];
+            label.distance <= distance && label.resource <= resource
+        })
+    }
+    // the new label is not dominated; hence, labels of the node that it dominates are discarded
+    fn push_label<M: Measure>(
+        &mut self,
+        node: usize,
+        distance: Weight,
+        resource: Weight,
+        solution: &mut Solution<M>,
+    ) {
+        let labels = &mut self.labels;
+        self.node_labels[node].retain(|&label| {
+            solution.check_dominance();
+            let label = &mut labels[label];
+            label.dominated = distance <= label.distance && resource <= label.resource;
+            !label.dominated
+        });
+
+        let label = labels.len();
+        labels.push(Label {
+            node,
+            distance,
+            resource,
+            dominated: false,
+        });
+        self.node_labels[node].push(label);
+        self.heap.push(Reverse((distance, resource, label)));
+        solution.create_label();
+    }
+}
+
+impl<G: ResourceGraph> ShortestDistanceAlgorithm<G> for LabelSetting {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new_resource_constrained(graph, source, sink, self.budget);
+        match self.run_cached_core(graph, source, sink, &mut solution) {
+            Some(distance) => solution.reached(distance),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        Solution::new_one_to_all(graph, source).decline()
+    }
+}
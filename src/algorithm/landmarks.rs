@@ -0,0 +1,276 @@
+use super::{potential::Potential, std_pq_dijkstra::State};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use std::collections::BinaryHeap;
+
+/// Landmarks together with the forward and backward distance tables
+/// providing triangle inequality lower bounds to the ALT algorithm.
+///
+/// Distances are stored row-wise per node such that the `i`-th landmark's entry for `node`
+/// is at `node * num_landmarks + i`; `Weight::MAX` represents an unreachable pair.
+pub struct Landmarks {
+    landmarks: Vec<usize>,
+    // d(landmark, node)
+    from_landmarks: Vec<Weight>,
+    // d(node, landmark)
+    to_landmarks: Vec<Weight>,
+}
+
+impl Landmarks {
+    /// Landmarks are sampled uniformly at random.
+    pub fn random<G: SpGraph>(graph: &G, num_landmarks: usize) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+        let num_landmarks = num_landmarks.min(graph.num_nodes());
+        let nodes: Vec<_> = (0..graph.num_nodes()).collect();
+        let chosen = nodes.choose_multiple(&mut rng, num_landmarks).copied();
+
+        let reversed = graph.reversed();
+        let mut landmarks = LandmarksBuilder::new(graph.num_nodes());
+        for landmark in chosen {
+            landmarks.add(graph, &reversed, landmark);
+        }
+        landmarks.into_row_major()
+    }
+
+    /// Each landmark is the reachable node which is farthest from the already selected landmarks;
+    /// the first landmark is the farthest node from a random node.
+    pub fn farthest<G: SpGraph>(graph: &G, num_landmarks: usize) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+        let num_landmarks = num_landmarks.min(graph.num_nodes());
+        let reversed = graph.reversed();
+        let mut landmarks = LandmarksBuilder::new(graph.num_nodes());
+
+        let start = rng.gen_range(0..graph.num_nodes());
+        let mut closest = shortest_path_tree(graph, start).distances;
+        while landmarks.num_landmarks() < num_landmarks {
+            let farthest = (0..graph.num_nodes())
+                .filter(|&v| closest[v] != Weight::MAX && !landmarks.landmarks.contains(&v))
+                .max_by_key(|&v| closest[v])
+                .or_else(|| (0..graph.num_nodes()).find(|v| !landmarks.landmarks.contains(v)));
+            let Some(landmark) = farthest else {
+                break;
+            };
+
+            landmarks.add(graph, &reversed, landmark);
+            let from_landmark = landmarks.from_landmarks.last().expect("landmark is added");
+            for (c, &d) in closest.iter_mut().zip(from_landmark) {
+                *c = if landmarks.num_landmarks() == 1 {
+                    d
+                } else {
+                    (*c).min(d)
+                };
+            }
+        }
+        landmarks.into_row_major()
+    }
+
+    /// Avoid heuristic of Goldberg & Werneck:
+    /// * grows a shortest path tree from a random root,
+    /// * weighs each node by the gap between its distance and the current lower bound,
+    /// * picks the subtree with the largest total gap that contains no landmark and
+    ///   descends to one of its leaves which becomes the next landmark.
+    pub fn avoid<G: SpGraph>(graph: &G, num_landmarks: usize) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+        let num_nodes = graph.num_nodes();
+        let num_landmarks = num_landmarks.min(num_nodes);
+        let reversed = graph.reversed();
+        let mut landmarks = LandmarksBuilder::new(num_nodes);
+
+        while landmarks.num_landmarks() < num_landmarks {
+            let root = rng.gen_range(0..num_nodes);
+            let tree = shortest_path_tree(graph, root);
+
+            let mut size = vec![0u128; num_nodes];
+            let mut has_landmark = vec![false; num_nodes];
+            for &v in tree.order.iter().rev() {
+                if landmarks.landmarks.contains(&v) {
+                    has_landmark[v] = true;
+                }
+                if !has_landmark[v] {
+                    let gap = tree.distances[v] - landmarks.lower_bound_col(root, v);
+                    size[v] += gap as u128;
+                }
+                if let Some(parent) = tree.parents[v] {
+                    has_landmark[parent] |= has_landmark[v];
+                    size[parent] += size[v];
+                }
+            }
+            for &v in &tree.order {
+                if has_landmark[v] {
+                    size[v] = 0;
+                }
+            }
+
+            let mut children = vec![vec![]; num_nodes];
+            for &v in &tree.order {
+                if let Some(parent) = tree.parents[v] {
+                    children[parent].push(v);
+                }
+            }
+
+            let top = tree.order.iter().copied().max_by_key(|&v| size[v]);
+            let landmark = match top {
+                Some(mut node) if size[node] > 0 => {
+                    while let Some(&child) = children[node]
+                        .iter()
+                        .filter(|&&c| size[c] > 0)
+                        .max_by_key(|&&c| size[c])
+                    {
+                        node = child;
+                    }
+                    node
+                }
+                // root's tree is covered; fall back to any node which is not a landmark yet
+                _ => match (0..num_nodes).find(|v| !landmarks.landmarks.contains(v)) {
+                    Some(node) => node,
+                    None => break,
+                },
+            };
+            landmarks.add(graph, &reversed, landmark);
+        }
+        landmarks.into_row_major()
+    }
+
+    pub fn memory_bytes(&self) -> usize {
+        self.landmarks.len() * std::mem::size_of::<usize>()
+            + (self.from_landmarks.len() + self.to_landmarks.len()) * std::mem::size_of::<Weight>()
+    }
+}
+
+impl Potential for Landmarks {
+    #[inline(always)]
+    fn potential(&self, node: usize, sink: usize) -> Weight {
+        let k = self.landmarks.len();
+        let (node_from, sink_from) = (
+            &self.from_landmarks[node * k..(node + 1) * k],
+            &self.from_landmarks[sink * k..(sink + 1) * k],
+        );
+        let (node_to, sink_to) = (
+            &self.to_landmarks[node * k..(node + 1) * k],
+            &self.to_landmarks[sink * k..(sink + 1) * k],
+        );
+
+        let mut bound = Weight::default();
+        for i in 0..k {
+            // d(v, t) >= d(L, t) - d(L, v)
+            if node_from[i] != Weight::MAX && sink_from[i] != Weight::MAX {
+                bound = bound.max(sink_from[i].saturating_sub(node_from[i]));
+            }
+            // d(v, t) >= d(v, L) - d(t, L)
+            if node_to[i] != Weight::MAX && sink_to[i] != Weight::MAX {
+                bound = bound.max(node_to[i].saturating_sub(sink_to[i]));
+            }
+        }
+        bound
+    }
+}
+
+// builder storing the distances column-wise per landmark while the landmarks are being selected
+struct LandmarksBuilder {
+    num_nodes: usize,
+    landmarks: Vec<usize>,
+    from_landmarks: Vec<Vec<Weight>>,
+    to_landmarks: Vec<Vec<Weight>>,
+}
+impl LandmarksBuilder {
+    fn new(num_nodes: usize) -> Self {
+        Self {
+            num_nodes,
+            landmarks: vec![],
+            from_landmarks: vec![],
+            to_landmarks: vec![],
+        }
+    }
+    fn num_landmarks(&self) -> usize {
+        self.landmarks.len()
+    }
+    fn add<G: SpGraph, R: SpGraph>(&mut self, graph: &G, reversed: &R, landmark: usize) {
+        self.landmarks.push(landmark);
+        self.from_landmarks
+            .push(shortest_path_tree(graph, landmark).distances);
+        self.to_landmarks
+            .push(shortest_path_tree(reversed, landmark).distances);
+    }
+    fn lower_bound_col(&self, source: usize, node: usize) -> Weight {
+        let mut bound = Weight::default();
+        for (from, to) in self.from_landmarks.iter().zip(&self.to_landmarks) {
+            if from[source] != Weight::MAX && from[node] != Weight::MAX {
+                bound = bound.max(from[node].saturating_sub(from[source]));
+            }
+            if to[source] != Weight::MAX && to[node] != Weight::MAX {
+                bound = bound.max(to[source].saturating_sub(to[node]));
+            }
+        }
+        bound
+    }
+    fn into_row_major(self) -> Landmarks {
+        let k = self.landmarks.len();
+        let mut from_landmarks = vec![Weight::MAX; self.num_nodes * k];
+        let mut to_landmarks = vec![Weight::MAX; self.num_nodes * k];
+        for i in 0..k {
+            for v in 0..self.num_nodes {
+                from_landmarks[v * k + i] = self.from_landmarks[i][v];
+                to_landmarks[v * k + i] = self.to_landmarks[i][v];
+            }
+        }
+        Landmarks {
+            landmarks: self.landmarks,
+            from_landmarks,
+            to_landmarks,
+        }
+    }
+}
+
+// one-to-all dijkstra
+struct ShortestPathTree {
+    distances: Vec<Weight>,
+    parents: Vec<Option<usize>>,
+    order: Vec<usize>,
+}
+fn shortest_path_tree<G: SpGraph>(graph: &G, source: usize) -> ShortestPathTree {
+    let mut distances = vec![Weight::MAX; graph.num_nodes()];
+    let mut parents = vec![None; graph.num_nodes()];
+    let mut order = vec![];
+    let mut heap = BinaryHeap::new();
+
+    distances[source] = 0;
+    heap.push(State {
+        cost: Weight::default(),
+        position: source,
+    });
+    while let Some(State { cost, position }) = heap.pop() {
+        if cost > distances[position] {
+            continue;
+        }
+        order.push(position);
+
+        let mut out_edges = graph.out_edges(position);
+        while let Some(edge) = out_edges.next_edge() {
+            let (head, weight) = (edge.head(), edge.weight());
+            let next_cost = cost + weight;
+            if next_cost < distances[head] {
+                distances[head] = next_cost;
+                parents[head] = Some(position);
+                heap.push(State {
+                    cost: next_cost,
+                    position: head,
+                });
+            }
+        }
+    }
+
+    ShortestPathTree {
+        distances,
+        parents,
+        order,
+    }
+}
+
+const SEED: u64 = 7542;
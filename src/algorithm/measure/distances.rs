@@ -0,0 +1,27 @@
+use super::run_measure::Measure;
+use crate::{
+    graph::{sp_graph::SpGraph, weight_type::WeightType},
+    Weight,
+};
+
+/// Keeps the distances found by a one-to-all query, or the row-major distance table of a many-to-many query,
+/// so that algorithms can be composed of other queries and results can be compared.
+#[derive(Default)]
+pub struct Distances {
+    pub distances: Option<Vec<Weight>>,
+}
+
+impl Measure for Distances {
+    fn initialize<G: SpGraph>(_: &G, _: usize, _: usize) -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    fn iterate(&mut self, _: usize, _: usize) {}
+    fn finalize(&mut self, _: Option<Weight>) {}
+    fn finalize_one_to_all<W: WeightType>(&mut self, distances: &[W]) {
+        self.distances = Some(distances.iter().map(|x| x.to_weight()).collect());
+    }
+    fn finalize_many_to_many(&mut self, table: &[Weight]) {
+        self.distances = Some(table.to_vec());
+    }
+}
@@ -8,6 +8,8 @@ pub struct Memory {
     pub total_num_items_in_observations: usize,
     pub max_capacity: usize,
     pub total_capacity_in_observations: usize,
+    pub num_labels: usize,
+    pub num_dominance_checks: usize,
 }
 impl Measure for Memory {
     fn initialize<G: SpGraph>(_: &G, _: usize, _: usize) -> Self {
@@ -18,6 +20,8 @@ impl Measure for Memory {
             total_num_items_in_observations: 0,
             max_capacity: 0,
             total_capacity_in_observations: 0,
+            num_labels: 0,
+            num_dominance_checks: 0,
         }
     }
     fn iterate(&mut self, heap_length: usize, heap_capacity: usize) {
@@ -30,6 +34,12 @@ impl Measure for Memory {
         self.max_capacity = self.max_capacity.max(heap_capacity);
         self.total_capacity_in_observations += heap_capacity;
     }
+    fn create_label(&mut self) {
+        self.num_labels += 1;
+    }
+    fn check_dominance(&mut self) {
+        self.num_dominance_checks += 1;
+    }
     fn finalize(&mut self, _: Option<Weight>) {
         self.time.stop()
     }
@@ -1,5 +1,7 @@
+pub mod distances;
 pub mod memory;
 pub mod no_measurement;
+pub mod preprocessing;
 pub mod run_measure;
 pub mod time;
 pub mod treatment_measure;
@@ -0,0 +1,54 @@
+use super::time::Time;
+use std::fmt::Display;
+
+/// One-off cost of the preprocessing phase of an algorithm, which is
+/// reported separately and never included in the per (s,t) pair measurements.
+pub struct Preprocessing {
+    pub elapsed_seconds: f32,
+    pub memory_bytes: usize,
+    pub stats: PreprocessingStats,
+}
+
+impl Preprocessing {
+    pub fn run<T, P, B>(preprocess: P, memory_bytes: B) -> (T, Self)
+    where
+        P: FnOnce() -> T,
+        B: Fn(&T) -> usize,
+    {
+        let mut time = Time::start();
+        let result = preprocess();
+        time.stop();
+        let preprocessing = Self {
+            elapsed_seconds: time.elapsed_seconds,
+            memory_bytes: memory_bytes(&result),
+            stats: PreprocessingStats::None,
+        };
+        (result, preprocessing)
+    }
+}
+
+/// Statistics of the preprocessed data specific to the algorithm.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum PreprocessingStats {
+    #[default]
+    None,
+    /// Edges added to the graph by contraction hierarchies.
+    Shortcuts(usize),
+    /// The preprocessing computes the complete distance matrix; its time & memory are reported
+    /// as the all-pairs shortest paths measurements.
+    DistanceMatrix,
+    /// Average and maximum number of label entries per node of hub labels.
+    LabelSizes(f32, usize),
+}
+
+impl Display for PreprocessingStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None | Self::DistanceMatrix => Ok(()),
+            Self::Shortcuts(num_shortcuts) => write!(f, "{} shortcuts", num_shortcuts),
+            Self::LabelSizes(average, max) => {
+                write!(f, "{:.2} average | {} maximum label size", average, max)
+            }
+        }
+    }
+}
@@ -1,7 +1,85 @@
-use crate::{graph::sp_graph::SpGraph, Weight};
+use crate::{
+    algorithm::path::ShortestPath,
+    graph::{
+        resource_graph::ResourceGraph, sp_graph::SpGraph, td_graph::TdGraph,
+        weight_type::WeightType,
+    },
+    Weight,
+};
 
 pub trait Measure {
     fn initialize<G: SpGraph>(graph: &G, source: usize, sink: usize) -> Self;
     fn iterate(&mut self, heap_length: usize, heap_capacity: usize);
     fn finalize(&mut self, shortest_distance: Option<Weight>);
+    fn negative_cycle(&mut self) {}
+    fn finalize_path<G: SpGraph>(&mut self, _graph: &G, _path: &ShortestPath) {}
+    fn finalize_k_paths<G: SpGraph>(&mut self, _graph: &G, _paths: &[ShortestPath]) {}
+    fn create_label(&mut self) {}
+    fn check_dominance(&mut self) {}
+
+    // measures comparing the found solution with a reference solution on the original graph of the graph data;
+    // `original_node` maps the nodes of the graph the algorithm runs on, which might be reordered, to the original nodes
+    const REQUIRES_REFERENCE: bool = false;
+    fn set_reference<G: SpGraph, F: Fn(usize) -> usize>(
+        &mut self,
+        _original_graph: &G,
+        _has_negative_weights: bool,
+        _original_node: F,
+    ) {
+    }
+
+    // one-to-all & many-to-many queries; measures which do not depend on the sink behave as in one-to-one queries
+    fn initialize_one_to_all<G: SpGraph>(graph: &G, source: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::initialize(graph, source, source)
+    }
+    fn finalize_one_to_all<W: WeightType>(&mut self, _distances: &[W]) {
+        self.finalize(None)
+    }
+    fn initialize_many_to_many<G: SpGraph>(graph: &G, sources: &[usize], targets: &[usize]) -> Self
+    where
+        Self: Sized,
+    {
+        Self::initialize(graph, sources[0], targets[0])
+    }
+    fn finalize_many_to_many(&mut self, _table: &[Weight]) {
+        self.finalize(None)
+    }
+
+    // nearest of a target set queries; measures which do not depend on the sink behave as in one-to-one queries
+    fn initialize_nearest<G: SpGraph>(graph: &G, source: usize, targets: &[usize]) -> Self
+    where
+        Self: Sized,
+    {
+        Self::initialize(graph, source, targets[0])
+    }
+    fn finalize_nearest(&mut self, _target: usize) {}
+
+    // time-dependent queries departing from the source at the given time; the shortest distance is the travel time
+    fn initialize_time_dependent<G: TdGraph>(
+        graph: &G,
+        source: usize,
+        sink: usize,
+        _departure_time: Weight,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::initialize(graph, source, sink)
+    }
+
+    // resource-constrained queries where the total resource of the path must not exceed the budget
+    fn initialize_resource_constrained<G: ResourceGraph>(
+        graph: &G,
+        source: usize,
+        sink: usize,
+        _budget: Weight,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::initialize(graph, source, sink)
+    }
 }
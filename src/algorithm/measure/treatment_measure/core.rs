@@ -1,10 +1,16 @@
-use crate::{algorithm::measure::run_measure::Measure, experimentation::treatment::Treatment};
+use crate::{
+    algorithm::measure::{preprocessing::Preprocessing, run_measure::Measure},
+    experimentation::treatment::Treatment,
+    graph::sp_graph::SpGraph,
+};
 use std::io::Write;
 
 pub trait TreatmentMeasure: Default {
     type M: Measure;
 
     fn aggregate(&mut self, run_measure: Self::M);
+    fn aggregate_preprocessing(&mut self, _preprocessing: Preprocessing) {}
+    fn aggregate_graph<G: SpGraph>(&mut self, _graph: &G) {}
 
     // cli
     fn log(&self);
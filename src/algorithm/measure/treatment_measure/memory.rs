@@ -1,7 +1,11 @@
 use super::{core::TreatmentMeasure, time::TreatmentTime};
 use crate::{
-    algorithm::measure::memory::Memory,
+    algorithm::measure::{
+        memory::Memory,
+        preprocessing::{Preprocessing, PreprocessingStats},
+    },
     experimentation::{factors::factor::Factor, treatment::Treatment},
+    graph::sp_graph::SpGraph,
     utils::cli,
 };
 use std::io::Write;
@@ -14,6 +18,12 @@ pub struct TreatmentMemory {
     pub total_num_items_in_observations: usize,
     pub max_capacity: usize,
     pub total_capacity_in_observations: usize,
+    pub graph_memory_bytes: Option<usize>,
+    pub preprocessing_memory_bytes: Option<usize>,
+    pub distance_matrix_memory_bytes: Option<usize>,
+    pub label_sizes: Option<(f32, usize)>,
+    pub num_labels: usize,
+    pub num_dominance_checks: usize,
 }
 impl TreatmentMemory {
     pub fn average_heap_size(&self) -> f32 {
@@ -23,6 +33,27 @@ impl TreatmentMemory {
             self.total_num_items_in_observations as f32 / self.num_iterations as f32
         }
     }
+    pub fn average_num_settled_nodes(&self) -> f32 {
+        if self.time.count == 0 {
+            0.0
+        } else {
+            self.num_iterations as f32 / self.time.count as f32
+        }
+    }
+    pub fn average_num_labels(&self) -> f32 {
+        if self.time.count == 0 {
+            0.0
+        } else {
+            self.num_labels as f32 / self.time.count as f32
+        }
+    }
+    pub fn average_num_dominance_checks(&self) -> f32 {
+        if self.time.count == 0 {
+            0.0
+        } else {
+            self.num_dominance_checks as f32 / self.time.count as f32
+        }
+    }
     pub fn average_heap_capacity(&self) -> f32 {
         if self.num_iterations == 0 {
             0.0
@@ -44,17 +75,68 @@ impl TreatmentMeasure for TreatmentMemory {
         self.max_capacity = self.max_capacity.max(run_measure.max_capacity);
         self.total_capacity_in_observations += run_measure.total_capacity_in_observations;
 
+        self.num_labels += run_measure.num_labels;
+        self.num_dominance_checks += run_measure.num_dominance_checks;
+
         self.time.aggregate(run_measure.time);
     }
+    fn aggregate_preprocessing(&mut self, preprocessing: Preprocessing) {
+        match preprocessing.stats {
+            PreprocessingStats::DistanceMatrix => {
+                self.distance_matrix_memory_bytes = Some(preprocessing.memory_bytes)
+            }
+            _ => self.preprocessing_memory_bytes = Some(preprocessing.memory_bytes),
+        }
+        if let PreprocessingStats::LabelSizes(average, max) = preprocessing.stats {
+            self.label_sizes = Some((average, max));
+        }
+        self.time.aggregate_preprocessing(preprocessing);
+    }
+    fn aggregate_graph<G: SpGraph>(&mut self, graph: &G) {
+        self.graph_memory_bytes = graph.memory_bytes();
+    }
     fn log(&self) {
-        let info = format!(
+        let mut info = format!(
             "average | maximum heap length   : {:.2} | {}\n\
-            average | maximum heap capacity : {:.2} | {}",
+            average | maximum heap capacity : {:.2} | {}\n\
+            average settled nodes           : {:.2}",
             self.average_heap_size(),
             self.max_num_items,
             self.average_heap_capacity(),
-            self.max_capacity
+            self.max_capacity,
+            self.average_num_settled_nodes()
         );
+        if let Some(bytes) = self.graph_memory_bytes {
+            info.push_str(&format!(
+                "\ngraph memory                    : {} bytes",
+                bytes
+            ));
+        }
+        if let Some(bytes) = self.preprocessing_memory_bytes {
+            info.push_str(&format!(
+                "\npreprocessed data memory        : {} bytes",
+                bytes
+            ));
+        }
+        if let Some(bytes) = self.distance_matrix_memory_bytes {
+            info.push_str(&format!(
+                "\ndistance matrix memory          : {} bytes",
+                bytes
+            ));
+        }
+        if let Some((average, max)) = self.label_sizes {
+            info.push_str(&format!(
+                "\naverage | maximum label size    : {:.2} | {}",
+                average, max
+            ));
+        }
+        if self.num_labels > 0 {
+            info.push_str(&format!(
+                "\naverage labels | dominance checks: {:.2} | {:.2}",
+                self.average_num_labels(),
+                self.average_num_dominance_checks()
+            ));
+        }
 
         cli::print_treatment_completion_log(&info);
     }
@@ -63,12 +145,18 @@ impl TreatmentMeasure for TreatmentMemory {
         use crate::experimentation::factors::*;
         writeln!(
             w,
-            "i\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\tAverage Heap Size\tMaximum Number of Items\tAverage Heap Capacity\tMaximum Heap Capacity",
+            "i\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\tAverage Heap Size\tMaximum Number of Items\tAverage Heap Capacity\tMaximum Heap Capacity\tAverage Number of Settled Nodes\tGraph Memory Bytes\tPreprocessing Memory Bytes\tDistance Matrix Memory Bytes\tAverage Label Size\tMaximum Label Size\tAverage Number of Labels\tAverage Number of Dominance Checks",
             graph_data::factor_graph_data::FactorGraphData::name_factor(),
             graph_representation::FactorGraphRepresentation::name_factor(),
+            node_ordering::FactorNodeOrdering::name_factor(),
+            weight_type::FactorWeightType::name_factor(),
             algorithm::factor_algorithm::FactorAlgorithm::name_factor(),
+            partition_size::FactorPartitionSize::name_factor(),
+            num_paths::FactorNumPaths::name_factor(),
+            delta::FactorDelta::name_factor(),
+            num_threads::FactorNumThreads::name_factor(),
             algorithm_data::FactorAlgorithmData::name_factor(),
-            pairs::FactorPairs::name_factor(),
+            queries::FactorQueries::name_factor(),
         )
         .expect("failed to write result");
     }
@@ -80,17 +168,38 @@ impl TreatmentMeasure for TreatmentMemory {
     ) {
         writeln!(
             w,
-            "{}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{}\t{}\t{}\t{}",
+            "{}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
             current_treatment_idx,
             treatment.graph_data,
             treatment.graph_representation,
+            treatment.node_ordering,
+            treatment.weight_type,
             treatment.algorithm,
+            treatment.partition_size,
+            treatment.num_paths,
+            treatment.delta,
+            treatment.num_threads,
             treatment.algorithm_data,
-            treatment.pairs,
+            treatment.queries,
             self.average_heap_size(),
             self.max_num_items,
             self.average_heap_capacity(),
-            self.max_capacity
+            self.max_capacity,
+            self.average_num_settled_nodes(),
+            // missing rather than zero for representations not reporting their memory
+            self.graph_memory_bytes
+                .map_or(String::new(), |bytes| bytes.to_string()),
+            // missing rather than zero for algorithms without these phases
+            self.preprocessing_memory_bytes
+                .map_or(String::new(), |bytes| bytes.to_string()),
+            self.distance_matrix_memory_bytes
+                .map_or(String::new(), |bytes| bytes.to_string()),
+            self.label_sizes
+                .map_or(String::new(), |(average, _)| average.to_string()),
+            self.label_sizes
+                .map_or(String::new(), |(_, max)| max.to_string()),
+            self.average_num_labels(),
+            self.average_num_dominance_checks()
         )
         .expect("failed to write result");
     }
@@ -1,5 +1,6 @@
 pub mod core;
 pub mod memory;
+#[allow(dead_code)]
 pub mod no_measurement;
 pub mod time;
 pub mod validation;
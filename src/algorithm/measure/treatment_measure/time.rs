@@ -1,6 +1,9 @@
 use super::core::TreatmentMeasure;
 use crate::{
-    algorithm::measure::time::Time,
+    algorithm::measure::{
+        preprocessing::{Preprocessing, PreprocessingStats},
+        time::Time,
+    },
     experimentation::{factors::factor::Factor, treatment::Treatment},
     utils::cli,
 };
@@ -10,6 +13,9 @@ use std::io::Write;
 pub struct TreatmentTime {
     pub count: usize,
     pub total_elapsed_secs: f32,
+    pub preprocessing_elapsed_secs: Option<f32>,
+    pub preprocessing_stats: PreprocessingStats,
+    pub all_pairs_elapsed_secs: Option<f32>,
 }
 impl TreatmentTime {
     pub fn elapsed_secs_per_treatment(&self) -> f32 {
@@ -28,12 +34,41 @@ impl TreatmentMeasure for TreatmentTime {
         self.count += 1;
         self.total_elapsed_secs += run_measure.elapsed_seconds;
     }
+    fn aggregate_preprocessing(&mut self, preprocessing: Preprocessing) {
+        match preprocessing.stats {
+            PreprocessingStats::DistanceMatrix => {
+                self.all_pairs_elapsed_secs = Some(preprocessing.elapsed_seconds)
+            }
+            stats => {
+                self.preprocessing_elapsed_secs = Some(preprocessing.elapsed_seconds);
+                self.preprocessing_stats = stats;
+            }
+        }
+    }
     fn log(&self) {
-        let info = format!(
+        let mut info = format!(
             "total | average-per-problem duration : {:.4} | {:.4} seconds",
             self.total_elapsed_secs,
             self.elapsed_secs_per_treatment()
         );
+        if let Some(secs) = self.preprocessing_elapsed_secs {
+            info.push_str(&format!(
+                "\npreprocessing duration               : {:.4} seconds",
+                secs
+            ));
+        }
+        if let Some(secs) = self.all_pairs_elapsed_secs {
+            info.push_str(&format!(
+                "\nall-pairs shortest paths duration    : {:.4} seconds",
+                secs
+            ));
+        }
+        if !matches!(self.preprocessing_stats, PreprocessingStats::None) {
+            info.push_str(&format!(
+                "\npreprocessing stats                  : {}",
+                self.preprocessing_stats
+            ));
+        }
         cli::print_treatment_completion_log(&info);
     }
 
@@ -41,12 +76,18 @@ impl TreatmentMeasure for TreatmentTime {
         use crate::experimentation::factors::*;
         writeln!(
             w,
-            "i\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\tTotal Elapsed Seconds\tElapsed Seconds per Problem",
+            "i\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\tTotal Elapsed Seconds\tElapsed Seconds per Problem\tPreprocessing Elapsed Seconds\tPreprocessing Stats\tAll-Pairs Elapsed Seconds",
             graph_data::factor_graph_data::FactorGraphData::name_factor(),
             graph_representation::FactorGraphRepresentation::name_factor(),
+            node_ordering::FactorNodeOrdering::name_factor(),
+            weight_type::FactorWeightType::name_factor(),
             algorithm::factor_algorithm::FactorAlgorithm::name_factor(),
+            partition_size::FactorPartitionSize::name_factor(),
+            num_paths::FactorNumPaths::name_factor(),
+            delta::FactorDelta::name_factor(),
+            num_threads::FactorNumThreads::name_factor(),
             algorithm_data::FactorAlgorithmData::name_factor(),
-            pairs::FactorPairs::name_factor(),
+            queries::FactorQueries::name_factor(),
         )
         .expect("failed to write result");
     }
@@ -58,15 +99,27 @@ impl TreatmentMeasure for TreatmentTime {
     ) {
         writeln!(
             w,
-            "{}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{}\t{}",
+            "{}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{}\t{}\t{}\t{}\t{}",
             current_treatment_idx,
             treatment.graph_data,
             treatment.graph_representation,
+            treatment.node_ordering,
+            treatment.weight_type,
             treatment.algorithm,
+            treatment.partition_size,
+            treatment.num_paths,
+            treatment.delta,
+            treatment.num_threads,
             treatment.algorithm_data,
-            treatment.pairs,
+            treatment.queries,
             self.total_elapsed_secs,
-            self.elapsed_secs_per_treatment()
+            self.elapsed_secs_per_treatment(),
+            // missing rather than zero for algorithms without these phases
+            self.preprocessing_elapsed_secs
+                .map_or(String::new(), |secs| secs.to_string()),
+            self.preprocessing_stats,
+            self.all_pairs_elapsed_secs
+                .map_or(String::new(), |secs| secs.to_string())
         )
         .expect("failed to write result");
     }
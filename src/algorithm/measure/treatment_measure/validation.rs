@@ -29,12 +29,18 @@ impl TreatmentMeasure for TreatmentValidation {
         use crate::experimentation::factors::*;
         writeln!(
             w,
-            "i\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\tNumber of Wrong Results\tFirst Wrong Source Sink Pair",
+            "i\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\tNumber of Wrong Results\tFirst Wrong Query",
             graph_data::factor_graph_data::FactorGraphData::name_factor(),
             graph_representation::FactorGraphRepresentation::name_factor(),
+            node_ordering::FactorNodeOrdering::name_factor(),
+            weight_type::FactorWeightType::name_factor(),
             algorithm::factor_algorithm::FactorAlgorithm::name_factor(),
+            partition_size::FactorPartitionSize::name_factor(),
+            num_paths::FactorNumPaths::name_factor(),
+            delta::FactorDelta::name_factor(),
+            num_threads::FactorNumThreads::name_factor(),
             algorithm_data::FactorAlgorithmData::name_factor(),
-            pairs::FactorPairs::name_factor(),
+            queries::FactorQueries::name_factor(),
         )
         .expect("failed to write result");
     }
@@ -46,17 +52,21 @@ impl TreatmentMeasure for TreatmentValidation {
     ) {
         writeln!(
             w,
-            "{}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{}\t{:?}",
+            "{}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{}\t{:?}",
             current_treatment_idx,
             treatment.graph_data,
             treatment.graph_representation,
+            treatment.node_ordering,
+            treatment.weight_type,
             treatment.algorithm,
+            treatment.partition_size,
+            treatment.num_paths,
+            treatment.delta,
+            treatment.num_threads,
             treatment.algorithm_data,
-            treatment.pairs,
+            treatment.queries,
             self.wrong_results.len(),
-            self.wrong_results
-                .first()
-                .map(|f| format!("{}-{}", f.source, f.sink))
+            self.wrong_results.first().map(|f| f.query())
         )
         .expect("failed to write result");
     }
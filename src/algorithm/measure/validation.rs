@@ -1,30 +1,100 @@
-use super::{no_measurement::NoMeasurement, run_measure::Measure};
+use super::{distances::Distances, no_measurement::NoMeasurement, run_measure::Measure};
 use crate::{
-    algorithm::{sd_algorithm::ShortestDistanceAlgorithm, std_pq_dijkstra::StdPqDijkstra},
-    graph::sp_graph::SpGraph,
+    algorithm::{
+        bellman_ford::BellmanFord,
+        path::ShortestPath,
+        sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+        solution::Solution,
+        std_pq_dijkstra::StdPqDijkstra,
+    },
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        resource_graph::ResourceGraph,
+        sp_graph::SpGraph,
+        td_graph::TdGraph,
+        weight_type::WeightType,
+    },
     Weight,
 };
+use itertools::Itertools;
+use std::collections::{HashSet, VecDeque};
 
+/// Compares the found solution with the solution of a reference algorithm;
+/// one-to-all queries, which have no sink, are validated by the entire distance vectors,
+/// many-to-many queries by the row-major distance tables of their `table` sources & targets,
+/// nearest of a target set queries by brute force distances to each target, where the reported target must be one of the nearest,
+/// and returned paths must consist of edges of the graph adding up to the found distance;
+/// k shortest paths must further be distinct simple paths with non-decreasing distances.
+///
+/// Reference solutions are computed on the original graph of the graph data with the crate wide `Weight`,
+/// rather than the possibly reordered graph the algorithm runs on; see `set_reference`.
 #[derive(Debug)]
 pub struct Validation {
     pub source: usize,
-    pub sink: usize,
+    pub sink: Option<usize>,
+    pub table: Option<(Vec<usize>, Vec<usize>)>,
+    pub nearest_targets: Option<Vec<(usize, Option<Weight>)>>,
     pub correct_shortest_distance: Option<Weight>,
     pub found_shortest_distance: Option<Weight>,
+    pub correct_distances: Option<Vec<Weight>>,
+    pub found_distances: Option<Vec<Weight>>,
+    pub correct_negative_cycle: bool,
+    pub found_negative_cycle: bool,
+    pub found_invalid_path: bool,
+    pub found_invalid_target: bool,
+    found_target: Option<usize>,
+    has_reference: bool,
 }
 impl Validation {
     pub fn is_correct(&self) -> bool {
+        debug_assert!(self.has_reference, "reference solution must be set");
         self.correct_shortest_distance == self.found_shortest_distance
+            && self.correct_distances == self.found_distances
+            && self.correct_negative_cycle == self.found_negative_cycle
+            && !self.found_invalid_path
+            && !self.found_invalid_target
+    }
+    pub fn query(&self) -> String {
+        if let Some((sources, targets)) = &self.table {
+            let first_wrong = (self.correct_distances.iter().flatten())
+                .zip(self.found_distances.iter().flatten())
+                .position(|(correct, found)| correct != found);
+            return match first_wrong {
+                Some(i) => format!(
+                    "{}-{}",
+                    sources[i / targets.len()],
+                    targets[i % targets.len()]
+                ),
+                None => format!("{}x{} table", sources.len(), targets.len()),
+            };
+        }
+        if let Some(targets) = &self.nearest_targets {
+            return format!("{}-nearest of {} targets", self.source, targets.len());
+        }
+        match self.sink {
+            Some(sink) => format!("{}-{}", self.source, sink),
+            None => self.source.to_string(),
+        }
     }
 }
 
 impl Measure for Validation {
-    fn initialize<G: SpGraph>(graph: &G, source: usize, sink: usize) -> Self {
+    fn initialize<G: SpGraph>(_: &G, source: usize, sink: usize) -> Self {
         Self {
             source,
-            sink,
-            correct_shortest_distance: get_valid_solution(graph, source, sink),
+            sink: Some(sink),
+            table: None,
+            nearest_targets: None,
+            correct_shortest_distance: None,
             found_shortest_distance: Some(Weight::MAX),
+            correct_distances: None,
+            found_distances: None,
+            correct_negative_cycle: false,
+            found_negative_cycle: false,
+            found_invalid_path: false,
+            found_invalid_target: false,
+            found_target: None,
+            has_reference: false,
         }
     }
     #[inline(always)]
@@ -32,8 +102,293 @@ impl Measure for Validation {
     fn finalize(&mut self, shortest_distance: Option<Weight>) {
         self.found_shortest_distance = shortest_distance;
     }
+    fn negative_cycle(&mut self) {
+        self.found_negative_cycle = true;
+    }
+    fn finalize_path<G: SpGraph>(&mut self, graph: &G, path: &ShortestPath) {
+        let sink = self
+            .sink
+            .expect("paths are only computed by one-to-one queries");
+        self.found_invalid_path = !is_path(graph, self.source, sink, path)
+            || Some(path.distance()) != self.found_shortest_distance;
+    }
+    fn finalize_k_paths<G: SpGraph>(&mut self, graph: &G, paths: &[ShortestPath]) {
+        let sink = self
+            .sink
+            .expect("paths are only computed by one-to-one queries");
+        let is_simple = |path: &ShortestPath| {
+            let nodes: HashSet<_> = path.nodes.iter().collect();
+            nodes.len() == path.nodes.len()
+        };
+        let distinct: HashSet<_> = paths.iter().map(|path| &path.nodes).collect();
+        self.found_invalid_path = distinct.len() != paths.len()
+            || Some(paths[0].distance()) != self.found_shortest_distance
+            || paths.windows(2).any(|x| x[0].distance() > x[1].distance())
+            || paths
+                .iter()
+                .any(|path| !is_path(graph, self.source, sink, path) || !is_simple(path));
+    }
+    fn initialize_nearest<G: SpGraph>(_: &G, source: usize, targets: &[usize]) -> Self {
+        Self {
+            source,
+            sink: None,
+            table: None,
+            nearest_targets: Some(targets.iter().map(|&target| (target, None)).collect()),
+            correct_shortest_distance: None,
+            found_shortest_distance: Some(Weight::MAX),
+            correct_distances: None,
+            found_distances: None,
+            correct_negative_cycle: false,
+            found_negative_cycle: false,
+            found_invalid_path: false,
+            found_invalid_target: false,
+            found_target: None,
+            has_reference: false,
+        }
+    }
+    fn finalize_nearest(&mut self, target: usize) {
+        // validated against the distances to the targets once the reference is set
+        self.found_target = Some(target);
+    }
+    fn initialize_time_dependent<G: TdGraph>(
+        graph: &G,
+        source: usize,
+        sink: usize,
+        departure_time: Weight,
+    ) -> Self {
+        Self {
+            correct_shortest_distance: get_valid_time_dependent_solution(
+                graph,
+                source,
+                sink,
+                departure_time,
+            ),
+            has_reference: true,
+            ..Self::initialize(graph, source, sink)
+        }
+    }
+    fn initialize_resource_constrained<G: ResourceGraph>(
+        graph: &G,
+        source: usize,
+        sink: usize,
+        budget: Weight,
+    ) -> Self {
+        Self {
+            correct_shortest_distance: get_valid_resource_constrained_solution(
+                graph, source, sink, budget,
+            ),
+            has_reference: true,
+            ..Self::initialize(graph, source, sink)
+        }
+    }
+    fn initialize_one_to_all<G: SpGraph>(_: &G, source: usize) -> Self {
+        Self {
+            source,
+            sink: None,
+            table: None,
+            nearest_targets: None,
+            correct_shortest_distance: None,
+            found_shortest_distance: None,
+            correct_distances: None,
+            found_distances: None,
+            correct_negative_cycle: false,
+            found_negative_cycle: false,
+            found_invalid_path: false,
+            found_invalid_target: false,
+            found_target: None,
+            has_reference: false,
+        }
+    }
+    fn finalize_one_to_all<W: WeightType>(&mut self, distances: &[W]) {
+        self.found_distances = Some(distances.iter().map(|x| x.to_weight()).collect());
+    }
+    fn initialize_many_to_many<G: SpGraph>(_: &G, sources: &[usize], targets: &[usize]) -> Self {
+        Self {
+            source: sources[0],
+            sink: None,
+            table: Some((sources.to_vec(), targets.to_vec())),
+            nearest_targets: None,
+            correct_shortest_distance: None,
+            found_shortest_distance: None,
+            correct_distances: None,
+            found_distances: None,
+            correct_negative_cycle: false,
+            found_negative_cycle: false,
+            found_invalid_path: false,
+            found_invalid_target: false,
+            found_target: None,
+            has_reference: false,
+        }
+    }
+    fn finalize_many_to_many(&mut self, table: &[Weight]) {
+        self.found_distances = Some(table.to_vec());
+    }
+
+    const REQUIRES_REFERENCE: bool = true;
+    fn set_reference<G: SpGraph, F: Fn(usize) -> usize>(
+        &mut self,
+        original_graph: &G,
+        has_negative_weights: bool,
+        original_node: F,
+    ) {
+        // time-dependent & resource-constrained references are computed on their own graphs, which are never reordered
+        if self.has_reference {
+            return;
+        }
+        let source = original_node(self.source);
+        if let Some((sources, targets)) = &self.table {
+            let sources = sources.iter().map(|&x| original_node(x)).collect_vec();
+            let targets = targets.iter().map(|&x| original_node(x)).collect_vec();
+            (self.correct_distances, self.correct_negative_cycle) = get_valid_many_to_many_solution(
+                original_graph,
+                has_negative_weights,
+                &sources,
+                &targets,
+            );
+        } else if let Some(targets) = &mut self.nearest_targets {
+            for (target, distance) in targets.iter_mut() {
+                let (target_distance, negative_cycle) = get_valid_solution(
+                    original_graph,
+                    has_negative_weights,
+                    source,
+                    original_node(*target),
+                );
+                *distance = target_distance;
+                self.correct_negative_cycle |= negative_cycle;
+            }
+            self.correct_shortest_distance = targets.iter().filter_map(|x| x.1).min();
+            if let Some(target) = self.found_target {
+                self.found_invalid_target = !targets
+                    .iter()
+                    .any(|&(t, distance)| t == target && distance == self.found_shortest_distance);
+            }
+        } else if let Some(sink) = self.sink {
+            (self.correct_shortest_distance, self.correct_negative_cycle) = get_valid_solution(
+                original_graph,
+                has_negative_weights,
+                source,
+                original_node(sink),
+            );
+        } else {
+            // distances of the original nodes are reported at the nodes of the graph the algorithm runs on
+            let (distances, negative_cycle) =
+                get_valid_one_to_all_solution(original_graph, has_negative_weights, source);
+            self.correct_distances = distances.map(|distances| {
+                (0..distances.len())
+                    .map(|node| distances[original_node(node)])
+                    .collect()
+            });
+            self.correct_negative_cycle = negative_cycle;
+        }
+        self.has_reference = true;
+    }
+}
+
+// whether the path is a sequence of existing edges from the source to the sink
+fn is_path<G: SpGraph>(graph: &G, source: usize, sink: usize, path: &ShortestPath) -> bool {
+    let is_edge = |(tail, head, weight): (usize, usize, Weight)| {
+        let mut out_edges = graph.out_edges(tail);
+        std::iter::from_fn(|| out_edges.next_edge())
+            .any(|edge| edge.head() == head && edge.weight() == weight)
+    };
+    path.nodes.first() == Some(&source)
+        && path.nodes.last() == Some(&sink)
+        && path.weights.len() + 1 == path.nodes.len()
+        && path
+            .nodes
+            .windows(2)
+            .zip(&path.weights)
+            .all(|(edge, &weight)| is_edge((edge[0], edge[1], weight)))
+}
+
+fn get_valid_solution<G: SpGraph>(
+    graph: &G,
+    has_negative_weights: bool,
+    source: usize,
+    sink: usize,
+) -> (Option<Weight>, bool) {
+    let solution = match has_negative_weights {
+        true => BellmanFord::run_pure::<NoMeasurement>(graph, source, sink),
+        false => StdPqDijkstra::run_pure::<NoMeasurement>(graph, source, sink),
+    };
+    (solution.shortest_distance, solution.negative_cycle)
 }
 
-fn get_valid_solution<G: SpGraph>(graph: &G, source: usize, sink: usize) -> Option<Weight> {
-    StdPqDijkstra::run_pure::<NoMeasurement>(graph, source, sink).shortest_distance
+fn get_valid_one_to_all_solution<G: SpGraph>(
+    graph: &G,
+    has_negative_weights: bool,
+    source: usize,
+) -> (Option<Vec<Weight>>, bool) {
+    let solution: Solution<Distances> = match has_negative_weights {
+        true => BellmanFord::new(graph).run_cached_one_to_all(graph, source),
+        false => StdPqDijkstra::new(graph).run_cached_one_to_all(graph, source),
+    };
+    (solution.measure.distances, solution.negative_cycle)
+}
+
+fn get_valid_many_to_many_solution<G: SpGraph>(
+    graph: &G,
+    has_negative_weights: bool,
+    sources: &[usize],
+    targets: &[usize],
+) -> (Option<Vec<Weight>>, bool) {
+    let solution: Solution<Distances> = match has_negative_weights {
+        true => BellmanFord::new(graph).run_cached_many_to_many(graph, sources, targets),
+        false => StdPqDijkstra::new(graph).run_cached_many_to_many(graph, sources, targets),
+    };
+    (solution.measure.distances, solution.negative_cycle)
+}
+
+// label-correcting earliest arrivals, which do not depend on the order of the relaxations under FIFO
+fn get_valid_time_dependent_solution<G: TdGraph>(
+    graph: &G,
+    source: usize,
+    sink: usize,
+    departure_time: Weight,
+) -> Option<Weight> {
+    let mut arrivals = vec![Weight::MAX; graph.num_nodes()];
+    let mut queue = VecDeque::from([source]);
+    arrivals[source] = departure_time;
+    while let Some(tail) = queue.pop_front() {
+        for (edge, travel_time) in graph.td_out_edges(tail) {
+            let arrival = arrivals[tail] + travel_time.travel_time(arrivals[tail]);
+            if arrival < arrivals[edge.head()] {
+                arrivals[edge.head()] = arrival;
+                queue.push_back(edge.head());
+            }
+        }
+    }
+    (arrivals[sink] != Weight::MAX).then(|| arrivals[sink] - departure_time)
+}
+
+// label-correcting pareto fronts of (distance, resource) pairs within the budget
+fn get_valid_resource_constrained_solution<G: ResourceGraph>(
+    graph: &G,
+    source: usize,
+    sink: usize,
+    budget: Weight,
+) -> Option<Weight> {
+    let mut fronts: Vec<Vec<(Weight, Weight)>> = vec![vec![]; graph.num_nodes()];
+    let mut queue = VecDeque::from([(source, 0, 0)]);
+    fronts[source].push((0, 0));
+    while let Some((tail, distance, resource)) = queue.pop_front() {
+        if !fronts[tail].contains(&(distance, resource)) {
+            continue;
+        }
+        for (edge, edge_resource) in graph.resource_out_edges(tail) {
+            let (head, distance, resource) = (
+                edge.head(),
+                distance + edge.weight(),
+                resource + edge_resource,
+            );
+            let front = &mut fronts[head];
+            if resource > budget || front.iter().any(|&(d, r)| d <= distance && r <= resource) {
+                continue;
+            }
+            front.retain(|&(d, r)| !(distance <= d && resource <= r));
+            front.push((distance, resource));
+            queue.push_back((head, distance, resource));
+        }
+    }
+    fronts[sink].iter().map(|&(d, _)| d).min()
 }
@@ -1,9 +1,44 @@
+pub mod apsp_query;
+pub mod arc_flags;
+pub mod arc_flags_dijkstra;
+pub mod astar;
+pub mod bellman_ford;
+pub mod bfs;
+pub mod bidirectional_astar;
+pub mod bidirectional_dijkstra;
+pub mod bucket_queue;
+pub mod ch_query;
+pub mod contraction_hierarchy;
+pub mod coordinate_potential;
+pub mod delta_stepping;
+pub mod dijkstra_queue;
+pub mod distance_matrix;
+pub mod fibonacci_heap;
+pub mod hub_label_query;
+pub mod hub_labels;
+pub mod label_setting;
+pub mod landmarks;
 pub mod measure;
 pub mod orx_pq_dijkstra;
 pub mod orx_pqdeckey_dijkstra;
+pub mod pairing_heap;
+pub mod partition;
+pub mod path;
+pub mod petgraph_astar;
+pub mod petgraph_bellman_ford;
 pub mod petgraph_dijkstra;
+pub mod potential;
 pub mod priority_queue_priority_queue;
+pub mod queue_dijkstra;
+pub mod radix_heap;
 pub mod run_attempt;
 pub mod sd_algorithm;
 pub mod solution;
+pub mod spfa;
 pub mod std_pq_dijkstra;
+pub mod target_set;
+pub mod td_dijkstra;
+#[cfg(test)]
+pub mod test_utils;
+pub mod yen;
+pub mod zero_one_bfs;
@@ -1,58 +1,67 @@
 use super::{
-    measure::run_measure::Measure, sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+    measure::run_measure::Measure,
+    path::Predecessors,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+    target_set::{Sink, TargetSet},
 };
 use crate::{
     graph::{
         out_edges::{OutEdgeData, OutEdges},
         sp_graph::SpGraph,
+        weight_type::WeightType,
     },
     Weight,
 };
 use orx_priority_queue::{DaryHeap, PriorityQueue};
 
-pub struct OrxPqDijkstra<Pq>
+pub struct OrxPqDijkstra<Pq, W = Weight>
 where
-    Pq: PriorityQueue<usize, Weight>,
+    Pq: PriorityQueue<usize, W>,
+    W: WeightType,
 {
     queue: Pq,
-    distances: Vec<Weight>,
+    distances: Vec<W>,
+    predecessors: Predecessors,
 }
-impl<Pq> OrxPqDijkstra<Pq>
+impl<Pq, W> OrxPqDijkstra<Pq, W>
 where
-    Pq: PriorityQueue<usize, Weight>,
+    Pq: PriorityQueue<usize, W>,
+    W: WeightType,
 {
-    fn new(queue: Pq) -> Self {
+    fn with_queue(queue: Pq) -> Self {
         Self {
             queue,
             distances: Default::default(),
+            predecessors: Default::default(),
         }
     }
-    fn run_cached_core<G: SpGraph, M: Measure>(
+    fn run_cached_core<G: SpGraph<W = W>, M: Measure, S: Sink>(
         &mut self,
         graph: &G,
         source: usize,
-        sink: usize,
-    ) -> Solution<M> {
+        mut sink: S,
+        solution: &mut Solution<M>,
+    ) -> Option<W> {
         // reset
-        let mut solution = Solution::new(graph, source, sink);
         self.distances
             .iter_mut()
             .take(graph.num_nodes())
-            .for_each(|x| *x = Weight::MAX);
+            .for_each(|x| *x = W::INFINITY);
         if self.distances.len() < graph.num_nodes() {
             self.distances
-                .extend((0..graph.num_nodes() - self.distances.len()).map(|_| Weight::MAX));
+                .extend((0..graph.num_nodes() - self.distances.len()).map(|_| W::INFINITY));
         }
         self.queue.clear();
 
         // init
-        self.distances[source] = 0;
-        self.queue.push(source, Weight::default());
+        self.distances[source] = W::ZERO;
+        self.queue.push(source, W::ZERO);
 
         // iterate
         while let Some((position, cost)) = self.queue.pop() {
-            if position == sink {
-                return solution.reached(cost);
+            if sink.is_sink(position) {
+                return Some(cost);
             }
 
             if cost > self.distances[position] {
@@ -61,30 +70,85 @@ where
 
             let mut out_edges = graph.out_edges(position);
             while let Some(edge) = out_edges.next_edge() {
-                let (head, weight) = (edge.head(), edge.weight());
-                let next_cost = cost + weight;
+                let (head, weight) = (edge.head(), edge.typed_weight());
+                let Some(next_cost) = cost.checked_add(weight) else {
+                    solution.overflow();
+                    return None;
+                };
                 if next_cost < self.distances[head] {
                     self.queue.push(head, next_cost);
                     self.distances[head] = next_cost;
+                    self.predecessors.set(head, position, weight.to_weight());
                 }
             }
 
             solution.iterate(self.queue.len(), self.queue.capacity());
         }
 
-        solution.not_connected()
+        None
     }
 }
 
 // impl
-impl<G, const D: usize> ShortestDistanceAlgorithm<G> for OrxPqDijkstra<DaryHeap<usize, Weight, D>>
+impl<G, W, const D: usize> PureShortestDistanceAlgorithm<G>
+    for OrxPqDijkstra<DaryHeap<usize, W, D>, W>
 where
-    G: SpGraph,
+    G: SpGraph<W = W>,
+    W: WeightType,
 {
     fn new(_: &G) -> Self {
-        Self::new(Default::default())
+        Self::with_queue(Default::default())
     }
+}
+impl<G, W, const D: usize> ShortestDistanceAlgorithm<G> for OrxPqDijkstra<DaryHeap<usize, W, D>, W>
+where
+    G: SpGraph<W = W>,
+    W: WeightType,
+{
     fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
-        self.run_cached_core(graph, source, sink)
+        let mut solution = Solution::new(graph, source, sink);
+        match self.run_cached_core(graph, source, Some(sink), &mut solution) {
+            Some(distance) => solution.reached(distance.to_weight()),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let distance = self.run_cached_core(graph, source, Some(sink), &mut solution);
+        self.predecessors.stop_tracking();
+        match distance {
+            Some(distance) => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance.to_weight(), path)
+            }
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.run_cached_core(graph, source, None, &mut solution);
+        solution.all_reached(&self.distances[..graph.num_nodes()])
+    }
+    fn run_cached_nearest<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        targets: &[usize],
+    ) -> Solution<M> {
+        let mut solution = Solution::new_nearest(graph, source, targets);
+        let mut target_set = TargetSet::new(graph.num_nodes(), targets);
+        match self.run_cached_core(graph, source, &mut target_set, &mut solution) {
+            Some(distance) => {
+                let target = target_set.reached().expect("search stops at a target");
+                solution.nearest_reached(target, distance.to_weight())
+            }
+            None => solution.not_connected(),
+        }
     }
 }
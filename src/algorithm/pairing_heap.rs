@@ -0,0 +1,220 @@
+use super::dijkstra_queue::DijkstraQueue;
+use crate::{
+    graph::{sp_graph::SpGraph, weight_type::WeightType},
+    Weight,
+};
+
+/// Pairing heap with decrease-key, stored in arrays indexed by the nodes of the graph.
+///
+/// Each node keeps its leftmost child, its right sibling and `prev` which is the parent
+/// for a leftmost child and the left sibling otherwise; pop combines the children of the root
+/// by the standard two-pass pairing.
+///
+/// * `len` is the number of nodes in the heap,
+/// * `capacity` is the number of node slots of the arrays, which is the number of nodes of the graph.
+pub struct PairingHeap<K = Weight> {
+    key: Vec<K>,
+    child: Vec<usize>,
+    sibling: Vec<usize>,
+    prev: Vec<usize>,
+    in_heap: Vec<bool>,
+    root: usize,
+    len: usize,
+    touched: Vec<usize>,
+    buffer: Vec<usize>,
+}
+
+impl<K: WeightType> PairingHeap<K> {
+    fn meld(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        let (parent, child) = match self.key[b] < self.key[a] {
+            true => (b, a),
+            false => (a, b),
+        };
+        let first_child = self.child[parent];
+        self.sibling[child] = first_child;
+        if first_child != NIL {
+            self.prev[first_child] = child;
+        }
+        self.prev[child] = parent;
+        self.child[parent] = child;
+        parent
+    }
+    fn detach(&mut self, node: usize) {
+        let (prev, sibling) = (self.prev[node], self.sibling[node]);
+        if self.child[prev] == node {
+            self.child[prev] = sibling;
+        } else {
+            self.sibling[prev] = sibling;
+        }
+        if sibling != NIL {
+            self.prev[sibling] = prev;
+        }
+        self.prev[node] = NIL;
+        self.sibling[node] = NIL;
+    }
+    fn push(&mut self, node: usize, key: K) {
+        self.key[node] = key;
+        self.child[node] = NIL;
+        self.sibling[node] = NIL;
+        self.prev[node] = NIL;
+        self.in_heap[node] = true;
+        self.touched.push(node);
+        self.len += 1;
+        self.root = self.meld(self.root, node);
+    }
+    fn decrease_key(&mut self, node: usize, key: K) {
+        self.key[node] = key;
+        if node != self.root {
+            self.detach(node);
+            self.root = self.meld(self.root, node);
+        }
+    }
+    fn combine_children(&mut self, node: usize) -> usize {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+        let mut child = self.child[node];
+        while child != NIL {
+            buffer.push(child);
+            let next = self.sibling[child];
+            self.prev[child] = NIL;
+            self.sibling[child] = NIL;
+            child = next;
+        }
+        self.child[node] = NIL;
+
+        // first pass: meld pairs from left to right
+        let mut num_pairs = 0;
+        for i in (0..buffer.len()).step_by(2) {
+            let second = buffer.get(i + 1).copied().unwrap_or(NIL);
+            buffer[num_pairs] = self.meld(buffer[i], second);
+            num_pairs += 1;
+        }
+        // second pass: meld from right to left
+        let mut root = NIL;
+        for i in (0..num_pairs).rev() {
+            root = self.meld(buffer[i], root);
+        }
+
+        self.buffer = buffer;
+        root
+    }
+}
+
+impl<K: WeightType> DijkstraQueue<K> for PairingHeap<K> {
+    fn for_graph<G: SpGraph>(graph: &G) -> Self {
+        let num_nodes = graph.num_nodes();
+        Self {
+            key: vec![K::INFINITY; num_nodes],
+            child: vec![NIL; num_nodes],
+            sibling: vec![NIL; num_nodes],
+            prev: vec![NIL; num_nodes],
+            in_heap: vec![false; num_nodes],
+            root: NIL,
+            len: 0,
+            touched: vec![],
+            buffer: vec![],
+        }
+    }
+    fn clear(&mut self) {
+        for &node in &self.touched {
+            self.in_heap[node] = false;
+        }
+        self.touched.clear();
+        self.root = NIL;
+        self.len = 0;
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn capacity(&self) -> usize {
+        self.key.len()
+    }
+    fn peek_key(&mut self) -> Option<K> {
+        match self.root {
+            NIL => None,
+            root => Some(self.key[root]),
+        }
+    }
+    fn pop(&mut self) -> Option<(usize, K)> {
+        let root = self.root;
+        if root == NIL {
+            return None;
+        }
+        self.root = self.combine_children(root);
+        self.in_heap[root] = false;
+        self.len -= 1;
+        Some((root, self.key[root]))
+    }
+    #[inline(always)]
+    fn relax(&mut self, node: usize, key: K) {
+        match self.in_heap[node] {
+            true if key < self.key[node] => self.decrease_key(node, key),
+            true => {}
+            false => self.push(node, key),
+        }
+    }
+}
+
+const NIL: usize = usize::MAX;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::{
+            queue_dijkstra::QueueDijkstra,
+            sd_algorithm::PureShortestDistanceAlgorithm,
+            test_utils::{
+                assert_same_distances, assert_same_keys_as_model, assert_same_one_to_all_distances,
+                queue_ops, random_graph, weights, QueueKind,
+            },
+        },
+        graph::adjlist_jagged_vec::AdjListJaggedVec,
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn relax_decreases_key() {
+        let graph: AdjListJaggedVec = vec![vec![]; 3];
+        let mut heap = PairingHeap::<Weight>::for_graph(&graph);
+        heap.relax(0, 10);
+        heap.relax(1, 7);
+        heap.relax(0, 5);
+        heap.relax(1, 9);
+        assert_eq!(heap.len(), 2);
+        assert_eq!(heap.pop(), Some((0, 5)));
+        assert_eq!(heap.pop(), Some((1, 7)));
+        assert_eq!(heap.pop(), None);
+    }
+
+    proptest! {
+        #[test]
+        fn pops_keys_in_order(ops in queue_ops(40, 1_000)) {
+            let graph: AdjListJaggedVec = vec![vec![]; 40];
+            let heap = &mut PairingHeap::<Weight>::for_graph(&graph);
+            assert_same_keys_as_model(heap, &ops, QueueKind::DecreaseKey);
+            heap.clear();
+            assert_same_keys_as_model(heap, &ops, QueueKind::DecreaseKey);
+        }
+
+        #[test]
+        fn dijkstra_matches_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let dijkstra = &mut QueueDijkstra::<PairingHeap, _>::new(&graph);
+            assert_same_distances(&graph, queries_seed, dijkstra);
+            assert_same_one_to_all_distances(&graph, queries_seed, dijkstra);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::graph::{
+    coordinates::Coordinates,
+    out_edges::{OutEdgeData, OutEdges},
+    sp_graph::SpGraph,
+};
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use std::collections::VecDeque;
+
+/// Partition of the nodes into cells such that `cells[node]` is the cell of the node.
+pub struct Partition {
+    cells: Vec<usize>,
+    num_cells: usize,
+}
+
+impl Partition {
+    /// Grid of `rows x columns = num_cells` cells on the node coordinates, where the number of rows is
+    /// the largest divisor of `num_cells` not exceeding its square root.
+    ///
+    /// Columns are balanced in their number of nodes by longitude, and the cells of each column by latitude.
+    pub fn grid(coordinates: &Coordinates, num_cells: usize) -> Self {
+        let num_rows = (1..=num_cells)
+            .take_while(|r| r * r <= num_cells)
+            .filter(|r| num_cells.is_multiple_of(*r))
+            .last()
+            .unwrap_or(1);
+        let num_columns = num_cells / num_rows;
+        let num_nodes = coordinates.num_nodes();
+
+        let mut by_longitude: Vec<_> = (0..num_nodes).collect();
+        by_longitude.sort_by(|&a, &b| {
+            let (a, b) = (coordinates.point(a), coordinates.point(b));
+            a.longitude.total_cmp(&b.longitude)
+        });
+
+        let mut cells = vec![0; num_nodes];
+        for column in 0..num_columns {
+            let begin = column * num_nodes / num_columns;
+            let end = (column + 1) * num_nodes / num_columns;
+            let column_nodes = &mut by_longitude[begin..end];
+            column_nodes.sort_by(|&a, &b| {
+                let (a, b) = (coordinates.point(a), coordinates.point(b));
+                a.latitude.total_cmp(&b.latitude)
+            });
+            let column_len = column_nodes.len();
+            for (i, &node) in column_nodes.iter().enumerate() {
+                cells[node] = column * num_rows + i * num_rows / column_len;
+            }
+        }
+
+        Self { cells, num_cells }
+    }
+
+    /// Cells are grown simultaneously by breadth-first search, ignoring edge directions,
+    /// from `num_cells` randomly sampled seed nodes.
+    pub fn bfs_grown<G: SpGraph>(graph: &G, num_cells: usize) -> Self {
+        let num_nodes = graph.num_nodes();
+        let reversed = graph.reversed();
+        let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+        let nodes: Vec<_> = (0..num_nodes).collect();
+
+        let mut cells = vec![usize::MAX; num_nodes];
+        let mut queue = VecDeque::new();
+        for (cell, &seed) in nodes.choose_multiple(&mut rng, num_cells).enumerate() {
+            cells[seed] = cell;
+            queue.push_back(seed);
+        }
+        while let Some(node) = queue.pop_front() {
+            let mut grow = |head: usize| {
+                if cells[head] == usize::MAX {
+                    cells[head] = cells[node];
+                    queue.push_back(head);
+                }
+            };
+            let mut out_edges = graph.out_edges(node);
+            while let Some(edge) = out_edges.next_edge() {
+                grow(edge.head());
+            }
+            let mut in_edges = reversed.out_edges(node);
+            while let Some(edge) = in_edges.next_edge() {
+                grow(edge.head());
+            }
+        }
+
+        // components without a seed are not connected to any other cell; hence, their cells do not matter
+        for (node, cell) in cells.iter_mut().enumerate() {
+            if *cell == usize::MAX {
+                *cell = node % num_cells;
+            }
+        }
+
+        Self { cells, num_cells }
+    }
+
+    pub fn cell(&self, node: usize) -> usize {
+        self.cells[node]
+    }
+    pub fn num_cells(&self) -> usize {
+        self.num_cells
+    }
+    pub fn memory_bytes(&self) -> usize {
+        self.cells.len() * std::mem::size_of::<usize>()
+    }
+}
+
+const SEED: u64 = 3457;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_utils::{random_coordinates, random_graph, weights};
+    use proptest::prelude::*;
+
+    fn cell_sizes(partition: &Partition, num_nodes: usize) -> Vec<usize> {
+        let mut sizes = vec![0; partition.num_cells()];
+        for node in 0..num_nodes {
+            sizes[partition.cell(node)] += 1;
+        }
+        sizes
+    }
+
+    proptest! {
+        #[test]
+        fn grid_cells_are_balanced(
+            seed in any::<u64>(),
+            num_nodes in 1..500usize,
+            num_cells in 1..40usize,
+        ) {
+            let partition = Partition::grid(&random_coordinates(seed, num_nodes), num_cells);
+            let sizes = cell_sizes(&partition, num_nodes);
+            let (min, max) = (sizes.iter().min().unwrap(), sizes.iter().max().unwrap());
+            // column sizes are the floor or ceil of num_nodes / num_columns, and so are the cell sizes within each column
+            prop_assert!(max - min <= 2, "{:?}", sizes);
+            prop_assert!(num_nodes < num_cells || *min > 0, "{:?}", sizes);
+        }
+
+        #[test]
+        fn bfs_grown_cells_are_not_empty(
+            seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+            num_cells in 1..40usize,
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let partition = Partition::bfs_grown(&graph, num_cells);
+            let sizes = cell_sizes(&partition, num_nodes);
+            prop_assert!(num_nodes < num_cells || sizes.iter().all(|&s| s > 0), "{:?}", sizes);
+        }
+    }
+}
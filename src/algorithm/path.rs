@@ -0,0 +1,65 @@
+use crate::Weight;
+
+/// Shortest path as the sequence of the nodes from the source to the sink
+/// together with the weights of the edges between consecutive nodes.
+#[derive(Debug, Clone, Default)]
+pub struct ShortestPath {
+    pub nodes: Vec<usize>,
+    pub weights: Vec<Weight>,
+}
+
+impl ShortestPath {
+    pub fn distance(&self) -> Weight {
+        self.weights.iter().sum()
+    }
+}
+
+/// Last edge, as `(tail, weight)`, on the current shortest path from the source to each node.
+///
+/// Edges are only recorded between `start_tracking` and `stop_tracking`
+/// so that distance queries do not pay for the bookkeeping.
+#[derive(Default)]
+pub struct Predecessors {
+    tracking: bool,
+    edges: Vec<(usize, Weight)>,
+}
+
+impl Predecessors {
+    pub fn start_tracking(&mut self, num_nodes: usize) {
+        self.tracking = true;
+        self.edges.clear();
+        self.edges
+            .resize(num_nodes, (NO_PREDECESSOR, Weight::default()));
+    }
+    pub fn stop_tracking(&mut self) {
+        self.tracking = false;
+    }
+
+    #[inline(always)]
+    pub fn set(&mut self, node: usize, tail: usize, weight: Weight) {
+        if self.tracking {
+            self.edges[node] = (tail, weight);
+        }
+    }
+
+    /// Walks back from the sink, which must have been reached, to the source.
+    pub fn path(&self, source: usize, sink: usize) -> ShortestPath {
+        let mut path = ShortestPath {
+            nodes: vec![sink],
+            weights: vec![],
+        };
+        let mut node = sink;
+        while node != source {
+            let (tail, weight) = self.edges[node];
+            debug_assert_ne!(tail, NO_PREDECESSOR, "sink must be reached from the source");
+            path.nodes.push(tail);
+            path.weights.push(weight);
+            node = tail;
+        }
+        path.nodes.reverse();
+        path.weights.reverse();
+        path
+    }
+}
+
+const NO_PREDECESSOR: usize = usize::MAX;
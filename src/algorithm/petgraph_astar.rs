@@ -0,0 +1,72 @@
+use super::{
+    measure::run_measure::Measure, petgraph_dijkstra::petgraph_path, potential::Potential,
+    sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+};
+use crate::graph::petgraph_graph::PetgraphGraph;
+use petgraph::visit::{EdgeRef, NodeIndexable};
+use std::rc::Rc;
+
+/// `petgraph::algo::astar` where the estimate cost of a node is its potential.
+pub struct PetgraphAStar<P: Potential> {
+    potential: Rc<P>,
+}
+
+impl<P: Potential> PetgraphAStar<P> {
+    pub fn new<G: PetgraphGraph>(_: &G, potential: Rc<P>) -> Self {
+        Self { potential }
+    }
+    fn run<G: PetgraphGraph, M: Measure>(
+        &self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+        path: bool,
+    ) -> Solution<M> {
+        let solution = Solution::new(graph, source, sink);
+
+        let g = graph.petgraph();
+        let sink_id = g.from_index(sink);
+        let result = petgraph::algo::astar(
+            g,
+            g.from_index(source),
+            |node| node == sink_id,
+            |e| *e.weight(),
+            |node| self.potential.potential(g.to_index(node), sink),
+        );
+
+        match (result, path) {
+            (Some((distance, _)), false) => solution.reached(distance),
+            (Some((distance, nodes)), true) => {
+                solution.reached_with_path(graph, distance, petgraph_path(g, nodes))
+            }
+            (None, _) => solution.not_connected(),
+        }
+    }
+}
+
+impl<G: PetgraphGraph, P: Potential> ShortestDistanceAlgorithm<G> for PetgraphAStar<P> {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        self.run(graph, source, sink, false)
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        self.run(graph, source, sink, true)
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        // without a sink, the estimate is zero and the search reduces to dijkstra's algorithm
+        let solution = Solution::new_one_to_all(graph, source);
+
+        let g = graph.petgraph();
+        let result = petgraph::algo::dijkstra(g, g.from_index(source), None, |e| *e.weight());
+        let mut distances = vec![crate::Weight::MAX; graph.num_nodes()];
+        for (node, distance) in result {
+            distances[g.to_index(node)] = distance;
+        }
+
+        solution.all_reached(&distances)
+    }
+}
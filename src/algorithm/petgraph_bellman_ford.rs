@@ -0,0 +1,89 @@
+use super::{
+    measure::run_measure::Measure,
+    petgraph_dijkstra::petgraph_path,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+};
+use crate::{graph::petgraph_graph::PetgraphGraph, Weight};
+use petgraph::{
+    algo::{bellman_ford::Paths, NegativeCycle},
+    visit::{GraphBase, NodeIndexable},
+};
+
+type FloatPaths<'a, G> = Paths<<<G as PetgraphGraph>::FloatPetgraph<'a> as GraphBase>::NodeId, f64>;
+
+/// `petgraph::algo::bellman_ford` which requires floating point weights; it runs on the `f64` weighted copy of the graph
+/// created once by `new`. Integer weights & distances below 2^53 are represented exactly.
+pub struct PetgraphBellmanFord<G: PetgraphGraph> {
+    float_weighted: G::FloatWeighted,
+}
+
+impl<G: PetgraphGraph> PetgraphBellmanFord<G> {
+    fn paths(&self, source: usize) -> Result<FloatPaths<'_, G>, NegativeCycle> {
+        let g = G::float_petgraph(&self.float_weighted);
+        petgraph::algo::bellman_ford(g, g.from_index(source))
+    }
+}
+
+impl<G: PetgraphGraph> PureShortestDistanceAlgorithm<G> for PetgraphBellmanFord<G> {
+    fn new(graph: &G) -> Self {
+        Self {
+            float_weighted: graph.float_weighted(),
+        }
+    }
+}
+impl<G: PetgraphGraph> ShortestDistanceAlgorithm<G> for PetgraphBellmanFord<G> {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let solution = Solution::new(graph, source, sink);
+        match self.paths(source) {
+            Err(NegativeCycle(_)) => solution.negative_cycle(),
+            Ok(paths) => match to_distance(paths.distances[sink]) {
+                Weight::MAX => solution.not_connected(),
+                distance => solution.reached(distance),
+            },
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let solution = Solution::new(graph, source, sink);
+        let paths = match self.paths(source) {
+            Err(NegativeCycle(_)) => return solution.negative_cycle(),
+            Ok(paths) => paths,
+        };
+        let distance = match to_distance(paths.distances[sink]) {
+            Weight::MAX => return solution.not_connected(),
+            distance => distance,
+        };
+
+        let float_g = G::float_petgraph(&self.float_weighted);
+        let mut nodes = vec![sink];
+        while let Some(predecessor) = paths.predecessors[*nodes.last().expect("non-empty")] {
+            nodes.push(float_g.to_index(predecessor));
+        }
+
+        let g = graph.petgraph();
+        let nodes = nodes.into_iter().rev().map(|x| g.from_index(x)).collect();
+        solution.reached_with_path(graph, distance, petgraph_path(g, nodes))
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let solution = Solution::new_one_to_all(graph, source);
+        match self.paths(source) {
+            Err(NegativeCycle(_)) => solution.negative_cycle(),
+            Ok(paths) => {
+                let distances: Vec<_> = paths.distances.into_iter().map(to_distance).collect();
+                solution.all_reached(&distances)
+            }
+        }
+    }
+}
+
+fn to_distance(distance: f64) -> Weight {
+    match distance.is_finite() {
+        true => distance as Weight,
+        false => Weight::MAX,
+    }
+}
@@ -1,31 +1,90 @@
 use super::{
-    measure::run_measure::Measure, sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+    measure::run_measure::Measure,
+    path::ShortestPath,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
 };
-use crate::graph::adjlist_petgraph::AdjListPetgraph;
-use petgraph::graph::NodeIndex;
+use crate::{graph::petgraph_graph::PetgraphGraph, Weight};
+use petgraph::visit::{EdgeRef, IntoEdges, NodeIndexable};
 
 pub struct PetgraphDijsktra;
 
-impl ShortestDistanceAlgorithm<AdjListPetgraph> for PetgraphDijsktra {
-    fn new(_: &AdjListPetgraph) -> Self {
+impl<G: PetgraphGraph> PureShortestDistanceAlgorithm<G> for PetgraphDijsktra {
+    fn new(_: &G) -> Self {
         Self
     }
-    fn run_cached<M: Measure>(
+}
+impl<G: PetgraphGraph> ShortestDistanceAlgorithm<G> for PetgraphDijsktra {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let solution = Solution::new(graph, source, sink);
+
+        let g = graph.petgraph();
+        let sink = g.from_index(sink);
+        let result = petgraph::algo::dijkstra(g, g.from_index(source), Some(sink), |e| *e.weight());
+        let distance = result.get(&sink).cloned();
+
+        match distance {
+            Some(d) => solution.reached(d),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_path<M: Measure>(
         &mut self,
-        graph: &AdjListPetgraph,
+        graph: &G,
         source: usize,
         sink: usize,
     ) -> Solution<M> {
         let solution = Solution::new(graph, source, sink);
 
-        let sink = NodeIndex::new(sink);
-        let result =
-            petgraph::algo::dijkstra(&graph, NodeIndex::new(source), Some(sink), |e| *e.weight());
-        let distance = result.get(&sink).cloned();
+        // petgraph::algo::dijkstra does not keep predecessors; astar with a zero estimate is dijkstra returning the path
+        let g = graph.petgraph();
+        let sink = g.from_index(sink);
+        let result = petgraph::algo::astar(
+            g,
+            g.from_index(source),
+            |node| node == sink,
+            |e| *e.weight(),
+            |_| Weight::default(),
+        );
 
-        match distance {
-            Some(d) => solution.reached(d),
+        match result {
+            Some((distance, nodes)) => {
+                let path = petgraph_path(g, nodes);
+                solution.reached_with_path(graph, distance, path)
+            }
             None => solution.not_connected(),
         }
     }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let solution = Solution::new_one_to_all(graph, source);
+
+        let g = graph.petgraph();
+        let result = petgraph::algo::dijkstra(g, g.from_index(source), None, |e| *e.weight());
+        let mut distances = vec![Weight::MAX; graph.num_nodes()];
+        for (node, distance) in result {
+            distances[g.to_index(node)] = distance;
+        }
+
+        solution.all_reached(&distances)
+    }
+}
+
+/// Path of the petgraph node ids where the weight of each step is the smallest weight of the edges connecting the nodes.
+pub fn petgraph_path<G>(g: G, nodes: Vec<G::NodeId>) -> ShortestPath
+where
+    G: IntoEdges<EdgeWeight = Weight> + NodeIndexable,
+    G::NodeId: Eq,
+{
+    let weights = nodes
+        .windows(2)
+        .map(|edge| {
+            g.edges(edge[0])
+                .filter(|e| e.target() == edge[1])
+                .map(|e| *e.weight())
+                .min()
+                .expect("consecutive nodes of the path are connected")
+        })
+        .collect();
+    let nodes = nodes.into_iter().map(|node| g.to_index(node)).collect();
+    ShortestPath { nodes, weights }
 }
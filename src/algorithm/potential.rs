@@ -0,0 +1,13 @@
+use crate::Weight;
+
+/// Lower bound on the remaining distance from a node to the sink which directs the search towards the sink.
+///
+/// Potentials are required to be consistent, `potential(u, t) <= w(u, v) + potential(v, t)` for all edges (u, v),
+/// so that each node is settled at most once.
+///
+/// Bidirectional searches further use `potential(s, v)` as a lower bound on the distance from the source,
+/// which must be consistent on the reversed graph, `potential(s, v) <= w(u, v) + potential(s, u)`;
+/// both hold for bounds derived from the triangle inequality.
+pub trait Potential {
+    fn potential(&self, node: usize, sink: usize) -> Weight;
+}
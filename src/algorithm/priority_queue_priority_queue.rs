@@ -1,35 +1,44 @@
 use super::{
-    measure::run_measure::Measure, sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+    measure::run_measure::Measure,
+    path::Predecessors,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
 };
 use crate::{
     graph::{
         out_edges::{OutEdgeData, OutEdges},
         sp_graph::SpGraph,
+        weight_type::WeightType,
     },
     Weight,
 };
 use priority_queue::PriorityQueue;
 
-pub struct PriorityQueuePqDecKeyDijkstra {
-    queue: PriorityQueue<usize, Weight>,
+pub struct PriorityQueuePqDecKeyDijkstra<W: WeightType = Weight> {
+    queue: PriorityQueue<usize, W>,
     visited: Vec<bool>,
+    // distances of the settled nodes, only maintained by one-to-all queries
+    distances: Vec<W>,
+    predecessors: Predecessors,
 }
 
-impl PriorityQueuePqDecKeyDijkstra {
-    fn new(queue: PriorityQueue<usize, u64>) -> Self {
+impl<W: WeightType> PriorityQueuePqDecKeyDijkstra<W> {
+    fn with_queue(queue: PriorityQueue<usize, W>) -> Self {
         Self {
             queue,
             visited: Default::default(),
+            distances: Default::default(),
+            predecessors: Default::default(),
         }
     }
-    fn run_cached_core<G: SpGraph, M: Measure>(
+    fn run_cached_core<G: SpGraph<W = W>, M: Measure>(
         &mut self,
         graph: &G,
         source: usize,
-        sink: usize,
-    ) -> Solution<M> {
+        sink: Option<usize>,
+        solution: &mut Solution<M>,
+    ) -> Option<W> {
         // reset
-        let mut solution = Solution::new(graph, source, sink);
         self.visited
             .iter_mut()
             .take(graph.num_nodes())
@@ -38,15 +47,21 @@ impl PriorityQueuePqDecKeyDijkstra {
             self.visited
                 .extend((0..graph.num_nodes() - self.visited.len()).map(|_| false));
         }
+        if sink.is_none() {
+            self.distances.clear();
+            self.distances.resize(graph.num_nodes(), W::INFINITY);
+        }
         self.queue.clear();
 
         // init
-        self.queue.push(source, Weight::default());
+        self.queue.push(source, W::ZERO);
 
         // iterate
         while let Some((position, cost)) = self.queue.pop() {
-            if position == sink {
-                return solution.reached(cost);
+            match sink {
+                Some(sink) if position == sink => return Some(cost),
+                None => self.distances[position] = cost,
+                _ => {}
             }
             // else if self.visited[position] {
             //     continue;
@@ -54,28 +69,68 @@ impl PriorityQueuePqDecKeyDijkstra {
 
             let mut out_edges = graph.out_edges(position);
             while let Some(edge) = out_edges.next_edge() {
-                let (head, weight) = (edge.head(), edge.weight());
+                let (head, weight) = (edge.head(), edge.typed_weight());
                 if !self.visited[head] {
-                    self.queue.push_decrease(head, cost + weight);
+                    let Some(next_cost) = cost.checked_add(weight) else {
+                        solution.overflow();
+                        return None;
+                    };
+                    if self.queue.push_decrease(head, next_cost) != Some(next_cost) {
+                        self.predecessors.set(head, position, weight.to_weight());
+                    }
                 }
             }
             self.visited[position] = true;
             solution.iterate(self.queue.len(), self.queue.capacity());
         }
 
-        solution.not_connected()
+        None
     }
 }
 
 // impl
-impl<G> ShortestDistanceAlgorithm<G> for PriorityQueuePqDecKeyDijkstra
+impl<G, W> PureShortestDistanceAlgorithm<G> for PriorityQueuePqDecKeyDijkstra<W>
 where
-    G: SpGraph,
+    G: SpGraph<W = W>,
+    W: WeightType,
 {
     fn new(graph: &G) -> Self {
-        Self::new(PriorityQueue::with_capacity(graph.num_nodes()))
+        Self::with_queue(PriorityQueue::with_capacity(graph.num_nodes()))
     }
+}
+impl<G, W> ShortestDistanceAlgorithm<G> for PriorityQueuePqDecKeyDijkstra<W>
+where
+    G: SpGraph<W = W>,
+    W: WeightType,
+{
     fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
-        self.run_cached_core(graph, source, sink)
+        let mut solution = Solution::new(graph, source, sink);
+        match self.run_cached_core(graph, source, Some(sink), &mut solution) {
+            Some(distance) => solution.reached(distance.to_weight()),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let distance = self.run_cached_core(graph, source, Some(sink), &mut solution);
+        self.predecessors.stop_tracking();
+        match distance {
+            Some(distance) => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance.to_weight(), path)
+            }
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.run_cached_core(graph, source, None, &mut solution);
+        solution.all_reached(&self.distances[..graph.num_nodes()])
     }
 }
@@ -0,0 +1,147 @@
+use super::{
+    dijkstra_queue::DijkstraQueue,
+    measure::run_measure::Measure,
+    path::Predecessors,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+    target_set::{Sink, TargetSet},
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+        weight_type::WeightType,
+    },
+    Weight,
+};
+
+/// Dijkstra's algorithm running on any queue implementing `DijkstraQueue`,
+/// such as the monotone integer queues which are not available in the priority queue crates.
+pub struct QueueDijkstra<Q: DijkstraQueue<W>, W: WeightType = Weight> {
+    queue: Q,
+    distances: Vec<W>,
+    predecessors: Predecessors,
+}
+
+impl<Q: DijkstraQueue<W>, W: WeightType> QueueDijkstra<Q, W> {
+    fn run_cached_core<G: SpGraph<W = W>, M: Measure, S: Sink>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        mut sink: S,
+        solution: &mut Solution<M>,
+    ) -> Option<W> {
+        // reset
+        self.distances
+            .iter_mut()
+            .take(graph.num_nodes())
+            .for_each(|x| *x = W::INFINITY);
+        if self.distances.len() < graph.num_nodes() {
+            self.distances
+                .extend((0..graph.num_nodes() - self.distances.len()).map(|_| W::INFINITY));
+        }
+        self.queue.clear();
+
+        // init
+        self.distances[source] = W::ZERO;
+        self.queue.relax(source, W::ZERO);
+
+        // iterate
+        while let Some((position, cost)) = self.queue.pop() {
+            if sink.is_sink(position) {
+                return Some(cost);
+            }
+
+            if cost > self.distances[position] {
+                continue;
+            }
+
+            let mut out_edges = graph.out_edges(position);
+            while let Some(edge) = out_edges.next_edge() {
+                let (head, weight) = (edge.head(), edge.typed_weight());
+                let Some(next_cost) = cost.checked_add(weight) else {
+                    solution.overflow();
+                    return None;
+                };
+                if next_cost < self.distances[head] {
+                    self.queue.relax(head, next_cost);
+                    self.distances[head] = next_cost;
+                    self.predecessors.set(head, position, weight.to_weight());
+                }
+            }
+
+            solution.iterate(self.queue.len(), self.queue.capacity());
+        }
+
+        None
+    }
+}
+
+// impl
+impl<G, Q, W> PureShortestDistanceAlgorithm<G> for QueueDijkstra<Q, W>
+where
+    G: SpGraph<W = W>,
+    Q: DijkstraQueue<W>,
+    W: WeightType,
+{
+    fn new(graph: &G) -> Self {
+        Self {
+            queue: Q::for_graph(graph),
+            distances: Default::default(),
+            predecessors: Default::default(),
+        }
+    }
+}
+impl<G, Q, W> ShortestDistanceAlgorithm<G> for QueueDijkstra<Q, W>
+where
+    G: SpGraph<W = W>,
+    Q: DijkstraQueue<W>,
+    W: WeightType,
+{
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        match self.run_cached_core(graph, source, Some(sink), &mut solution) {
+            Some(distance) => solution.reached(distance.to_weight()),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let distance = self.run_cached_core(graph, source, Some(sink), &mut solution);
+        self.predecessors.stop_tracking();
+        match distance {
+            Some(distance) => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance.to_weight(), path)
+            }
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.run_cached_core(graph, source, None, &mut solution);
+        solution.all_reached(&self.distances[..graph.num_nodes()])
+    }
+    fn run_cached_nearest<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        targets: &[usize],
+    ) -> Solution<M> {
+        let mut solution = Solution::new_nearest(graph, source, targets);
+        let mut target_set = TargetSet::new(graph.num_nodes(), targets);
+        match self.run_cached_core(graph, source, &mut target_set, &mut solution) {
+            Some(distance) => {
+                let target = target_set.reached().expect("search stops at a target");
+                solution.nearest_reached(target, distance.to_weight())
+            }
+            None => solution.not_connected(),
+        }
+    }
+}
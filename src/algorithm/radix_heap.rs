@@ -0,0 +1,135 @@
+use super::dijkstra_queue::DijkstraQueue;
+use crate::{graph::sp_graph::SpGraph, Weight};
+
+/// Radix heap: a monotone integer priority queue where an entry is stored in the bucket
+/// of the highest bit in which its key differs from the last popped key.
+///
+/// Buckets are redistributed whenever the bucket of the last popped key is empty on a peek or pop,
+/// which sets the last key to the minimum key; each entry moves to a lower bucket at most once per bit of the key.
+///
+/// * `len` is the number of entries including stale ones,
+/// * `capacity` is the number of entry slots allocated by the buckets.
+pub struct RadixHeap {
+    buckets: [Vec<(usize, Weight)>; NUM_BUCKETS],
+    last: Weight,
+    len: usize,
+}
+
+impl Default for RadixHeap {
+    fn default() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| vec![]),
+            last: 0,
+            len: 0,
+        }
+    }
+}
+
+impl RadixHeap {
+    fn bucket_of(&self, key: Weight) -> usize {
+        (Weight::BITS - (key ^ self.last).leading_zeros()) as usize
+    }
+    fn redistribute(&mut self) {
+        let Some(i) = self.buckets.iter().position(|x| !x.is_empty()) else {
+            return;
+        };
+        if i == 0 {
+            return;
+        }
+
+        let bucket = std::mem::take(&mut self.buckets[i]);
+        self.last = bucket
+            .iter()
+            .map(|x| x.1)
+            .min()
+            .expect("bucket is not empty");
+        for &(node, key) in &bucket {
+            let j = self.bucket_of(key);
+            self.buckets[j].push((node, key));
+        }
+        // keep the allocation of the emptied bucket
+        self.buckets[i] = bucket;
+        self.buckets[i].clear();
+    }
+}
+
+impl DijkstraQueue for RadixHeap {
+    fn for_graph<G: SpGraph>(_: &G) -> Self {
+        Self::default()
+    }
+    fn clear(&mut self) {
+        self.buckets.iter_mut().for_each(Vec::clear);
+        self.last = 0;
+        self.len = 0;
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn capacity(&self) -> usize {
+        self.buckets.iter().map(Vec::capacity).sum()
+    }
+    fn peek_key(&mut self) -> Option<Weight> {
+        if self.buckets[0].is_empty() {
+            self.redistribute();
+        }
+        self.buckets[0].last().map(|x| x.1)
+    }
+    fn pop(&mut self) -> Option<(usize, Weight)> {
+        if self.buckets[0].is_empty() {
+            self.redistribute();
+        }
+        let popped = self.buckets[0].pop();
+        if popped.is_some() {
+            self.len -= 1;
+        }
+        popped
+    }
+    #[inline(always)]
+    fn relax(&mut self, node: usize, key: Weight) {
+        debug_assert!(key >= self.last, "keys must be monotone");
+        let bucket = self.bucket_of(key);
+        self.buckets[bucket].push((node, key));
+        self.len += 1;
+    }
+}
+
+const NUM_BUCKETS: usize = Weight::BITS as usize + 1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::{
+        bidirectional_dijkstra::BiDijkstra,
+        queue_dijkstra::QueueDijkstra,
+        sd_algorithm::PureShortestDistanceAlgorithm,
+        test_utils::{
+            assert_same_distances, assert_same_keys_as_model, assert_same_one_to_all_distances,
+            queue_ops, random_graph, weights, QueueKind,
+        },
+    };
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn pops_keys_in_order(
+            ops in queue_ops(40, 100_000),
+        ) {
+            let queue = &mut RadixHeap::default();
+            assert_same_keys_as_model(queue, &ops, QueueKind::Monotone);
+        }
+
+        #[test]
+        fn dijkstra_matches_dijkstra(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            assert_same_distances(&graph, queries_seed, &mut QueueDijkstra::<RadixHeap>::new(&graph));
+            assert_same_one_to_all_distances(&graph, queries_seed, &mut QueueDijkstra::<RadixHeap>::new(&graph));
+            assert_same_distances(&graph, queries_seed, &mut BiDijkstra::<RadixHeap>::new(&graph));
+        }
+    }
+}
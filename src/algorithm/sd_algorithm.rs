@@ -1,9 +1,92 @@
-use super::{measure::run_measure::Measure, solution::Solution};
-use crate::graph::sp_graph::SpGraph;
+use super::{
+    measure::{distances::Distances, run_measure::Measure},
+    solution::Solution,
+};
+use crate::{graph::sp_graph::SpGraph, Weight};
 
 pub trait ShortestDistanceAlgorithm<G: SpGraph> {
-    fn new(graph: &G) -> Self;
     fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M>;
+
+    /// Computes a shortest path from the source to the sink in addition to the shortest distance;
+    /// algorithms which do not reconstruct paths decline the query.
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        Solution::new(graph, source, sink).decline()
+    }
+
+    /// Computes the shortest distances from the source to all nodes;
+    /// unreachable nodes have a distance of `Weight::MAX`.
+    ///
+    /// Algorithms answering only one-to-one queries decline the query, and hence,
+    /// the many-to-many & nearest target baselines built on it.
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M>;
+
+    /// Computes the row-major table of the shortest distances from each source to each target.
+    ///
+    /// Naive baseline repeating one-to-all queries from each source;
+    /// algorithms with a dedicated many-to-many method override it.
+    fn run_cached_many_to_many<M: Measure>(
+        &mut self,
+        graph: &G,
+        sources: &[usize],
+        targets: &[usize],
+    ) -> Solution<M> {
+        let mut solution = Solution::new_many_to_many(graph, sources, targets);
+        let mut table = Vec::with_capacity(sources.len() * targets.len());
+        for &source in sources {
+            let one_to_all: Solution<Distances> = self.run_cached_one_to_all(graph, source);
+            if one_to_all.query_declined {
+                return solution.decline();
+            }
+            if one_to_all.negative_cycle {
+                return solution.negative_cycle();
+            }
+            let distances = (one_to_all.measure.distances)
+                .expect("distances are kept unless there exists a negative cycle");
+            table.extend(targets.iter().map(|&target| distances[target]));
+            solution.iterate(0, 0);
+        }
+        solution.table_reached(&table)
+    }
+
+    /// Computes the shortest distance from the source to the nearest of the targets & the target reached.
+    ///
+    /// Naive baseline picking the nearest target from a one-to-all query;
+    /// algorithms which stop at the first settled target override it.
+    fn run_cached_nearest<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        targets: &[usize],
+    ) -> Solution<M> {
+        let solution = Solution::new_nearest(graph, source, targets);
+        let one_to_all: Solution<Distances> = self.run_cached_one_to_all(graph, source);
+        if one_to_all.query_declined {
+            return solution.decline();
+        }
+        if one_to_all.negative_cycle {
+            return solution.negative_cycle();
+        }
+        let distances = (one_to_all.measure.distances)
+            .expect("distances are kept unless there exists a negative cycle");
+        let nearest = (targets.iter().copied())
+            .filter(|&target| distances[target] != Weight::MAX)
+            .min_by_key(|&target| distances[target]);
+        match nearest {
+            Some(target) => solution.nearest_reached(target, distances[target]),
+            None => solution.not_connected(),
+        }
+    }
+}
+
+/// Shortest distance algorithms created from the graph alone, without any preprocessing or parameter;
+/// these can run pure queries which create the algorithm from scratch for each query.
+pub trait PureShortestDistanceAlgorithm<G: SpGraph>: ShortestDistanceAlgorithm<G> {
+    fn new(graph: &G) -> Self;
     fn run_pure<M: Measure>(graph: &G, source: usize, sink: usize) -> Solution<M>
     where
         Self: Sized,
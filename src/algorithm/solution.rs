@@ -1,15 +1,98 @@
-use super::measure::run_measure::Measure;
-use crate::{graph::sp_graph::SpGraph, Weight};
+use super::{measure::run_measure::Measure, path::ShortestPath};
+use crate::{
+    graph::{
+        resource_graph::ResourceGraph, sp_graph::SpGraph, td_graph::TdGraph,
+        weight_type::WeightType,
+    },
+    Weight,
+};
 
 pub struct Solution<M: Measure> {
     pub measure: M,
     pub shortest_distance: Option<Weight>,
+    pub negative_cycle: bool,
+    /// Whether a distance overflowed the weight type; the query is not solved.
+    pub distance_overflow: bool,
+    /// Whether the algorithm does not answer this kind of query; the query is not solved.
+    pub query_declined: bool,
+    pub path: Option<ShortestPath>,
+    pub k_paths: Vec<ShortestPath>,
 }
 impl<M: Measure> Solution<M> {
     pub fn new<G: SpGraph>(graph: &G, source: usize, sink: usize) -> Self {
         Self {
             measure: M::initialize(graph, source, sink),
             shortest_distance: None,
+            negative_cycle: false,
+            distance_overflow: false,
+            query_declined: false,
+            path: None,
+            k_paths: vec![],
+        }
+    }
+    pub fn new_time_dependent<G: TdGraph>(
+        graph: &G,
+        source: usize,
+        sink: usize,
+        departure_time: Weight,
+    ) -> Self {
+        Self {
+            measure: M::initialize_time_dependent(graph, source, sink, departure_time),
+            shortest_distance: None,
+            negative_cycle: false,
+            distance_overflow: false,
+            query_declined: false,
+            path: None,
+            k_paths: vec![],
+        }
+    }
+    pub fn new_resource_constrained<G: ResourceGraph>(
+        graph: &G,
+        source: usize,
+        sink: usize,
+        budget: Weight,
+    ) -> Self {
+        Self {
+            measure: M::initialize_resource_constrained(graph, source, sink, budget),
+            shortest_distance: None,
+            negative_cycle: false,
+            distance_overflow: false,
+            query_declined: false,
+            path: None,
+            k_paths: vec![],
+        }
+    }
+    pub fn new_one_to_all<G: SpGraph>(graph: &G, source: usize) -> Self {
+        Self {
+            measure: M::initialize_one_to_all(graph, source),
+            shortest_distance: None,
+            negative_cycle: false,
+            distance_overflow: false,
+            query_declined: false,
+            path: None,
+            k_paths: vec![],
+        }
+    }
+    pub fn new_many_to_many<G: SpGraph>(graph: &G, sources: &[usize], targets: &[usize]) -> Self {
+        Self {
+            measure: M::initialize_many_to_many(graph, sources, targets),
+            shortest_distance: None,
+            negative_cycle: false,
+            distance_overflow: false,
+            query_declined: false,
+            path: None,
+            k_paths: vec![],
+        }
+    }
+    pub fn new_nearest<G: SpGraph>(graph: &G, source: usize, targets: &[usize]) -> Self {
+        Self {
+            measure: M::initialize_nearest(graph, source, targets),
+            shortest_distance: None,
+            negative_cycle: false,
+            distance_overflow: false,
+            query_declined: false,
+            path: None,
+            k_paths: vec![],
         }
     }
     pub fn reached(mut self, distance: Weight) -> Self {
@@ -17,11 +100,61 @@ impl<M: Measure> Solution<M> {
         self.measure.finalize(self.shortest_distance);
         self
     }
+    pub fn reached_with_path<G: SpGraph>(
+        mut self,
+        graph: &G,
+        distance: Weight,
+        path: ShortestPath,
+    ) -> Self {
+        self = self.reached(distance);
+        self.measure.finalize_path(graph, &path);
+        self.path = Some(path);
+        self
+    }
+    pub fn reached_with_k_paths<G: SpGraph>(mut self, graph: &G, paths: Vec<ShortestPath>) -> Self {
+        self = self.reached(paths[0].distance());
+        self.measure.finalize_k_paths(graph, &paths);
+        self.path = Some(paths[0].clone());
+        self.k_paths = paths;
+        self
+    }
+    pub fn nearest_reached(mut self, target: usize, distance: Weight) -> Self {
+        self = self.reached(distance);
+        self.measure.finalize_nearest(target);
+        self
+    }
+    pub fn all_reached<W: WeightType>(mut self, distances: &[W]) -> Self {
+        self.measure.finalize_one_to_all(distances);
+        self
+    }
+    pub fn table_reached(mut self, table: &[Weight]) -> Self {
+        self.measure.finalize_many_to_many(table);
+        self
+    }
     pub fn not_connected(mut self) -> Self {
         self.measure.finalize(self.shortest_distance);
         self
     }
+    pub fn negative_cycle(mut self) -> Self {
+        self.negative_cycle = true;
+        self.measure.negative_cycle();
+        self.measure.finalize(self.shortest_distance);
+        self
+    }
+    pub fn overflow(&mut self) {
+        self.distance_overflow = true;
+    }
+    pub fn decline(mut self) -> Self {
+        self.query_declined = true;
+        self
+    }
     pub fn iterate(&mut self, heap_length: usize, heap_capacity: usize) {
         self.measure.iterate(heap_length, heap_capacity)
     }
+    pub fn create_label(&mut self) {
+        self.measure.create_label()
+    }
+    pub fn check_dominance(&mut self) {
+        self.measure.check_dominance()
+    }
 }
@@ -0,0 +1,127 @@
+use super::{
+    measure::run_measure::Measure,
+    path::Predecessors,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::collections::VecDeque;
+
+/// Shortest path faster algorithm, queue-based Bellman-Ford where only the nodes
+/// whose distances have decreased are scanned again.
+///
+/// The number of edges on the current path to each node is tracked;
+/// a path with `n` edges contains a cycle, which must be negative, since distances only decrease.
+#[derive(Default)]
+pub struct Spfa {
+    queue: VecDeque<usize>,
+    in_queue: Vec<bool>,
+    distances: Vec<Weight>,
+    predecessors: Predecessors,
+    num_path_edges: Vec<usize>,
+}
+
+impl Spfa {
+    // computes the distances from the source to all nodes; returns false if a negative cycle is reachable
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        solution: &mut Solution<M>,
+    ) -> bool {
+        // reset
+        let num_nodes = graph.num_nodes();
+        self.distances.clear();
+        self.distances.resize(num_nodes, Weight::MAX);
+        self.in_queue.clear();
+        self.in_queue.resize(num_nodes, false);
+        self.num_path_edges.clear();
+        self.num_path_edges.resize(num_nodes, 0);
+        self.queue.clear();
+
+        // init
+        self.distances[source] = 0;
+        self.queue.push_back(source);
+        self.in_queue[source] = true;
+
+        // iterate
+        while let Some(position) = self.queue.pop_front() {
+            self.in_queue[position] = false;
+            let cost = self.distances[position];
+
+            let mut out_edges = graph.out_edges(position);
+            while let Some(edge) = out_edges.next_edge() {
+                let (head, weight) = (edge.head(), edge.weight());
+                let next_cost = cost + weight;
+                if next_cost < self.distances[head] {
+                    self.distances[head] = next_cost;
+                    self.predecessors.set(head, position, weight);
+                    self.num_path_edges[head] = self.num_path_edges[position] + 1;
+                    if self.num_path_edges[head] >= num_nodes {
+                        return false;
+                    }
+                    if !self.in_queue[head] {
+                        self.in_queue[head] = true;
+                        self.queue.push_back(head);
+                    }
+                }
+            }
+
+            solution.iterate(self.queue.len(), self.queue.capacity());
+        }
+
+        true
+    }
+}
+
+impl<G: SpGraph> PureShortestDistanceAlgorithm<G> for Spfa {
+    fn new(_: &G) -> Self {
+        Self::default()
+    }
+}
+impl<G: SpGraph> ShortestDistanceAlgorithm<G> for Spfa {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        if !self.run_cached_core(graph, source, &mut solution) {
+            return solution.negative_cycle();
+        }
+        match self.distances[sink] {
+            Weight::MAX => solution.not_connected(),
+            distance => solution.reached(distance),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let converged = self.run_cached_core(graph, source, &mut solution);
+        self.predecessors.stop_tracking();
+        if !converged {
+            return solution.negative_cycle();
+        }
+        match self.distances[sink] {
+            Weight::MAX => solution.not_connected(),
+            distance => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance, path)
+            }
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        match self.run_cached_core(graph, source, &mut solution) {
+            true => solution.all_reached(&self.distances[..graph.num_nodes()]),
+            false => solution.negative_cycle(),
+        }
+    }
+}
@@ -1,47 +1,56 @@
 use super::{
-    measure::run_measure::Measure, sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+    measure::run_measure::Measure,
+    path::Predecessors,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+    target_set::{Sink, TargetSet},
 };
 use crate::graph::{
     out_edges::{OutEdgeData, OutEdges},
     sp_graph::SpGraph,
+    weight_type::WeightType,
 };
 use crate::Weight;
 use std::cmp::Ordering;
 
 #[derive(Default)]
-pub struct StdPqDijkstra {
-    heap: std::collections::BinaryHeap<State>,
-    distances: Vec<Weight>,
+pub struct StdPqDijkstra<W: WeightType = Weight> {
+    heap: std::collections::BinaryHeap<State<W>>,
+    distances: Vec<W>,
+    predecessors: Predecessors,
 }
 
-impl<G: SpGraph> ShortestDistanceAlgorithm<G> for StdPqDijkstra {
-    fn new(_: &G) -> Self {
-        Self::default()
-    }
-    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+impl<W: WeightType> StdPqDijkstra<W> {
+    // runs until the sink is settled, or all reachable nodes are settled when the sink is None
+    fn run_cached_core<G: SpGraph<W = W>, M: Measure, S: Sink>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        mut sink: S,
+        solution: &mut Solution<M>,
+    ) -> Option<W> {
         // reset
-        let mut solution = Solution::new(graph, source, sink);
         self.distances
             .iter_mut()
             .take(graph.num_nodes())
-            .for_each(|x| *x = Weight::MAX);
+            .for_each(|x| *x = W::INFINITY);
         if self.distances.len() < graph.num_nodes() {
             self.distances
-                .extend((0..graph.num_nodes() - self.distances.len()).map(|_| Weight::MAX));
+                .extend((0..graph.num_nodes() - self.distances.len()).map(|_| W::INFINITY));
         }
         self.heap.clear();
 
         // init
-        self.distances[source] = 0;
+        self.distances[source] = W::ZERO;
         self.heap.push(State {
-            cost: Weight::default(),
+            cost: W::ZERO,
             position: source,
         });
 
         // iterate
         while let Some(State { cost, position }) = self.heap.pop() {
-            if position == sink {
-                return solution.reached(cost);
+            if sink.is_sink(position) {
+                return Some(cost);
             }
 
             if cost > self.distances[position] {
@@ -50,32 +59,92 @@ impl<G: SpGraph> ShortestDistanceAlgorithm<G> for StdPqDijkstra {
 
             let mut out_edges = graph.out_edges(position);
             while let Some(edge) = out_edges.next_edge() {
-                let (head, weight) = (edge.head(), edge.weight());
+                let (head, weight) = (edge.head(), edge.typed_weight());
+                let Some(next_cost) = cost.checked_add(weight) else {
+                    solution.overflow();
+                    return None;
+                };
                 let next = State {
-                    cost: cost + weight,
+                    cost: next_cost,
                     position: head,
                 };
 
                 if next.cost < self.distances[next.position] {
                     self.heap.push(next);
                     self.distances[next.position] = next.cost;
+                    self.predecessors
+                        .set(next.position, position, weight.to_weight());
                 }
             }
 
             solution.iterate(self.heap.len(), self.heap.capacity());
         }
 
-        solution.not_connected()
+        None
+    }
+}
+
+impl<G: SpGraph<W = W>, W: WeightType> PureShortestDistanceAlgorithm<G> for StdPqDijkstra<W> {
+    fn new(_: &G) -> Self {
+        Self::default()
+    }
+}
+impl<G: SpGraph<W = W>, W: WeightType> ShortestDistanceAlgorithm<G> for StdPqDijkstra<W> {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        match self.run_cached_core(graph, source, Some(sink), &mut solution) {
+            Some(distance) => solution.reached(distance.to_weight()),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let distance = self.run_cached_core(graph, source, Some(sink), &mut solution);
+        self.predecessors.stop_tracking();
+        match distance {
+            Some(distance) => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance.to_weight(), path)
+            }
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.run_cached_core(graph, source, None, &mut solution);
+        solution.all_reached(&self.distances[..graph.num_nodes()])
+    }
+    fn run_cached_nearest<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        targets: &[usize],
+    ) -> Solution<M> {
+        let mut solution = Solution::new_nearest(graph, source, targets);
+        let mut target_set = TargetSet::new(graph.num_nodes(), targets);
+        match self.run_cached_core(graph, source, &mut target_set, &mut solution) {
+            Some(distance) => {
+                let target = target_set.reached().expect("search stops at a target");
+                solution.nearest_reached(target, distance.to_weight())
+            }
+            None => solution.not_connected(),
+        }
     }
 }
 
 /// State required for the std::collections::BinaryHeap.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct State {
-    pub cost: Weight,
+pub struct State<W = Weight> {
+    pub cost: W,
     pub position: usize,
 }
-impl Ord for State {
+impl<W: WeightType> Ord for State<W> {
     fn cmp(&self, other: &Self) -> Ordering {
         other
             .cost
@@ -83,7 +152,7 @@ impl Ord for State {
             .then_with(|| self.position.cmp(&other.position))
     }
 }
-impl PartialOrd for State {
+impl<W: WeightType> PartialOrd for State<W> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
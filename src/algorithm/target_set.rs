@@ -0,0 +1,52 @@
+/// Stopping criterion of the label-setting searches, checked whenever a node is settled.
+pub trait Sink {
+    fn is_sink(&mut self, node: usize) -> bool;
+}
+
+/// A single sink, or no sink for one-to-all searches.
+impl Sink for Option<usize> {
+    #[inline(always)]
+    fn is_sink(&mut self, node: usize) -> bool {
+        *self == Some(node)
+    }
+}
+
+impl<S: Sink> Sink for &mut S {
+    #[inline(always)]
+    fn is_sink(&mut self, node: usize) -> bool {
+        (**self).is_sink(node)
+    }
+}
+
+/// Set of candidate targets where the search stops at the first settled target, the nearest one.
+pub struct TargetSet {
+    is_target: Vec<bool>,
+    reached: Option<usize>,
+}
+
+impl TargetSet {
+    pub fn new(num_nodes: usize, targets: &[usize]) -> Self {
+        let mut is_target = vec![false; num_nodes];
+        for &target in targets {
+            is_target[target] = true;
+        }
+        Self {
+            is_target,
+            reached: None,
+        }
+    }
+    pub fn reached(&self) -> Option<usize> {
+        self.reached
+    }
+}
+
+impl Sink for TargetSet {
+    #[inline(always)]
+    fn is_sink(&mut self, node: usize) -> bool {
+        let is_target = self.is_target[node];
+        if is_target {
+            self.reached = Some(node);
+        }
+        is_target
+    }
+}
@@ -0,0 +1,87 @@
+use super::{
+    measure::run_measure::Measure, sd_algorithm::ShortestDistanceAlgorithm, solution::Solution,
+    std_pq_dijkstra::State,
+};
+use crate::{
+    graph::{out_edges::OutEdgeData, td_graph::TdGraph},
+    Weight,
+};
+use std::collections::BinaryHeap;
+
+/// Time-dependent Dijkstra's algorithm settling the nodes in the order of their earliest arrival times
+/// when departing from the source at `departure_time`; correct since the travel time functions satisfy FIFO.
+///
+/// The shortest distance of a query is the travel time, the earliest arrival at the sink minus the departure time.
+pub struct TdDijkstra {
+    departure_time: Weight,
+    heap: BinaryHeap<State>,
+    arrivals: Vec<Weight>,
+}
+
+impl TdDijkstra {
+    pub fn new<G: TdGraph>(_: &G, departure_time: Weight) -> Self {
+        Self {
+            departure_time,
+            heap: Default::default(),
+            arrivals: Default::default(),
+        }
+    }
+    fn run_cached_core<G: TdGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+        solution: &mut Solution<M>,
+    ) -> Option<Weight> {
+        // reset
+        self.arrivals.clear();
+        self.arrivals.resize(graph.num_nodes(), Weight::MAX);
+        self.heap.clear();
+
+        // init
+        self.arrivals[source] = self.departure_time;
+        self.heap.push(State {
+            cost: self.departure_time,
+            position: source,
+        });
+
+        // iterate
+        while let Some(State { cost, position }) = self.heap.pop() {
+            if position == sink {
+                return Some(cost - self.departure_time);
+            }
+
+            if cost > self.arrivals[position] {
+                continue;
+            }
+
+            for (edge, travel_time) in graph.td_out_edges(position) {
+                let next = State {
+                    cost: cost + travel_time.travel_time(cost),
+                    position: edge.head(),
+                };
+                if next.cost < self.arrivals[next.position] {
+                    self.heap.push(next);
+                    self.arrivals[next.position] = next.cost;
+                }
+            }
+
+            solution.iterate(self.heap.len(), self.heap.capacity());
+        }
+
+        None
+    }
+}
+
+impl<G: TdGraph> ShortestDistanceAlgorithm<G> for TdDijkstra {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new_time_dependent(graph, source, sink, self.departure_time);
+        match self.run_cached_core(graph, source, sink, &mut solution) {
+            Some(distance) => solution.reached(distance),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        Solution::new_one_to_all(graph, source).decline()
+    }
+}
@@ -0,0 +1,258 @@
+use super::{
+    dijkstra_queue::DijkstraQueue,
+    measure::{distances::Distances, no_measurement::NoMeasurement},
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    std_pq_dijkstra::StdPqDijkstra,
+};
+use crate::{
+    experimentation::factors::{
+        graph_data::random::{GraphRandom, RandomResources, RandomWeights},
+        pairs::FactorPairs,
+        sources::FactorSources,
+        tables::FactorTables,
+    },
+    graph::{
+        adjlist_jagged_vec::AdjListJaggedVec,
+        coordinates::{Coordinates, Point},
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+        sp_graph_builder::SpGraphBuilder,
+    },
+    Weight,
+};
+use proptest::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+};
+
+/// Builder of a random graph with non-negative weights such that any graph representation can be tested.
+pub fn random_graph_builder<B: SpGraphBuilder>(
+    seed: u64,
+    num_nodes: usize,
+    density: f32,
+    weights: RandomWeights,
+) -> B {
+    let random = GraphRandom {
+        seed,
+        num_nodes,
+        density,
+        negative_weights: false,
+        weights,
+        resources: RandomResources::None,
+    };
+    random.create_graph_builder::<B>()
+}
+pub fn random_graph(
+    seed: u64,
+    num_nodes: usize,
+    density: f32,
+    weights: RandomWeights,
+) -> AdjListJaggedVec {
+    random_graph_builder::<AdjListJaggedVec>(seed, num_nodes, density, weights).build()
+}
+/// Uniformly random positions over the whole longitude & latitude range.
+pub fn random_coordinates(seed: u64, num_nodes: usize) -> Coordinates {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let points = (0..num_nodes)
+        .map(|_| Point::new(rng.gen_range(-180.0..180.0), rng.gen_range(-90.0..90.0)))
+        .collect();
+    Coordinates::new(points)
+}
+pub fn weights() -> impl Strategy<Value = RandomWeights> {
+    prop_oneof![
+        Just(RandomWeights::Uniform),
+        Just(RandomWeights::Unit),
+        Just(RandomWeights::ZeroOne),
+    ]
+}
+
+/// Asserts that the algorithm finds the shortest distances of dijkstra for random pairs.
+pub fn assert_same_distances<G, A>(graph: &G, pairs_seed: u64, algorithm: &mut A)
+where
+    G: SpGraph<W = Weight>,
+    A: ShortestDistanceAlgorithm<G>,
+{
+    let mut dijkstra = StdPqDijkstra::new(graph);
+    let pairs = FactorPairs {
+        seed: pairs_seed,
+        num_pairs: 50,
+        paths: false,
+    };
+    for (source, sink) in pairs.create_pairs(graph.num_nodes()) {
+        let expected = dijkstra.run_cached::<NoMeasurement>(graph, source, sink);
+        let found = algorithm.run_cached::<NoMeasurement>(graph, source, sink);
+        assert_eq!(
+            expected.shortest_distance, found.shortest_distance,
+            "{}-{}",
+            source, sink
+        );
+    }
+}
+
+/// Asserts that both graphs have the same out edges in the same order, and that dijkstra finds the same distances on them.
+pub fn assert_same_graphs<G, H>(expected: &G, found: &H, pairs_seed: u64)
+where
+    G: SpGraph<W = Weight>,
+    H: SpGraph<W = Weight>,
+{
+    assert_eq!(expected.num_nodes(), found.num_nodes());
+    assert_eq!(expected.num_edges(), found.num_edges());
+    for node in 0..expected.num_nodes() {
+        assert_eq!(
+            out_edges(expected, node),
+            out_edges(found, node),
+            "{}",
+            node
+        );
+    }
+
+    let mut expected_dijkstra = StdPqDijkstra::new(expected);
+    let mut found_dijkstra = StdPqDijkstra::new(found);
+    let pairs = FactorPairs {
+        seed: pairs_seed,
+        num_pairs: 50,
+        paths: false,
+    };
+    for (source, sink) in pairs.create_pairs(expected.num_nodes()) {
+        let e = expected_dijkstra.run_cached::<NoMeasurement>(expected, source, sink);
+        let f = found_dijkstra.run_cached::<NoMeasurement>(found, source, sink);
+        assert_eq!(
+            e.shortest_distance, f.shortest_distance,
+            "{}-{}",
+            source, sink
+        );
+    }
+}
+
+fn out_edges<G: SpGraph<W = Weight>>(graph: &G, node: usize) -> Vec<(usize, Weight)> {
+    let mut edges = vec![];
+    let mut out_edges = graph.out_edges(node);
+    while let Some(edge) = out_edges.next_edge() {
+        edges.push((edge.head(), edge.weight()));
+    }
+    edges
+}
+
+/// Asserts that the algorithm finds the shortest distances of dijkstra from random sources to all nodes.
+pub fn assert_same_one_to_all_distances<G, A>(graph: &G, sources_seed: u64, algorithm: &mut A)
+where
+    G: SpGraph<W = Weight>,
+    A: ShortestDistanceAlgorithm<G>,
+{
+    let mut dijkstra = StdPqDijkstra::new(graph);
+    let sources = FactorSources {
+        seed: sources_seed,
+        num_sources: 5,
+    };
+    for source in sources.create_sources(graph.num_nodes()) {
+        let expected = dijkstra.run_cached_one_to_all::<Distances>(graph, source);
+        let found = algorithm.run_cached_one_to_all::<Distances>(graph, source);
+        assert_eq!(
+            expected.measure.distances, found.measure.distances,
+            "{}",
+            source
+        );
+    }
+}
+
+/// Asserts that the algorithm finds the distance table of dijkstra for random sources & targets.
+pub fn assert_same_tables<G, A>(graph: &G, tables_seed: u64, algorithm: &mut A)
+where
+    G: SpGraph<W = Weight>,
+    A: ShortestDistanceAlgorithm<G>,
+{
+    let mut dijkstra = StdPqDijkstra::new(graph);
+    let tables = FactorTables {
+        seed: tables_seed,
+        num_sources: 5,
+        num_targets: 8,
+    };
+    let (sources, targets) = tables.create_sources_and_targets(graph.num_nodes());
+    let expected = dijkstra.run_cached_many_to_many::<Distances>(graph, &sources, &targets);
+    let found = algorithm.run_cached_many_to_many::<Distances>(graph, &sources, &targets);
+    assert_eq!(expected.measure.distances, found.measure.distances);
+}
+
+/// Operation on a queue of the property tests; `Relax` is skipped unless it improves the key of the node
+/// as in dijkstra, and `Pop` peeks before popping.
+#[derive(Clone, Copy, Debug)]
+pub enum QueueOp {
+    Relax(usize, Weight),
+    Pop,
+}
+pub fn queue_ops(num_nodes: usize, max_key: Weight) -> impl Strategy<Value = Vec<QueueOp>> {
+    let op = prop_oneof![
+        3 => (0..num_nodes, 0..=max_key).prop_map(|(node, key)| QueueOp::Relax(node, key)),
+        1 => Just(QueueOp::Pop),
+    ];
+    prop::collection::vec(op, 0..400)
+}
+
+/// Kind of the queue under test, which determines the reference model.
+#[derive(Clone, Copy, Debug)]
+pub enum QueueKind {
+    /// Keys of `Relax` are offsets from the last popped key; every relax pushes an entry & stale entries are popped.
+    Monotone,
+    /// Keys of `Relax` are absolute; relaxing a node in the queue decreases its key.
+    DecreaseKey,
+}
+
+/// Asserts that the queue peeks & pops the keys of a reference model followed by popping the remaining entries.
+pub fn assert_same_keys_as_model<Q: DijkstraQueue>(
+    queue: &mut Q,
+    ops: &[QueueOp],
+    kind: QueueKind,
+) {
+    let mut distances = BTreeMap::new();
+    let mut entries = BinaryHeap::new();
+    let mut in_queue = BTreeMap::new();
+    let mut last = 0;
+
+    let pop = |queue: &mut Q, entries: &mut BinaryHeap<_>, in_queue: &mut BTreeMap<_, _>| {
+        let expected = match kind {
+            QueueKind::Monotone => entries.pop().map(|Reverse(key)| key),
+            QueueKind::DecreaseKey => in_queue.values().min().copied(),
+        };
+        assert_eq!(queue.peek_key(), expected);
+        let popped = queue.pop();
+        assert_eq!(popped.map(|x| x.1), expected);
+        if let (QueueKind::DecreaseKey, Some((node, key))) = (kind, popped) {
+            assert_eq!(in_queue.remove(&node), Some(key));
+        }
+        expected
+    };
+
+    for &op in ops {
+        match op {
+            QueueOp::Relax(node, key) => {
+                let key = match kind {
+                    QueueKind::Monotone => last + key,
+                    QueueKind::DecreaseKey => key,
+                };
+                if distances.get(&node).is_some_and(|&d| d <= key) {
+                    continue;
+                }
+                distances.insert(node, key);
+                queue.relax(node, key);
+                entries.push(Reverse(key));
+                in_queue.insert(node, key);
+            }
+            QueueOp::Pop => {
+                if let Some(key) = pop(queue, &mut entries, &mut in_queue) {
+                    last = key;
+                }
+            }
+        }
+        let expected_len = match kind {
+            QueueKind::Monotone => entries.len(),
+            QueueKind::DecreaseKey => in_queue.len(),
+        };
+        assert_eq!(queue.len(), expected_len);
+    }
+
+    while pop(queue, &mut entries, &mut in_queue).is_some() {}
+    assert_eq!(queue.len(), 0);
+}
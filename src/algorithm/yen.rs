@@ -0,0 +1,309 @@
+use super::{
+    measure::run_measure::Measure,
+    path::{Predecessors, ShortestPath},
+    sd_algorithm::ShortestDistanceAlgorithm,
+    solution::Solution,
+    std_pq_dijkstra::State,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+};
+
+/// Yen's algorithm for the k shortest loopless paths from the source to the sink.
+///
+/// The i-th path is selected among candidates deviating from each of the previous paths:
+/// for each spur node on the last selected path, the root path up to the spur node is kept and
+/// the rest is the shortest spur path avoiding the nodes of the root path and the edges leaving
+/// the spur node along any selected path with the same root.
+///
+/// Paths are identified by their node sequences; parallel edges do not create distinct paths.
+pub struct Yen {
+    k: usize,
+    search: SpurSearch,
+}
+
+impl Yen {
+    pub fn new<G: SpGraph>(_: &G, k: usize) -> Self {
+        Self {
+            k,
+            search: SpurSearch::default(),
+        }
+    }
+
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+        solution: &mut Solution<M>,
+    ) -> Vec<ShortestPath> {
+        self.search.reset(graph.num_nodes());
+        let Some(first) = self.search.run(graph, source, Some(sink), solution) else {
+            return vec![];
+        };
+
+        let mut paths = vec![first];
+        let mut candidates = BinaryHeap::new();
+        let mut known: HashSet<Vec<usize>> = HashSet::from_iter([paths[0].nodes.clone()]);
+        while paths.len() < self.k {
+            let last = paths.last().expect("at least one path is found");
+            for i in 0..last.nodes.len() - 1 {
+                let (spur, root) = (last.nodes[i], &last.nodes[..=i]);
+
+                for path in paths.iter().filter(|p| p.nodes.len() > i + 1) {
+                    if &path.nodes[..=i] == root {
+                        self.search.blocked_heads.push(path.nodes[i + 1]);
+                    }
+                }
+                for &node in &root[..i] {
+                    self.search.blocked_nodes[node] = true;
+                }
+
+                if let Some(spur_path) = self.search.run(graph, spur, Some(sink), solution) {
+                    let mut candidate = ShortestPath {
+                        nodes: root[..i].to_vec(),
+                        weights: last.weights[..i].to_vec(),
+                    };
+                    candidate.nodes.extend(spur_path.nodes);
+                    candidate.weights.extend(spur_path.weights);
+                    if known.insert(candidate.nodes.clone()) {
+                        candidates.push(Reverse(Candidate(candidate.distance(), candidate)));
+                    }
+                }
+
+                self.search.blocked_heads.clear();
+                for &node in &root[..i] {
+                    self.search.blocked_nodes[node] = false;
+                }
+            }
+
+            match candidates.pop() {
+                Some(Reverse(Candidate(_, path))) => paths.push(path),
+                None => break,
+            }
+        }
+        paths
+    }
+}
+
+impl<G: SpGraph> ShortestDistanceAlgorithm<G> for Yen {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        let paths = self.run_cached_core(graph, source, sink, &mut solution);
+        match paths.is_empty() {
+            true => solution.not_connected(),
+            false => solution.reached_with_k_paths(graph, paths),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        self.run_cached(graph, source, sink)
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.search.reset(graph.num_nodes());
+        self.search.run(graph, source, None, &mut solution);
+        solution.all_reached(&self.search.distances[..graph.num_nodes()])
+    }
+}
+
+// dijkstra's algorithm from the spur node skipping the blocked nodes & the blocked edges leaving the spur node
+#[derive(Default)]
+struct SpurSearch {
+    heap: BinaryHeap<State>,
+    distances: Vec<Weight>,
+    predecessors: Predecessors,
+    blocked_nodes: Vec<bool>,
+    blocked_heads: Vec<usize>,
+}
+
+impl SpurSearch {
+    fn reset(&mut self, num_nodes: usize) {
+        self.blocked_nodes.clear();
+        self.blocked_nodes.resize(num_nodes, false);
+        self.blocked_heads.clear();
+    }
+
+    fn run<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: Option<usize>,
+        solution: &mut Solution<M>,
+    ) -> Option<ShortestPath> {
+        // reset
+        self.distances.clear();
+        self.distances.resize(graph.num_nodes(), Weight::MAX);
+        self.predecessors.start_tracking(graph.num_nodes());
+        self.heap.clear();
+
+        // init
+        self.distances[source] = 0;
+        self.heap.push(State {
+            cost: Weight::default(),
+            position: source,
+        });
+
+        // iterate
+        while let Some(State { cost, position }) = self.heap.pop() {
+            if Some(position) == sink {
+                return Some(self.predecessors.path(source, position));
+            }
+
+            if cost > self.distances[position] {
+                continue;
+            }
+
+            let mut out_edges = graph.out_edges(position);
+            while let Some(edge) = out_edges.next_edge() {
+                let (head, weight) = (edge.head(), edge.weight());
+                if self.blocked_nodes[head]
+                    || (position == source && self.blocked_heads.contains(&head))
+                {
+                    continue;
+                }
+                let next_cost = cost + weight;
+                if next_cost < self.distances[head] {
+                    self.heap.push(State {
+                        cost: next_cost,
+                        position: head,
+                    });
+                    self.distances[head] = next_cost;
+                    self.predecessors.set(head, position, weight);
+                }
+            }
+
+            solution.iterate(self.heap.len(), self.heap.capacity());
+        }
+
+        None
+    }
+}
+
+// candidate path ordered by its distance
+struct Candidate(Weight, ShortestPath);
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl Eq for Candidate {}
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::{
+            measure::no_measurement::NoMeasurement,
+            test_utils::{assert_same_distances, random_graph, weights},
+        },
+        experimentation::factors::pairs::FactorPairs,
+        graph::adjlist_jagged_vec::AdjListJaggedVec,
+    };
+    use proptest::prelude::*;
+
+    // distances of all simple paths from the source to the sink by exhaustive search, in increasing order
+    fn simple_path_distances(graph: &AdjListJaggedVec, source: usize, sink: usize) -> Vec<Weight> {
+        fn extend(
+            graph: &AdjListJaggedVec,
+            node: usize,
+            sink: usize,
+            distance: Weight,
+            on_path: &mut Vec<bool>,
+            distances: &mut Vec<Weight>,
+        ) {
+            if node == sink {
+                distances.push(distance);
+                return;
+            }
+            on_path[node] = true;
+            for head in 0..graph.num_nodes() {
+                // parallel edges do not create distinct paths
+                let weight = graph[node]
+                    .iter()
+                    .filter(|e| e.head() == head)
+                    .map(|e| e.weight())
+                    .min();
+                if let (false, Some(weight)) = (on_path[head], weight) {
+                    extend(graph, head, sink, distance + weight, on_path, distances);
+                }
+            }
+            on_path[node] = false;
+        }
+
+        let mut distances = vec![];
+        let on_path = &mut vec![false; graph.num_nodes()];
+        extend(graph, source, sink, 0, on_path, &mut distances);
+        distances.sort();
+        distances
+    }
+
+    proptest! {
+        #[test]
+        fn first_path_matches_dijkstra(
+            seed in any::<u64>(),
+            pairs_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+            k in 1..4usize,
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            assert_same_distances(&graph, pairs_seed, &mut Yen::new(&graph, k));
+        }
+
+        #[test]
+        fn paths_match_exhaustive_search(
+            seed in any::<u64>(),
+            pairs_seed in any::<u64>(),
+            num_nodes in 4..8usize,
+            density in 0.1f32..0.6,
+            weights in weights(),
+            k in 1..12usize,
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let yen = &mut Yen::new(&graph, k);
+            let pairs = FactorPairs { seed: pairs_seed, num_pairs: 10, paths: false };
+            for (source, sink) in pairs.create_pairs(num_nodes) {
+                let paths = yen.run_cached::<NoMeasurement>(&graph, source, sink).k_paths;
+                let expected = simple_path_distances(&graph, source, sink);
+                let distances: Vec<_> = paths.iter().map(ShortestPath::distance).collect();
+                prop_assert_eq!(&distances[..], &expected[..k.min(expected.len())]);
+
+                for path in &paths {
+                    prop_assert_eq!(path.nodes.first(), Some(&source));
+                    prop_assert_eq!(path.nodes.last(), Some(&sink));
+                    prop_assert_eq!(path.nodes.iter().collect::<HashSet<_>>().len(), path.nodes.len());
+                    for (edge, &weight) in path.nodes.windows(2).zip(&path.weights) {
+                        let (tail, head) = (edge[0], edge[1]);
+                        prop_assert!(graph[tail].iter().any(|e| e.head() == head && e.weight() == weight));
+                    }
+                }
+                let distinct: HashSet<_> = paths.iter().map(|p| &p.nodes).collect();
+                prop_assert_eq!(distinct.len(), paths.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::{
+    measure::run_measure::Measure,
+    path::Predecessors,
+    sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+    solution::Solution,
+};
+use crate::{
+    graph::{
+        out_edges::{OutEdgeData, OutEdges},
+        sp_graph::SpGraph,
+    },
+    Weight,
+};
+use std::collections::VecDeque;
+
+/// 0-1 breadth-first search on a deque; heads of zero weight edges are pushed to the front
+/// and heads of unit weight edges to the back, so that nodes are popped in the order of their distances.
+///
+/// Hence, only valid for graphs with weights in {0, 1}.
+#[derive(Default)]
+pub struct ZeroOneBfs {
+    deque: VecDeque<(usize, Weight)>,
+    distances: Vec<Weight>,
+    predecessors: Predecessors,
+}
+
+impl ZeroOneBfs {
+    // runs until the sink is settled, or all reachable nodes are settled when the sink is None
+    fn run_cached_core<G: SpGraph, M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: Option<usize>,
+        solution: &mut Solution<M>,
+    ) -> Option<Weight> {
+        // reset
+        self.distances.clear();
+        self.distances.resize(graph.num_nodes(), Weight::MAX);
+        self.deque.clear();
+
+        // init
+        self.distances[source] = 0;
+        self.deque.push_back((source, 0));
+
+        // iterate
+        while let Some((position, cost)) = self.deque.pop_front() {
+            if Some(position) == sink {
+                return Some(cost);
+            }
+
+            if cost > self.distances[position] {
+                continue;
+            }
+
+            let mut out_edges = graph.out_edges(position);
+            while let Some(edge) = out_edges.next_edge() {
+                let (head, weight) = (edge.head(), edge.weight());
+                let next_cost = cost + weight;
+                if next_cost < self.distances[head] {
+                    self.distances[head] = next_cost;
+                    self.predecessors.set(head, position, weight);
+                    match weight == 0 {
+                        true => self.deque.push_front((head, next_cost)),
+                        false => self.deque.push_back((head, next_cost)),
+                    }
+                }
+            }
+
+            solution.iterate(self.deque.len(), self.deque.capacity());
+        }
+
+        None
+    }
+}
+
+impl<G: SpGraph> PureShortestDistanceAlgorithm<G> for ZeroOneBfs {
+    fn new(_: &G) -> Self {
+        Self::default()
+    }
+}
+impl<G: SpGraph> ShortestDistanceAlgorithm<G> for ZeroOneBfs {
+    fn run_cached<M: Measure>(&mut self, graph: &G, source: usize, sink: usize) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        match self.run_cached_core(graph, source, Some(sink), &mut solution) {
+            Some(distance) => solution.reached(distance),
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_path<M: Measure>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        sink: usize,
+    ) -> Solution<M> {
+        let mut solution = Solution::new(graph, source, sink);
+        self.predecessors.start_tracking(graph.num_nodes());
+        let distance = self.run_cached_core(graph, source, Some(sink), &mut solution);
+        self.predecessors.stop_tracking();
+        match distance {
+            Some(distance) => {
+                let path = self.predecessors.path(source, sink);
+                solution.reached_with_path(graph, distance, path)
+            }
+            None => solution.not_connected(),
+        }
+    }
+    fn run_cached_one_to_all<M: Measure>(&mut self, graph: &G, source: usize) -> Solution<M> {
+        let mut solution = Solution::new_one_to_all(graph, source);
+        self.run_cached_core(graph, source, None, &mut solution);
+        solution.all_reached(&self.distances[..graph.num_nodes()])
+    }
+}
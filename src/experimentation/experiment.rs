@@ -1,7 +1,9 @@
 use super::{
     factors::{
-        algorithm::factor_algorithm::FactorAlgorithm, factor::Factor,
-        graph_representation::FactorGraphRepresentation,
+        algorithm::factor_algorithm::FactorAlgorithm, delta::FactorDelta, factor::Factor,
+        graph_representation::FactorGraphRepresentation, node_ordering::FactorNodeOrdering,
+        num_paths::FactorNumPaths, num_threads::FactorNumThreads,
+        partition_size::FactorPartitionSize, weight_type::FactorWeightType,
     },
     measurement::Measurement,
     treatment::Treatment,
@@ -13,7 +15,7 @@ use crate::{
     },
     experimentation::factors::{
         algorithm_data::FactorAlgorithmData, graph_data::factor_graph_data::FactorGraphData,
-        pairs::FactorPairs,
+        queries::FactorQueries,
     },
     utils::{self, cli},
 };
@@ -25,9 +27,22 @@ pub struct Experiment {
     pub measurement: Measurement,
     pub graph_data: Vec<FactorGraphData>,
     pub graph_representation: Vec<FactorGraphRepresentation>,
+    #[serde(default = "default_node_ordering")]
+    pub node_ordering: Vec<FactorNodeOrdering>,
+    #[serde(default = "default_weight_type")]
+    pub weight_type: Vec<FactorWeightType>,
     pub algorithm: Vec<FactorAlgorithm>,
+    #[serde(default = "default_partition_size")]
+    pub partition_size: Vec<FactorPartitionSize>,
+    #[serde(default = "default_num_paths")]
+    pub num_paths: Vec<FactorNumPaths>,
+    #[serde(default = "default_delta")]
+    pub delta: Vec<FactorDelta>,
+    #[serde(default = "default_num_threads")]
+    pub num_threads: Vec<FactorNumThreads>,
     pub algorithm_data: Vec<FactorAlgorithmData>,
-    pub pairs: Vec<FactorPairs>,
+    #[serde(alias = "pairs")]
+    pub queries: Vec<FactorQueries>,
 }
 
 impl Experiment {
@@ -35,39 +50,95 @@ impl Experiment {
         let measurement = Measurement::query_from_cli();
         let graph_data = FactorGraphData::query_levels_from_cli_with_headers();
         let graph_representation = FactorGraphRepresentation::query_levels_from_cli_with_headers();
+        let node_ordering = FactorNodeOrdering::query_levels_from_cli_with_headers();
+        let weight_type = FactorWeightType::query_levels_from_cli_with_headers();
         let algorithm = FactorAlgorithm::query_levels_from_cli_with_headers();
+        let partition_size = FactorPartitionSize::query_levels_from_cli_with_headers();
+        let num_paths = FactorNumPaths::query_levels_from_cli_with_headers();
+        let delta = FactorDelta::query_levels_from_cli_with_headers();
+        let num_threads = FactorNumThreads::query_levels_from_cli_with_headers();
         let algorithm_data = FactorAlgorithmData::query_levels_from_cli_with_headers();
-        let pairs = FactorPairs::query_levels_from_cli_with_headers();
+        let queries = FactorQueries::query_levels_from_cli_with_headers();
         Self {
             measurement,
             graph_data,
             graph_representation,
+            node_ordering,
+            weight_type,
             algorithm,
+            partition_size,
+            num_paths,
+            delta,
+            num_threads,
             algorithm_data,
-            pairs,
+            queries,
         }
     }
+    pub fn validate(&self) -> Result<(), String> {
+        fn validate_levels<F: Factor>(levels: &[F]) -> Result<(), String> {
+            levels.iter().try_for_each(|level| {
+                level
+                    .validate()
+                    .map_err(|e| format!("{} level {:?}: {}", F::name_factor(), level, e))
+            })
+        }
+        validate_levels(&self.graph_data)?;
+        validate_levels(&self.graph_representation)?;
+        validate_levels(&self.node_ordering)?;
+        validate_levels(&self.weight_type)?;
+        validate_levels(&self.algorithm)?;
+        validate_levels(&self.partition_size)?;
+        validate_levels(&self.num_paths)?;
+        validate_levels(&self.delta)?;
+        validate_levels(&self.num_threads)?;
+        validate_levels(&self.algorithm_data)?;
+        validate_levels(&self.queries)
+    }
     pub fn num_treatments(&self) -> usize {
         self.graph_data.len()
             * self.graph_representation.len()
+            * self.node_ordering.len()
+            * self.weight_type.len()
             * self.algorithm.len()
+            * self.partition_size.len()
+            * self.num_paths.len()
+            * self.delta.len()
+            * self.num_threads.len()
             * self.algorithm_data.len()
-            * self.pairs.len()
+            * self.queries.len()
     }
     pub fn create_treatments(&self) -> Vec<Treatment> {
         let mut treatments = vec![];
         for &graph_data in &self.graph_data {
             for &graph_representation in &self.graph_representation {
-                for &algorithm in &self.algorithm {
-                    for &algorithm_data in &self.algorithm_data {
-                        for &pairs in &self.pairs {
-                            treatments.push(Treatment {
-                                graph_data,
-                                graph_representation,
-                                algorithm,
-                                algorithm_data,
-                                pairs,
-                            });
+                for &node_ordering in &self.node_ordering {
+                    for &weight_type in &self.weight_type {
+                        for &algorithm in &self.algorithm {
+                            for &partition_size in &self.partition_size {
+                                for &num_paths in &self.num_paths {
+                                    for &delta in &self.delta {
+                                        for &num_threads in &self.num_threads {
+                                            for &algorithm_data in &self.algorithm_data {
+                                                for &queries in &self.queries {
+                                                    treatments.push(Treatment::new(
+                                                        graph_data,
+                                                        graph_representation,
+                                                        node_ordering,
+                                                        weight_type,
+                                                        algorithm,
+                                                        partition_size,
+                                                        num_paths,
+                                                        delta,
+                                                        num_threads,
+                                                        algorithm_data,
+                                                        queries,
+                                                    ));
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -161,3 +232,33 @@ impl Experiment {
         );
     }
 }
+
+// experiment files created before the node ordering factor keep the original node ids
+fn default_node_ordering() -> Vec<FactorNodeOrdering> {
+    vec![FactorNodeOrdering::default()]
+}
+
+// experiment files created before the weight type factor use the weight type of the crate
+fn default_weight_type() -> Vec<FactorWeightType> {
+    vec![FactorWeightType::default()]
+}
+
+// experiment files created before the partition size factor use the unpartitioned graph
+fn default_partition_size() -> Vec<FactorPartitionSize> {
+    vec![FactorPartitionSize::default()]
+}
+
+// experiment files created before the number of paths factor find the single shortest path
+fn default_num_paths() -> Vec<FactorNumPaths> {
+    vec![FactorNumPaths::default()]
+}
+
+// experiment files created before the delta factor use the bucket width of the former delta-stepping level
+fn default_delta() -> Vec<FactorDelta> {
+    vec![FactorDelta::default()]
+}
+
+// experiment files created before the number of threads factor run sequentially
+fn default_num_threads() -> Vec<FactorNumThreads> {
+    vec![FactorNumThreads::default()]
+}
@@ -0,0 +1,76 @@
+use crate::utils::cli;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum AllPairs {
+    FloydWarshall,
+    BlockedFloydWarshall { block_size: usize },
+    Johnson,
+}
+impl AllPairs {
+    pub fn level_from_cli() -> Vec<Self> {
+        PlainAllPairs::level_from_cli()
+            .into_iter()
+            .flat_map(|all_pairs| match all_pairs {
+                PlainAllPairs::FloydWarshall => vec![Self::FloydWarshall],
+                PlainAllPairs::BlockedFloydWarshall => {
+                    cli::print_scalar_query_get_answers("block size", DEFAULT_BLOCK_SIZE, |b| {
+                        b >= &1
+                    })
+                    .into_iter()
+                    .map(|block_size| Self::BlockedFloydWarshall { block_size })
+                    .collect_vec()
+                }
+                PlainAllPairs::Johnson => vec![Self::Johnson],
+            })
+            .collect()
+    }
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::BlockedFloydWarshall { block_size: 0 } => Err(String::from(
+                "blocked Floyd-Warshall requires a positive block size",
+            )),
+            _ => Ok(()),
+        }
+    }
+    pub fn to_cell_string(self) -> String {
+        match self {
+            Self::FloydWarshall => String::from("All-Pairs\n* Floyd-Warshall"),
+            Self::BlockedFloydWarshall { block_size } => format!(
+                "All-Pairs\n* blocked Floyd-Warshall\n* block size : {}",
+                block_size
+            ),
+            Self::Johnson => String::from("All-Pairs\n* Johnson"),
+        }
+    }
+}
+
+// plain
+#[derive(Clone, Debug, EnumIter)]
+enum PlainAllPairs {
+    FloydWarshall,
+    BlockedFloydWarshall,
+    Johnson,
+}
+impl PlainAllPairs {
+    fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = PlainAllPairs::iter().collect();
+        let definitions = &[
+            "relaxes all pairs through each intermediate node in turn",
+            "Floyd-Warshall on square blocks of the matrix that fit into the cache",
+            "Bellman-Ford node potentials removing negative weights,\n\
+            followed by Dijkstra's algorithm from every node",
+        ];
+        cli::print_subheader(1, "All-Pairs Shortest Paths");
+        cli::print_definition_under_header(
+            "The complete distance matrix is computed once per graph; its time & memory are reported\n\
+            separately, and the queries are answered by looking up the matrix.",
+        );
+        cli::print_table_get_choices("all-pairs algorithm", &available_levels, definitions, 0)
+    }
+}
+
+const DEFAULT_BLOCK_SIZE: usize = 64;
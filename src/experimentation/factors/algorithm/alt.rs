@@ -0,0 +1,63 @@
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Alt {
+    pub selection: LandmarkSelection,
+    pub num_landmarks: usize,
+}
+impl Alt {
+    pub fn level_from_cli() -> Vec<Self> {
+        cli::print_subheader(1, "ALT Landmarks");
+        cli::print_definition_under_header(
+            "Distances from and to the landmarks are computed once per graph in a preprocessing\n\
+            phase, the time & memory of which are reported separately from the queries.",
+        );
+        let selection = LandmarkSelection::level_from_cli();
+        let num_landmarks = cli::print_scalar_query_get_answers(
+            "number of landmarks",
+            DEFAULT_NUM_LANDMARKS,
+            |k| k >= &1,
+        );
+
+        let mut combinations = vec![];
+        for &selection in &selection {
+            for &num_landmarks in &num_landmarks {
+                combinations.push(Self {
+                    selection,
+                    num_landmarks,
+                });
+            }
+        }
+        combinations
+    }
+    pub fn to_cell_string(self) -> String {
+        format!(
+            "ALT\n* landmarks : {}\n* selection : {:?}",
+            self.num_landmarks, self.selection
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, EnumIter, Serialize, Deserialize)]
+pub enum LandmarkSelection {
+    Random,
+    Farthest,
+    Avoid,
+}
+impl LandmarkSelection {
+    fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = LandmarkSelection::iter().collect();
+        let definitions = &[
+            "landmarks are sampled uniformly at random",
+            "each landmark is the node farthest away from the already selected ones",
+            "avoid heuristic: next landmark is a leaf of the shortest path subtree\n\
+            with the weakest lower bounds that does not contain a landmark",
+        ];
+        cli::print_table_get_choices("landmark selection", &available_levels, definitions, 0)
+    }
+}
+
+const DEFAULT_NUM_LANDMARKS: usize = 16;
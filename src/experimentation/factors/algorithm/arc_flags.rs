@@ -0,0 +1,43 @@
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct ArcFlags {
+    pub partitioner: Partitioner,
+}
+impl ArcFlags {
+    pub fn level_from_cli() -> Vec<Self> {
+        cli::print_subheader(1, "Arc Flags");
+        cli::print_definition_under_header(
+            "Nodes are partitioned into the cells of the partition size factor & the flags of the edges are computed by backward searches\n\
+            from the boundary nodes of the cells in a preprocessing phase, the time & memory of which\n\
+            are reported separately from the queries.",
+        );
+        Partitioner::level_from_cli()
+            .into_iter()
+            .map(|partitioner| Self { partitioner })
+            .collect()
+    }
+    pub fn to_cell_string(self) -> String {
+        format!("Arc Flags\n* partitioner : {:?}", self.partitioner)
+    }
+}
+
+#[derive(Clone, Copy, Debug, EnumIter, Serialize, Deserialize)]
+pub enum Partitioner {
+    Grid,
+    BfsGrown,
+}
+impl Partitioner {
+    fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = Partitioner::iter().collect();
+        let definitions = &[
+            "grid on the node coordinates with cells of balanced sizes\n\
+            requires coordinates; hence, only available for Dimacs9th graphs",
+            "cells grown by breadth-first search from randomly sampled seed nodes",
+        ];
+        cli::print_table_get_choices("partitioner", &available_levels, definitions, 1)
+    }
+}
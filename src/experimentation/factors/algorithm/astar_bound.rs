@@ -0,0 +1,29 @@
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, EnumIter, Serialize, Deserialize)]
+pub enum AStarBound {
+    Euclidean,
+    GreatCircle,
+}
+impl AStarBound {
+    pub fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = AStarBound::iter().collect();
+        let definitions = &[
+            "straight line distance on (longitude, latitude) coordinates",
+            "great-circle distance on the sphere by the haversine formula",
+        ];
+
+        cli::print_subheader(1, "A* Lower Bounds");
+        cli::print_definition_under_header(
+            "Distances are scaled by the minimum weight-to-distance ratio of the edges\n\
+            so that the bound is admissible for the travel-distance instances.",
+        );
+        cli::print_table_get_choices("A* lower bound", &available_levels, definitions, 0)
+    }
+    pub fn to_cell_string(self) -> String {
+        format!("A*\n* lower bound: {:?} distance", self)
+    }
+}
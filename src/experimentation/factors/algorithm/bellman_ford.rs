@@ -0,0 +1,35 @@
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, EnumIter, Serialize, Deserialize)]
+pub enum BellmanFord {
+    Rounds,
+    Spfa,
+    Petgraph,
+}
+impl BellmanFord {
+    pub fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = BellmanFord::iter().collect();
+        let definitions = &[
+            "relaxes the edges of all reached nodes in rounds until no distance changes",
+            "shortest path faster algorithm; queue of the nodes with decreased distances",
+            "petgraph::algo::bellman_ford on a copy of the graph with f64 weights\n\
+            only available for petgraph graph representations",
+        ];
+
+        cli::print_subheader(1, "Bellman-Ford Variants");
+        cli::print_definition_under_header(
+            "Both variants allow negative weights and report negative cycles reachable from the source.",
+        );
+        cli::print_table_get_choices("Bellman-Ford variant", &available_levels, definitions, 0)
+    }
+    pub fn to_cell_string(self) -> String {
+        match self {
+            Self::Rounds => String::from("Bellman-Ford\n* rounds over all edges"),
+            Self::Spfa => String::from("Bellman-Ford\n* SPFA queue"),
+            Self::Petgraph => String::from("Bellman-Ford\n* impl: petgraph::algo::bellman_ford"),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use super::{alt::Alt, astar_bound::AStarBound};
+use crate::utils::cli;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum BidirectionalAStar {
+    Coordinates(AStarBound),
+    Landmarks(Alt),
+}
+impl BidirectionalAStar {
+    pub fn level_from_cli() -> Vec<Self> {
+        PlainBidirectionalAStar::level_from_cli()
+            .into_iter()
+            .flat_map(|potential| match potential {
+                PlainBidirectionalAStar::Coordinates => AStarBound::level_from_cli()
+                    .into_iter()
+                    .map(Self::Coordinates)
+                    .collect_vec(),
+                PlainBidirectionalAStar::Landmarks => Alt::level_from_cli()
+                    .into_iter()
+                    .map(Self::Landmarks)
+                    .collect_vec(),
+            })
+            .collect()
+    }
+    pub fn to_cell_string(self) -> String {
+        match self {
+            Self::Coordinates(bound) => format!("Bidirectional {}", bound.to_cell_string()),
+            Self::Landmarks(alt) => format!("Bidirectional {}", alt.to_cell_string()),
+        }
+    }
+}
+
+// plain
+#[derive(Clone, Debug, EnumIter)]
+enum PlainBidirectionalAStar {
+    Coordinates,
+    Landmarks,
+}
+impl PlainBidirectionalAStar {
+    fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = PlainBidirectionalAStar::iter().collect();
+        let definitions = &[
+            "lower bounds computed from node coordinates\n\
+            requires coordinates; hence, only available for Dimacs9th graphs",
+            "landmark & triangle inequality lower bounds computed in a preprocessing phase",
+        ];
+        cli::print_subheader(1, "Bidirectional A*");
+        cli::print_definition_under_header(
+            "Forward & backward searches use the average of the lower bounds to the sink & from the source\n\
+            as consistent potentials, and stop by the bidirectional Dijkstra criterion on the reduced weights.",
+        );
+        cli::print_table_get_choices(
+            "bidirectional A* potential",
+            &available_levels,
+            definitions,
+            0,
+        )
+    }
+}
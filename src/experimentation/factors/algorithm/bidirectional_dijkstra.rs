@@ -0,0 +1,56 @@
+use super::{priority_queue::PriorityQueue, priority_queue_deckey::PriorityQueueDecKey};
+use crate::utils::cli;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum BidirectionalDijkstra {
+    PriorityQueue(PriorityQueue),
+    PriorityQueueDecKey(PriorityQueueDecKey),
+}
+
+impl BidirectionalDijkstra {
+    pub fn level_from_cli() -> Vec<Self> {
+        PlainBidirectionalDijkstra::level_from_cli()
+            .into_iter()
+            .flat_map(|dijkstra| match dijkstra {
+                PlainBidirectionalDijkstra::PriorityQueue => PriorityQueue::level_from_cli()
+                    .into_iter()
+                    .map(BidirectionalDijkstra::PriorityQueue)
+                    .collect_vec(),
+                PlainBidirectionalDijkstra::PriorityQueueDecKey => {
+                    PriorityQueueDecKey::level_from_cli()
+                        .into_iter()
+                        .map(BidirectionalDijkstra::PriorityQueueDecKey)
+                        .collect_vec()
+                }
+            })
+            .collect()
+    }
+    pub fn to_cell_string(self) -> String {
+        match self {
+            Self::PriorityQueue(x) => x.to_cell_string("BidirectionalDijkstra"),
+            Self::PriorityQueueDecKey(x) => x.to_cell_string("BidirectionalDijkstra"),
+        }
+    }
+}
+
+// plain
+#[derive(Clone, Debug, EnumIter)]
+enum PlainBidirectionalDijkstra {
+    PriorityQueue,
+    PriorityQueueDecKey,
+}
+impl PlainBidirectionalDijkstra {
+    fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = PlainBidirectionalDijkstra::iter().collect();
+        let definitions = &[
+            "impl using a basic priority queue per direction",
+            "impl using a priority queue with decrease key per direction",
+        ];
+        cli::print_subheader(1, "Bidirectional Dijsktra Implementations");
+        cli::print_table_get_choices("BidirectionalDijsktra", &available_levels, definitions, 0)
+    }
+}
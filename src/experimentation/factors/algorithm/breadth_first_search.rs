@@ -0,0 +1,31 @@
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, EnumIter, Serialize, Deserialize)]
+pub enum BreadthFirstSearch {
+    Unit,
+    ZeroOne,
+}
+impl BreadthFirstSearch {
+    pub fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = BreadthFirstSearch::iter().collect();
+        let definitions = &[
+            "FIFO queue; requires all weights to be 1",
+            "deque with zero weight edges pushed to the front; requires weights in {0, 1}",
+        ];
+
+        cli::print_subheader(1, "Breadth-First Search Variants");
+        cli::print_definition_under_header(
+            "Both variants are rejected for graphs with other weights; see the weights of random graphs.",
+        );
+        cli::print_table_get_choices("breadth-first search", &available_levels, definitions, 0)
+    }
+    pub fn to_cell_string(self) -> String {
+        match self {
+            Self::Unit => String::from("BFS\n* unit weights"),
+            Self::ZeroOne => String::from("BFS\n* 0-1 weights"),
+        }
+    }
+}
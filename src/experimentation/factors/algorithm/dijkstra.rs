@@ -51,7 +51,8 @@ impl PlainDijkstra {
         let definitions = &[
             "impl using a basic priority queue",
             "impl using a priority queue with decrease key",
-            "petgraph::algo::dijkstra",
+            "petgraph::algo::dijkstra\n\
+            only available for petgraph graph representations",
         ];
         cli::print_subheader(1, "Dijsktra Implementations");
         cli::print_table_get_choices("Dijsktra", &available_levels, definitions, 0)
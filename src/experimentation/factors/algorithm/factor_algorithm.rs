@@ -1,4 +1,10 @@
-use super::{dary::Dary, dijkstra::Dijkstra, priority_queue::PriorityQueue};
+use super::{
+    all_pairs::AllPairs, alt::Alt, arc_flags::ArcFlags, astar_bound::AStarBound,
+    bellman_ford::BellmanFord, bidirectional_astar::BidirectionalAStar,
+    bidirectional_dijkstra::BidirectionalDijkstra, breadth_first_search::BreadthFirstSearch,
+    dary::Dary, dijkstra::Dijkstra, hub_labels::HubLabels, priority_queue::PriorityQueue,
+    resource_constrained::ResourceConstrained, time_dependent_dijkstra::TimeDependentDijkstra,
+};
 use crate::{experimentation::factors::factor::Factor, utils::cli};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -8,10 +14,64 @@ use strum_macros::EnumIter;
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum FactorAlgorithm {
     Dijkstra(Dijkstra),
+    BidirectionalDijkstra(BidirectionalDijkstra),
+    AStar(AStarBound),
+    Alt(Alt),
+    ContractionHierarchies,
+    DeltaStepping,
+    BellmanFord(BellmanFord),
+    KShortestPaths,
+    AllPairs(AllPairs),
+    BreadthFirstSearch(BreadthFirstSearch),
+    HubLabels(HubLabels),
+    ArcFlags(ArcFlags),
+    TimeDependentDijkstra(TimeDependentDijkstra),
+    ResourceConstrained(ResourceConstrained),
+    BidirectionalAStar(BidirectionalAStar),
+    PetgraphAStar(AStarBound),
+}
+impl FactorAlgorithm {
+    pub fn allows_negative_weights(&self) -> bool {
+        matches!(self, Self::BellmanFord(_) | Self::AllPairs(_))
+    }
+    /// Whether the algorithm runs with weight types other than the crate wide `Weight`;
+    /// the monotone integer queues and all other algorithms are keyed by `Weight`.
+    pub fn allows_weight_types(&self) -> bool {
+        matches!(
+            self,
+            Self::Dijkstra(Dijkstra::PriorityQueue(
+                PriorityQueue::StdBinaryHeap | PriorityQueue::OrxDaryHeap(_)
+            )) | Self::Dijkstra(Dijkstra::PriorityQueueDecKey(_))
+        )
+    }
+    pub fn reconstructs_paths(&self) -> bool {
+        !matches!(
+            self,
+            Self::BidirectionalDijkstra(_)
+                | Self::ContractionHierarchies
+                | Self::DeltaStepping
+                | Self::AllPairs(_)
+                | Self::HubLabels(_)
+                | Self::TimeDependentDijkstra(_)
+                | Self::ResourceConstrained(_)
+                | Self::BidirectionalAStar(_)
+        )
+    }
+    pub fn is_time_dependent(&self) -> bool {
+        matches!(self, Self::TimeDependentDijkstra(_))
+    }
+    pub fn uses_petgraph(&self) -> bool {
+        matches!(
+            self,
+            Self::Dijkstra(Dijkstra::Petgraph)
+                | Self::BellmanFord(BellmanFord::Petgraph)
+                | Self::PetgraphAStar(_)
+        )
+    }
 }
 impl Factor for FactorAlgorithm {
     fn query_header_index() -> usize {
-        4
+        6
     }
     fn name_factor() -> &'static str {
         "Algorithm"
@@ -28,12 +88,87 @@ impl Factor for FactorAlgorithm {
                     .into_iter()
                     .map(Self::Dijkstra)
                     .collect_vec(),
+                PlainAlgorithm::BidirectionalDijkstra => BidirectionalDijkstra::level_from_cli()
+                    .into_iter()
+                    .map(Self::BidirectionalDijkstra)
+                    .collect_vec(),
+                PlainAlgorithm::AStar => AStarBound::level_from_cli()
+                    .into_iter()
+                    .map(Self::AStar)
+                    .collect_vec(),
+                PlainAlgorithm::Alt => Alt::level_from_cli()
+                    .into_iter()
+                    .map(Self::Alt)
+                    .collect_vec(),
+                PlainAlgorithm::ContractionHierarchies => vec![Self::ContractionHierarchies],
+                PlainAlgorithm::DeltaStepping => vec![Self::DeltaStepping],
+                PlainAlgorithm::BellmanFord => BellmanFord::level_from_cli()
+                    .into_iter()
+                    .map(Self::BellmanFord)
+                    .collect_vec(),
+                PlainAlgorithm::KShortestPaths => vec![Self::KShortestPaths],
+                PlainAlgorithm::AllPairs => AllPairs::level_from_cli()
+                    .into_iter()
+                    .map(Self::AllPairs)
+                    .collect_vec(),
+                PlainAlgorithm::BreadthFirstSearch => BreadthFirstSearch::level_from_cli()
+                    .into_iter()
+                    .map(Self::BreadthFirstSearch)
+                    .collect_vec(),
+                PlainAlgorithm::HubLabels => HubLabels::level_from_cli()
+                    .into_iter()
+                    .map(Self::HubLabels)
+                    .collect_vec(),
+                PlainAlgorithm::ArcFlags => ArcFlags::level_from_cli()
+                    .into_iter()
+                    .map(Self::ArcFlags)
+                    .collect_vec(),
+                PlainAlgorithm::TimeDependentDijkstra => TimeDependentDijkstra::level_from_cli()
+                    .into_iter()
+                    .map(Self::TimeDependentDijkstra)
+                    .collect_vec(),
+                PlainAlgorithm::ResourceConstrained => ResourceConstrained::level_from_cli()
+                    .into_iter()
+                    .map(Self::ResourceConstrained)
+                    .collect_vec(),
+                PlainAlgorithm::BidirectionalAStar => BidirectionalAStar::level_from_cli()
+                    .into_iter()
+                    .map(Self::BidirectionalAStar)
+                    .collect_vec(),
+                PlainAlgorithm::PetgraphAStar => AStarBound::level_from_cli()
+                    .into_iter()
+                    .map(Self::PetgraphAStar)
+                    .collect_vec(),
             })
             .collect()
     }
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::AllPairs(a) => a.validate(),
+            Self::TimeDependentDijkstra(t) => t.validate(),
+            _ => Ok(()),
+        }
+    }
     fn to_cell_string(&self) -> String {
         match self {
             Self::Dijkstra(d) => d.to_cell_string(),
+            Self::BidirectionalDijkstra(d) => d.to_cell_string(),
+            Self::AStar(b) => b.to_cell_string(),
+            Self::Alt(a) => a.to_cell_string(),
+            Self::ContractionHierarchies => String::from("Contraction Hierarchies"),
+            Self::DeltaStepping => String::from("Delta-Stepping"),
+            Self::BellmanFord(b) => b.to_cell_string(),
+            Self::KShortestPaths => String::from("Yen's k-Shortest Paths"),
+            Self::AllPairs(a) => a.to_cell_string(),
+            Self::BreadthFirstSearch(b) => b.to_cell_string(),
+            Self::HubLabels(h) => h.to_cell_string(),
+            Self::ArcFlags(a) => a.to_cell_string(),
+            Self::TimeDependentDijkstra(t) => t.to_cell_string(),
+            Self::ResourceConstrained(r) => r.to_cell_string(),
+            Self::BidirectionalAStar(b) => b.to_cell_string(),
+            Self::PetgraphAStar(b) => {
+                format!("{}\n* impl: petgraph::algo::astar", b.to_cell_string())
+            }
         }
     }
 }
@@ -49,11 +184,59 @@ impl Default for FactorAlgorithm {
 #[derive(Clone, Debug, EnumIter)]
 enum PlainAlgorithm {
     Dijkstra,
+    BidirectionalDijkstra,
+    AStar,
+    Alt,
+    ContractionHierarchies,
+    DeltaStepping,
+    BellmanFord,
+    KShortestPaths,
+    AllPairs,
+    BreadthFirstSearch,
+    HubLabels,
+    ArcFlags,
+    TimeDependentDijkstra,
+    ResourceConstrained,
+    BidirectionalAStar,
+    PetgraphAStar,
 }
 impl PlainAlgorithm {
     fn level_from_cli() -> Vec<Self> {
         let available_levels: Vec<_> = PlainAlgorithm::iter().collect();
-        let definitions = &["Dijsktra's shortest path algorithm"];
+        let definitions = &[
+            "Dijsktra's shortest path algorithm",
+            "Bidirectional Dijsktra's algorithm\n\
+            forward search from the source & backward search from the sink",
+            "A* search with a lower bound computed from node coordinates\n\
+            requires coordinates; hence, only available for Dimacs9th graphs",
+            "ALT: A* search with landmarks & triangle inequality lower bounds\n\
+            landmark distances are computed in a preprocessing phase",
+            "Contraction Hierarchies: bidirectional upward search on the hierarchy\n\
+            nodes are contracted by edge difference & shortcuts are added in a preprocessing phase\n\
+            one-to-all queries by PHAST & many-to-many queries by buckets at the nodes",
+            "Delta-stepping: parallel label-correcting search on buckets of width delta\n\
+            delta of the delta factor; relaxations are shared among the threads of the number of threads factor",
+            "Bellman-Ford label-correcting algorithms\n\
+            allow negative weights & detect negative cycles",
+            "Yen's k-shortest loopless paths\n\
+            k paths of the number of paths factor; the first of which is the shortest path",
+            "All-pairs shortest paths: Floyd-Warshall & Johnson's algorithm\n\
+            the distance matrix is computed in a preprocessing phase & looked up by the queries",
+            "Breadth-first search & 0-1 breadth-first search\n\
+            only available for graphs with unit weights or weights in {0, 1}",
+            "Hub labels: 2-hop labels by pruned landmark labeling\n\
+            labels are constructed in a preprocessing phase & queries merge two labels",
+            "Arc flags: Dijkstra's algorithm on the edges flagged for the cell of the sink\n\
+            nodes are partitioned into cells & flags are computed in a preprocessing phase",
+            "Time-dependent Dijkstra's algorithm on piecewise-linear FIFO travel time functions\n\
+            earliest arrival one-to-one queries for a given departure time",
+            "Resource-constrained shortest paths by label-setting with dominance pruning\n\
+            one-to-one queries within a budget on the second resource of the edges",
+            "Bidirectional A* search with average potentials\n\
+            coordinate or landmark lower bounds to the sink & from the source",
+            "petgraph::algo::astar with a lower bound computed from node coordinates\n\
+            only available for Dimacs9th graphs with petgraph graph representations",
+        ];
         cli::print_table_get_choices(
             FactorAlgorithm::name_factor(),
             &available_levels,
@@ -0,0 +1,41 @@
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct HubLabels {
+    pub ordering: HubOrdering,
+}
+impl HubLabels {
+    pub fn level_from_cli() -> Vec<Self> {
+        cli::print_subheader(1, "Hub Labels");
+        cli::print_definition_under_header(
+            "Labels are constructed once per graph by pruned landmark labeling in a preprocessing\n\
+            phase, the time, label sizes & memory of which are reported separately from the queries.",
+        );
+        HubOrdering::level_from_cli()
+            .into_iter()
+            .map(|ordering| Self { ordering })
+            .collect()
+    }
+    pub fn to_cell_string(self) -> String {
+        format!("Hub Labels\n* ordering : {:?}", self.ordering)
+    }
+}
+
+#[derive(Clone, Copy, Debug, EnumIter, Serialize, Deserialize)]
+pub enum HubOrdering {
+    Degree,
+    ContractionOrder,
+}
+impl HubOrdering {
+    fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = HubOrdering::iter().collect();
+        let definitions = &[
+            "nodes with larger in & out degrees become hubs first",
+            "nodes contracted later in a contraction hierarchy become hubs first",
+        ];
+        cli::print_table_get_choices("hub ordering", &available_levels, definitions, 1)
+    }
+}
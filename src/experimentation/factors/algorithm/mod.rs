@@ -1,5 +1,16 @@
+pub mod all_pairs;
+pub mod alt;
+pub mod arc_flags;
+pub mod astar_bound;
+pub mod bellman_ford;
+pub mod bidirectional_astar;
+pub mod bidirectional_dijkstra;
+pub mod breadth_first_search;
 pub mod dary;
 pub mod dijkstra;
 pub mod factor_algorithm;
+pub mod hub_labels;
 pub mod priority_queue;
 pub mod priority_queue_deckey;
+pub mod resource_constrained;
+pub mod time_dependent_dijkstra;
@@ -9,6 +9,8 @@ use strum_macros::EnumIter;
 pub enum PriorityQueue {
     OrxDaryHeap(Dary),
     StdBinaryHeap,
+    BucketQueue,
+    RadixHeap,
 }
 impl PriorityQueue {
     pub fn level_from_cli() -> Vec<Self> {
@@ -20,6 +22,8 @@ impl PriorityQueue {
                     .map(Self::OrxDaryHeap)
                     .collect_vec(),
                 PlainPriorityQueue::StdBinaryHeap => vec![Self::StdBinaryHeap],
+                PlainPriorityQueue::BucketQueue => vec![Self::BucketQueue],
+                PlainPriorityQueue::RadixHeap => vec![Self::RadixHeap],
             })
             .collect()
     }
@@ -36,6 +40,8 @@ impl PriorityQueue {
             Self::StdBinaryHeap => {
                 format!("{}\n* std::collections::BinaryHeap", algorithm_name)
             }
+            Self::BucketQueue => format!("{}\n* Dial's bucket queue", algorithm_name),
+            Self::RadixHeap => format!("{}\n* radix heap", algorithm_name),
         }
     }
 }
@@ -45,13 +51,19 @@ impl PriorityQueue {
 enum PlainPriorityQueue {
     StdBinaryHeap,
     OrxDaryHeap,
+    BucketQueue,
+    RadixHeap,
 }
 impl PlainPriorityQueue {
     fn level_from_cli() -> Vec<Self> {
         let available_levels: Vec<_> = PlainPriorityQueue::iter().collect();
         let definitions = &[
-            "Basic priority queue with orx_priority_queue::DaryHeap",
             "Basic priority queue with std::collections::BinaryHeap",
+            "Basic priority queue with orx_priority_queue::DaryHeap",
+            "Dial's bucket queue with one bucket per integer key\n\
+            monotone; circular array of maximum edge weight + 1 buckets",
+            "Radix heap with a bucket per bit of the integer key\n\
+            monotone; entries are redistributed to lower buckets as the minimum key increases",
         ];
 
         cli::print_subheader(2, "Basic Priority Queues");
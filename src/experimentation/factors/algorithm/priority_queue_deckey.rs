@@ -9,6 +9,8 @@ pub enum PriorityQueueDecKey {
     OrxDaryHeapOfIndices(Dary),
     OrxDaryHeapWithMap(Dary),
     PriorityQueuePriorityQueue,
+    FibonacciHeap,
+    PairingHeap,
 }
 impl PriorityQueueDecKey {
     pub fn level_from_cli() -> Vec<Self> {
@@ -17,16 +19,19 @@ impl PriorityQueueDecKey {
 
         plain
             .into_iter()
-            .flat_map(|queue| {
-                dary.iter().map(move |&dary| match queue {
-                    PlainPriorityQueueDecKey::OrxDaryHeapOfIndices => {
-                        Self::OrxDaryHeapOfIndices(dary)
-                    }
-                    PlainPriorityQueueDecKey::OrxDaryHeapWithMap => Self::OrxDaryHeapWithMap(dary),
-                    PlainPriorityQueueDecKey::PriorityQueuePriorityQueue => {
-                        Self::PriorityQueuePriorityQueue
-                    }
-                })
+            .flat_map(|queue| match queue {
+                PlainPriorityQueueDecKey::OrxDaryHeapOfIndices => dary
+                    .iter()
+                    .map(|&d| Self::OrxDaryHeapOfIndices(d))
+                    .collect(),
+                PlainPriorityQueueDecKey::OrxDaryHeapWithMap => {
+                    dary.iter().map(|&d| Self::OrxDaryHeapWithMap(d)).collect()
+                }
+                PlainPriorityQueueDecKey::PriorityQueuePriorityQueue => {
+                    vec![Self::PriorityQueuePriorityQueue]
+                }
+                PlainPriorityQueueDecKey::FibonacciHeap => vec![Self::FibonacciHeap],
+                PlainPriorityQueueDecKey::PairingHeap => vec![Self::PairingHeap],
             })
             .collect()
     }
@@ -51,6 +56,8 @@ impl PriorityQueueDecKey {
             Self::PriorityQueuePriorityQueue => {
                 format!("{}\n* priority_queue::PriorityQueue", algorithm_name)
             }
+            Self::FibonacciHeap => format!("{}\n* Fibonacci heap", algorithm_name),
+            Self::PairingHeap => format!("{}\n* pairing heap", algorithm_name),
         }
     }
 }
@@ -61,6 +68,8 @@ enum PlainPriorityQueueDecKey {
     OrxDaryHeapOfIndices,
     OrxDaryHeapWithMap,
     PriorityQueuePriorityQueue,
+    FibonacciHeap,
+    PairingHeap,
 }
 impl PlainPriorityQueueDecKey {
     fn level_from_cli() -> Vec<Self> {
@@ -72,6 +81,10 @@ impl PlainPriorityQueueDecKey {
             * heap paired up with a hash map.",
             "Priority queue with priority_queue::PriorityQueue\n\
             * heap paired up with a index map.",
+            "Fibonacci heap with amortized O(1) decrease-key\n\
+            * forest of heap-ordered trees in arrays indexed by nodes.",
+            "Pairing heap with decrease-key by cut & meld\n\
+            * multiway tree in arrays indexed by nodes.",
         ];
 
         cli::print_subheader(2, "DecreaseKey Priority Queues");
@@ -0,0 +1,27 @@
+use crate::{utils::cli, Weight};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct ResourceConstrained {
+    pub budget: Weight,
+}
+impl ResourceConstrained {
+    pub fn level_from_cli() -> Vec<Self> {
+        cli::print_subheader(1, "Resource-Constrained Shortest Paths");
+        cli::print_definition_under_header(
+            "Shortest paths whose total resource, such as toll or fuel, does not exceed the budget;\n\
+            requires a random graph with edge resources.",
+        );
+        let budget =
+            cli::print_scalar_query_get_answers("resource budget", DEFAULT_BUDGET, |b| b >= &1);
+        budget.into_iter().map(|budget| Self { budget }).collect()
+    }
+    pub fn to_cell_string(self) -> String {
+        format!(
+            "Resource-Constrained Label-Setting\n* budget : {}",
+            self.budget
+        )
+    }
+}
+
+const DEFAULT_BUDGET: Weight = 2000;
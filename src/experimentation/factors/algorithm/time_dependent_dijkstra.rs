@@ -0,0 +1,44 @@
+use crate::{graph::travel_time::PERIOD, utils::cli, Weight};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct TimeDependentDijkstra {
+    pub departure_time: Weight,
+}
+impl TimeDependentDijkstra {
+    pub fn level_from_cli() -> Vec<Self> {
+        cli::print_subheader(1, "Time-Dependent Dijkstra");
+        cli::print_definition_under_header(
+            "Earliest arrival queries departing from the source at the given time of the day in seconds;\n\
+            the shortest distance is the travel time. Static graphs have constant travel times.",
+        );
+        let departure_time = cli::print_scalar_query_get_answers(
+            "departure time in seconds within [0, 86400)",
+            DEFAULT_DEPARTURE_TIME,
+            |t| (0..PERIOD).contains(t),
+        );
+        departure_time
+            .into_iter()
+            .map(|departure_time| Self { departure_time })
+            .collect()
+    }
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0..PERIOD).contains(&self.departure_time) {
+            return Err(format!(
+                "time-dependent dijkstra requires a departure time within [0, {})",
+                PERIOD
+            ));
+        }
+        Ok(())
+    }
+    pub fn to_cell_string(self) -> String {
+        format!(
+            "Time-Dependent Dijkstra\n* departure : {:02}:{:02}:{:02}",
+            self.departure_time / 3600,
+            self.departure_time % 3600 / 60,
+            self.departure_time % 60
+        )
+    }
+}
+
+const DEFAULT_DEPARTURE_TIME: Weight = 28_800;
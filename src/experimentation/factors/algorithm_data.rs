@@ -4,15 +4,16 @@ use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-#[derive(Clone, Copy, Debug, EnumIter, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, EnumIter, Serialize, Deserialize)]
 pub enum FactorAlgorithmData {
+    #[default]
     Cached,
     Pure,
 }
 
 impl Factor for FactorAlgorithmData {
     fn query_header_index() -> usize {
-        5
+        11
     }
     fn name_factor() -> &'static str {
         "Caching"
@@ -24,16 +25,12 @@ impl Factor for FactorAlgorithmData {
     fn query_levels_from_cli() -> Vec<Self> {
         let available_levels: Vec<_> = FactorAlgorithmData::iter().collect();
         let definitions = &[
-            "Reuses once allocated internal data for all (s,t) pairs\n\
+            "Reuses once allocated internal data for all queries\n\
             Leads to an impure run method requiring `&mut self`.",
-            "Recreates internal data for each (s,t) pair\n\
-            Leads to a pure run method only requiring `&self`.",
+            "Recreates internal data for each query\n\
+            Leads to a pure run method only requiring `&self`.\n\
+            Inputs prepared once per graph, such as A* bounds, ALT landmarks or contraction hierarchies, are shared.",
         ];
         cli::print_table_get_choices(Self::name_factor(), &available_levels, definitions, 0)
     }
 }
-impl Default for FactorAlgorithmData {
-    fn default() -> Self {
-        Self::Cached
-    }
-}
@@ -0,0 +1,54 @@
+use super::factor::Factor;
+use crate::{utils::cli, Weight};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Bucket width delta of the delta-stepping algorithms separating light edges from heavy edges;
+/// all other algorithms run with the default width which they ignore.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct FactorDelta(pub Weight);
+
+impl FactorDelta {
+    pub fn width(&self) -> Weight {
+        self.0
+    }
+    pub fn is_default(&self) -> bool {
+        self.0 == DEFAULT_DELTA
+    }
+}
+
+impl Factor for FactorDelta {
+    fn query_header_index() -> usize {
+        9
+    }
+    fn name_factor() -> &'static str {
+        "Delta"
+    }
+    fn definition_factor() -> &'static str {
+        "Bucket width delta of delta-stepping; light edges, weight <= delta, are relaxed repeatedly while a bucket is emptied & heavy edges once per bucket."
+    }
+    fn query_levels_from_cli() -> Vec<Self> {
+        cli::print_scalar_query_get_answers("bucket width delta", DEFAULT_DELTA, |d| d >= &1)
+            .into_iter()
+            .map(Self)
+            .collect()
+    }
+    fn validate(&self) -> Result<(), String> {
+        match self.0 < 1 {
+            true => Err(String::from("the bucket width delta must be positive")),
+            false => Ok(()),
+        }
+    }
+}
+impl Default for FactorDelta {
+    fn default() -> Self {
+        Self(DEFAULT_DELTA)
+    }
+}
+impl Debug for FactorDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+const DEFAULT_DELTA: Weight = 1000;
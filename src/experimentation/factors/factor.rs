@@ -16,6 +16,11 @@ pub trait Factor: Clone + Debug + Default {
         self.name_level()
     }
 
+    /// Levels read from an experiment file skip the checks of the cli queries; hence, they are validated when loaded.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+
     // cli
     fn query_levels_from_cli() -> Vec<Self>;
 
@@ -1,5 +1,8 @@
 use crate::{
-    graph::sp_graph_builder::SpGraphBuilder,
+    graph::{
+        coordinates::{Coordinates, Point},
+        sp_graph_builder::SpGraphBuilder,
+    },
     utils::{self, cli},
     Weight,
 };
@@ -51,9 +54,9 @@ impl GraphDimacs9th {
         utils::fs::create_dir_if_absent(dir.as_path());
         dir
     }
-    fn path_zip_file(&self) -> PathBuf {
+    fn path_zip_file(&self, extension: &str) -> PathBuf {
         let folder = Self::data_folder();
-        let zip_filename = format!("{}.gr.zip", self.filename());
+        let zip_filename = format!("{}.{}.zip", self.filename(), extension);
         folder.join(zip_filename)
     }
     fn filename(&self) -> &'static str {
@@ -67,11 +70,12 @@ impl GraphDimacs9th {
             Self::USARoaddUSA => "USA-road-d.USA",
         }
     }
-    fn path_extracted_file(&self) -> PathBuf {
-        Self::data_folder_temp().join(format!("{}.gr", self.filename()))
+    fn path_extracted_file(&self, extension: &str) -> PathBuf {
+        Self::data_folder_temp().join(format!("{}.{}", self.filename(), extension))
     }
     fn extract_and_read_lines(
         &self,
+        extension: &str,
     ) -> std::io::Result<std::io::Lines<std::io::BufReader<std::fs::File>>> {
         #[allow(clippy::unused_io_amount)]
         fn get_file_as_byte_vec(filename: &str) -> Vec<u8> {
@@ -85,15 +89,15 @@ impl GraphDimacs9th {
             buffer
         }
 
-        if !self.path_extracted_file().exists() {
+        if !self.path_extracted_file(extension).exists() {
             let temp_dir = Self::data_folder_temp();
-            let path_zip = self.path_zip_file();
+            let path_zip = self.path_zip_file(extension);
             let bytes = get_file_as_byte_vec(path_zip.to_str().unwrap());
             zip_extract::extract(std::io::Cursor::new(bytes), temp_dir.as_path(), true)
                 .expect("failed to extract the compressed data file");
         }
 
-        let file = std::fs::File::open(self.path_extracted_file())?;
+        let file = std::fs::File::open(self.path_extracted_file(extension))?;
         Ok(std::io::BufReader::new(file).lines())
     }
 
@@ -101,7 +105,7 @@ impl GraphDimacs9th {
     pub fn create_graph_builder<B: SpGraphBuilder>(&self) -> B {
         let mut maybe_g: Option<B> = None;
 
-        let lines = self.extract_and_read_lines().expect("failed to lines");
+        let lines = self.extract_and_read_lines("gr").expect("failed to lines");
         for line in lines.map_while(Result::ok) {
             let parts: Vec<_> = line.split(' ').collect();
             match parts.first() {
@@ -127,4 +131,32 @@ impl GraphDimacs9th {
 
         maybe_g.expect("missing graph meta data")
     }
+
+    // coordinates
+    pub fn create_coordinates(&self) -> Coordinates {
+        let mut maybe_points: Option<Vec<Point>> = None;
+
+        let lines = self.extract_and_read_lines("co").expect("failed to lines");
+        for line in lines.map_while(Result::ok) {
+            let parts: Vec<_> = line.split(' ').collect();
+            match parts.first() {
+                Some(&"p") => {
+                    let num_nodes: usize = parts[4].parse().expect("invalid num-nodes");
+                    maybe_points = Some(vec![Point::default(); num_nodes]);
+                }
+                Some(&"v") => {
+                    let id: usize = parts[1].parse().expect("invalid node id");
+                    let x: i64 = parts[2].parse().expect("invalid x coordinate");
+                    let y: i64 = parts[3].parse().expect("invalid y coordinate");
+                    let points = maybe_points
+                        .as_mut()
+                        .expect("missing coordinates meta data");
+                    points[id - 1] = Point::new(x as f64 * 1e-6, y as f64 * 1e-6);
+                }
+                _ => {}
+            }
+        }
+
+        Coordinates::new(maybe_points.expect("missing coordinates meta data"))
+    }
 }
@@ -1,6 +1,16 @@
-use super::{dimacs9th::GraphDimacs9th, random::GraphRandom};
+use super::{
+    dimacs9th::GraphDimacs9th,
+    random::{GraphRandom, RandomResources, RandomWeights},
+    rush_hour::GraphRushHour,
+};
 use crate::{
-    experimentation::factors::factor::Factor, graph::sp_graph_builder::SpGraphBuilder, utils::cli,
+    experimentation::factors::factor::Factor,
+    graph::{
+        adjlist_jagged_vec::AdjListJaggedVec, coordinates::Coordinates,
+        resource_graph_builder::ResourceGraphBuilder, sp_graph_builder::SpGraphBuilder,
+        td_graph_builder::TdGraphBuilder, travel_time::TravelTimeFunction,
+    },
+    utils::cli,
 };
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -11,6 +21,7 @@ use strum_macros::EnumIter;
 pub enum FactorGraphData {
     Random(GraphRandom),
     Dimacs9th(GraphDimacs9th),
+    RushHour(GraphRushHour),
 }
 
 impl FactorGraphData {
@@ -19,6 +30,67 @@ impl FactorGraphData {
         match self {
             Self::Random(x) => x.create_graph_builder::<B>(),
             Self::Dimacs9th(x) => x.create_graph_builder::<B>(),
+            Self::RushHour(x) => x.create_graph_builder::<B>(),
+        }
+    }
+    /// Time-dependent graph; travel times of the static graphs are constant.
+    pub fn create_td_graph_builder<B: TdGraphBuilder>(&self) -> B {
+        match self {
+            Self::RushHour(x) => x.create_td_graph_builder::<B>(),
+            _ => {
+                let graph = self.create_graph_builder::<AdjListJaggedVec>().build();
+                B::from_static_graph(&graph, TravelTimeFunction::constant)
+            }
+        }
+    }
+    /// Graph with the second resource of the edges; only random graphs with resources.
+    pub fn create_resource_graph_builder<B: ResourceGraphBuilder>(&self) -> B {
+        match self {
+            Self::Random(x) => x.create_resource_graph_builder::<B>(),
+            _ => panic!("must've been handled with run_special"),
+        }
+    }
+    pub fn has_resources(&self) -> bool {
+        match self {
+            Self::Random(x) => x.resources != RandomResources::None,
+            Self::Dimacs9th(_) | Self::RushHour(_) => false,
+        }
+    }
+    pub fn is_time_dependent(&self) -> bool {
+        matches!(self, Self::RushHour(_))
+    }
+    pub fn has_coordinates(&self) -> bool {
+        match self {
+            Self::Random(_) => false,
+            Self::Dimacs9th(_) | Self::RushHour(_) => true,
+        }
+    }
+    pub fn has_negative_weights(&self) -> bool {
+        match self {
+            Self::Random(x) => x.negative_weights,
+            Self::Dimacs9th(_) | Self::RushHour(_) => false,
+        }
+    }
+    pub fn has_unit_weights(&self) -> bool {
+        match self {
+            Self::Random(x) => !x.negative_weights && x.weights == RandomWeights::Unit,
+            Self::Dimacs9th(_) | Self::RushHour(_) => false,
+        }
+    }
+    pub fn has_zero_one_weights(&self) -> bool {
+        match self {
+            Self::Random(x) => {
+                !x.negative_weights
+                    && matches!(x.weights, RandomWeights::Unit | RandomWeights::ZeroOne)
+            }
+            Self::Dimacs9th(_) | Self::RushHour(_) => false,
+        }
+    }
+    pub fn create_coordinates(&self) -> Option<Coordinates> {
+        match self {
+            Self::Random(_) => None,
+            Self::Dimacs9th(x) => Some(x.create_coordinates()),
+            Self::RushHour(x) => Some(x.create_coordinates()),
         }
     }
 }
@@ -46,14 +118,27 @@ impl Factor for FactorGraphData {
                     .into_iter()
                     .map(Self::Dimacs9th)
                     .collect_vec(),
+                PlainGraphData::RushHour => GraphRushHour::level_from_cli()
+                    .into_iter()
+                    .map(Self::RushHour)
+                    .collect_vec(),
             })
             .collect()
     }
 
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Random(x) => x.validate(),
+            Self::Dimacs9th(_) => Ok(()),
+            Self::RushHour(x) => x.validate(),
+        }
+    }
+
     fn to_cell_string(&self) -> String {
         match self {
             Self::Random(x) => x.to_cell_string(),
             Self::Dimacs9th(x) => x.to_cell_string(),
+            Self::RushHour(x) => x.to_cell_string(),
         }
     }
 }
@@ -68,6 +153,7 @@ impl Default for FactorGraphData {
 enum PlainGraphData {
     Random,
     Dimacs9th,
+    RushHour,
 }
 impl PlainGraphData {
     fn level_from_cli() -> Vec<Self> {
@@ -80,6 +166,9 @@ impl PlainGraphData {
             Consists of USA road networks that are part of the challenge core instances.\n\
             These networks can be considered sparse having densities within 1e-7 and 1e-5.\n\
             (density = A / N^2; where density is 1 for a fully connected graph)",
+            "Dimacs-9th-Challenge road network with time-dependent travel times\n\
+            piecewise-linear functions of the departure time with morning & evening rush hours\n\
+            only available for the time-dependent algorithms",
         ];
         cli::print_table_get_choices(
             FactorGraphData::name_factor(),
@@ -1,3 +1,4 @@
 pub mod dimacs9th;
 pub mod factor_graph_data;
 pub mod random;
+pub mod rush_hour;
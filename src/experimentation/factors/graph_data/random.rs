@@ -1,13 +1,34 @@
-use crate::{graph::sp_graph_builder::SpGraphBuilder, utils::cli, Weight};
+use crate::{
+    graph::{
+        adjlist_jagged_vec::AdjListJaggedVec,
+        out_edges::{OutEdgeData, OutEdges},
+        resource_graph_builder::ResourceGraphBuilder,
+        sp_graph::SpGraph,
+        sp_graph_builder::SpGraphBuilder,
+    },
+    utils::cli,
+    Weight,
+};
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct GraphRandom {
     pub seed: u64,
     pub num_nodes: usize,
     pub density: f32,
+    /// Weights are shifted by random node potentials, w(i, j) + p(j) - p(i), which keeps all cycles positive;
+    /// requires the `signed-weights` feature.
+    #[serde(default)]
+    pub negative_weights: bool,
+    #[serde(default)]
+    pub weights: RandomWeights,
+    /// Second resource of the edges for resource-constrained queries; the topology & weights are not affected.
+    #[serde(default)]
+    pub resources: RandomResources,
 }
 
 impl GraphRandom {
@@ -23,26 +44,65 @@ impl GraphRandom {
             DEFAULT_SPARSITY,
             |&n| (1e-5..=1.0).contains(&n),
         );
+        #[cfg(feature = "signed-weights")]
+        let negative_weights = cli::print_scalar_query_get_answers(
+            "random graph with negative weights & no negative cycles (true / false)",
+            false,
+            |_| true,
+        );
+        #[cfg(not(feature = "signed-weights"))]
+        let negative_weights = vec![false];
+        let weights = RandomWeights::level_from_cli();
+        let resources = RandomResources::level_from_cli();
 
         let mut combinations = vec![];
         for &seed in &seed {
             for &num_nodes in &num_nodes {
                 for &density in &density {
-                    combinations.push(Self {
-                        seed,
-                        density,
-                        num_nodes,
-                    });
+                    for &negative_weights in &negative_weights {
+                        for &weights in &weights {
+                            for &resources in &resources {
+                                combinations.push(Self {
+                                    seed,
+                                    density,
+                                    num_nodes,
+                                    negative_weights,
+                                    weights,
+                                    resources,
+                                });
+                            }
+                        }
+                    }
                 }
             }
         }
         combinations
     }
+    pub fn validate(&self) -> Result<(), String> {
+        if self.negative_weights && cfg!(not(feature = "signed-weights")) {
+            return Err(String::from("negative weights require the signed-weights feature:\ncargo run --release --features signed-weights"));
+        }
+        Ok(())
+    }
     pub fn to_cell_string(self) -> String {
-        format!(
+        let cell = format!(
             "Random:\n* seed      : {}\n* num_nodes : {}\n* density   : {:.4}",
             self.seed, self.num_nodes, self.density
-        )
+        );
+        let cell = match self.weights {
+            RandomWeights::Uniform => cell,
+            RandomWeights::Unit => format!("{}\n* unit weights", cell),
+            RandomWeights::ZeroOne => format!("{}\n* 0-1 weights", cell),
+        };
+        let cell = match self.resources {
+            RandomResources::None => cell,
+            RandomResources::Uniform => format!("{}\n* uniform resources", cell),
+            RandomResources::AntiCorrelated => format!("{}\n* anti-correlated resources", cell),
+        };
+        match self.negative_weights {
+            true => format!("{}\n* negative weights", cell),
+            false => cell,
+        }
     }
 
     // graph ctor
@@ -67,6 +127,10 @@ impl GraphRandom {
             .collect();
         let num_edges: usize = out_degrees.iter().sum();
 
+        let potentials = self
+            .negative_weights
+            .then(|| node_potentials(seed, num_nodes));
+
         let mut builder = B::new(Some(num_nodes), Some(num_edges));
 
         for (i, out_degree) in (0..num_nodes).zip(&out_degrees) {
@@ -79,11 +143,41 @@ impl GraphRandom {
                 .take(out_degree);
             for head in heads {
                 let weight = rng.gen_range(1..2 * num_nodes) as Weight;
+                let weight = self.weights.map(weight);
+                let weight = match &potentials {
+                    Some(p) => shift_by_potentials(weight, p[i], p[*head]),
+                    None => weight,
+                };
                 builder.add_edge(i, *head, weight);
             }
         }
         builder
     }
+    pub fn create_resource_graph_builder<B: ResourceGraphBuilder>(&self) -> B {
+        let graph = self.create_graph_builder::<AdjListJaggedVec>().build();
+        let num_nodes = graph.num_nodes();
+
+        // separate stream so that the topology & weights are the same as the graph without resources
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed.wrapping_add(2));
+        let mut builder = B::new(Some(num_nodes), Some(graph.num_edges()));
+        for (node, out_edges) in graph.iter().enumerate() {
+            builder.add_node(node, Some(out_edges.len()));
+        }
+        for tail in 0..num_nodes {
+            let mut out_edges = graph.out_edges(tail);
+            while let Some(edge) = out_edges.next_edge() {
+                let resource = match self.resources {
+                    RandomResources::None => 0,
+                    RandomResources::Uniform => rng.gen_range(1..2 * num_nodes) as Weight,
+                    RandomResources::AntiCorrelated => {
+                        (2 * num_nodes as Weight - edge.weight()).max(1)
+                    }
+                };
+                builder.add_edge(tail, edge.head(), edge.weight(), resource);
+            }
+        }
+        builder
+    }
 }
 impl Default for GraphRandom {
     fn default() -> Self {
@@ -91,10 +185,78 @@ impl Default for GraphRandom {
             seed: DEFAULT_SEED,
             num_nodes: DEFAULT_NUM_NODES,
             density: DEFAULT_SPARSITY,
+            negative_weights: false,
+            weights: RandomWeights::default(),
+            resources: RandomResources::default(),
+        }
+    }
+}
+
+/// Distribution of the weights of the random graph before the negative weights shift, if any.
+#[derive(Clone, Copy, Debug, Default, EnumIter, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RandomWeights {
+    #[default]
+    Uniform,
+    Unit,
+    ZeroOne,
+}
+impl RandomWeights {
+    fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = RandomWeights::iter().collect();
+        let definitions = &[
+            "uniformly distributed within [1, 2 * num_nodes)",
+            "all weights are 1",
+            "weights are 0 or 1 with equal probability",
+        ];
+        cli::print_table_get_choices("random graph weights", &available_levels, definitions, 0)
+    }
+    // the uniform weight is always drawn so that the topology does not depend on the distribution
+    fn map(self, uniform_weight: Weight) -> Weight {
+        match self {
+            Self::Uniform => uniform_weight,
+            Self::Unit => 1,
+            Self::ZeroOne => uniform_weight % 2,
         }
     }
 }
 
+/// Distribution of the second resource of the edges.
+#[derive(Clone, Copy, Debug, Default, EnumIter, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RandomResources {
+    #[default]
+    None,
+    Uniform,
+    AntiCorrelated,
+}
+impl RandomResources {
+    fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = RandomResources::iter().collect();
+        let definitions = &[
+            "edges carry no resource",
+            "uniformly distributed within [1, 2 * num_nodes) independent of the weights",
+            "2 * num_nodes - weight; short edges consume more of the resource",
+        ];
+        cli::print_table_get_choices("random graph resources", &available_levels, definitions, 0)
+    }
+}
+
+// negative weights
+fn node_potentials(seed: u64, num_nodes: usize) -> Vec<Weight> {
+    // separate stream so that the topology & base weights are the same as the non-negative graph
+    let mut rng = ChaCha8Rng::seed_from_u64(seed.wrapping_add(1));
+    (0..num_nodes)
+        .map(|_| rng.gen_range(0..num_nodes) as Weight)
+        .collect()
+}
+#[cfg(feature = "signed-weights")]
+fn shift_by_potentials(weight: Weight, tail_potential: Weight, head_potential: Weight) -> Weight {
+    weight + head_potential - tail_potential
+}
+#[cfg(not(feature = "signed-weights"))]
+fn shift_by_potentials(_: Weight, _: Weight, _: Weight) -> Weight {
+    panic!("negative weights without the signed-weights feature must've been rejected when the experiment is loaded")
+}
+
 // defaults
 const DEFAULT_SEED: u64 = 9864;
 const DEFAULT_SPARSITY: f32 = 0.25;
@@ -0,0 +1,130 @@
+use super::dimacs9th::GraphDimacs9th;
+use crate::{
+    graph::{
+        adjlist_jagged_vec::AdjListJaggedVec, coordinates::Coordinates,
+        sp_graph_builder::SpGraphBuilder, td_graph_builder::TdGraphBuilder,
+        travel_time::TravelTimeFunction,
+    },
+    utils::cli,
+    Weight,
+};
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+/// Dimacs9th road network with time-dependent travel times, where the weights are the free-flow travel times;
+/// every edge is slowed down in the morning & evening rush hours by a random intensity within `[0, peak_slowdown]`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct GraphRushHour {
+    pub dimacs: GraphDimacs9th,
+    pub seed: u64,
+    pub peak_slowdown: f32,
+}
+
+impl GraphRushHour {
+    pub fn level_from_cli() -> Vec<Self> {
+        let dimacs = GraphDimacs9th::level_from_cli();
+        cli::print_subheader(1, "Rush-Hour Travel Times");
+        cli::print_definition_under_header(
+            "Free-flow travel times are increased during 08:00-09:00 & 17:00-18:30 by a random factor of each edge,\n\
+            ramping up in the two hours before & down in the two hours after the peaks.",
+        );
+        let seed = cli::print_scalar_query_get_answers("random seed", DEFAULT_SEED, |_| true);
+        let peak_slowdown = cli::print_scalar_query_get_answers(
+            "maximum relative slowdown at the peaks",
+            DEFAULT_PEAK_SLOWDOWN,
+            is_valid_peak_slowdown,
+        );
+
+        let mut combinations = vec![];
+        for &dimacs in &dimacs {
+            for &seed in &seed {
+                for &peak_slowdown in &peak_slowdown {
+                    combinations.push(Self {
+                        dimacs,
+                        seed,
+                        peak_slowdown,
+                    });
+                }
+            }
+        }
+        combinations
+    }
+    pub fn validate(&self) -> Result<(), String> {
+        match is_valid_peak_slowdown(&self.peak_slowdown) {
+            true => Ok(()),
+            false => Err(String::from(
+                "peak slowdown of the rush hours must be a finite non-negative number",
+            )),
+        }
+    }
+    pub fn to_cell_string(self) -> String {
+        format!(
+            "RushHour:\n* name          : {:?}\n* seed          : {}\n* peak slowdown : {:.2}",
+            self.dimacs, self.seed, self.peak_slowdown
+        )
+    }
+
+    // graph ctor
+    /// Static graph of the free-flow travel times.
+    pub fn create_graph_builder<B: SpGraphBuilder>(&self) -> B {
+        self.dimacs.create_graph_builder::<B>()
+    }
+    pub fn create_td_graph_builder<B: TdGraphBuilder>(&self) -> B {
+        let free_flow = self
+            .dimacs
+            .create_graph_builder::<AdjListJaggedVec>()
+            .build();
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        B::from_static_graph(&free_flow, |weight| {
+            let intensity: f32 = rng.gen_range(0.0..=self.peak_slowdown);
+            rush_hour_profile(weight, intensity)
+        })
+    }
+    pub fn create_coordinates(&self) -> Coordinates {
+        self.dimacs.create_coordinates()
+    }
+}
+impl Default for GraphRushHour {
+    fn default() -> Self {
+        Self {
+            dimacs: GraphDimacs9th::default(),
+            seed: DEFAULT_SEED,
+            peak_slowdown: DEFAULT_PEAK_SLOWDOWN,
+        }
+    }
+}
+
+// the peak increase is capped by the ramp-down duration so that the function decreases with a slope of at least -1 (FIFO)
+fn rush_hour_profile(free_flow: Weight, intensity: f32) -> TravelTimeFunction {
+    let increase = (free_flow as f32 * intensity) as Weight;
+    let increase = increase.min(RAMP_DURATION);
+    match increase {
+        0 => TravelTimeFunction::constant(free_flow),
+        _ => {
+            let peak = free_flow + increase;
+            TravelTimeFunction::new(vec![
+                (0, free_flow),
+                (hours(6.0), free_flow),
+                (hours(8.0), peak),
+                (hours(9.0), peak),
+                (hours(11.0), free_flow),
+                (hours(15.0), free_flow),
+                (hours(17.0), peak),
+                (hours(18.5), peak),
+                (hours(20.5), free_flow),
+            ])
+        }
+    }
+}
+fn hours(hours: f32) -> Weight {
+    (hours * 3600.0) as Weight
+}
+fn is_valid_peak_slowdown(peak_slowdown: &f32) -> bool {
+    peak_slowdown.is_finite() && *peak_slowdown >= 0.0
+}
+
+// defaults
+const DEFAULT_SEED: u64 = 4271;
+const DEFAULT_PEAK_SLOWDOWN: f32 = 0.5;
+const RAMP_DURATION: Weight = 7200;
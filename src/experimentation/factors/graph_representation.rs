@@ -4,12 +4,26 @@ use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-#[derive(Clone, Copy, Debug, EnumIter, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, EnumIter, Serialize, Deserialize)]
 #[allow(clippy::enum_variant_names)]
 pub enum FactorGraphRepresentation {
+    #[default]
     AdjListJaggedVec,
     AdjListFlatVec,
     AdjListPetgraph,
+    AdjListCsr,
+    AdjListPetgraphStable,
+    AdjListPetgraphMap,
+    AdjListPetgraphCsr,
+    AdjListCompressed,
+    AdjListCsr64,
+}
+
+impl FactorGraphRepresentation {
+    /// Whether the edge weights can be stored with weight types other than the crate wide `Weight`.
+    pub fn allows_weight_types(&self) -> bool {
+        matches!(self, Self::AdjListJaggedVec | Self::AdjListFlatVec)
+    }
 }
 
 impl Factor for FactorGraphRepresentation {
@@ -25,15 +39,18 @@ impl Factor for FactorGraphRepresentation {
     fn query_levels_from_cli() -> Vec<Self> {
         let available_levels: Vec<_> = FactorGraphRepresentation::iter().collect();
         let definitions = &[
-            "Adjacency list by jagged Vec<Vec<OutEdge>>",
-            "Adjacency list by flattened Vec<OutEdge>",
+            "Adjacency list by jagged Vec<Vec<OutEdge>>; available with all weight types",
+            "Adjacency list by flattened Vec<OutEdge>; available with all weight types",
             "Adjacency list by petgraph::graph::Graph",
+            "Compressed sparse row with separate u32 heads & 32-bit weights arrays and u32 offsets;\n\
+            graphs with weights beyond 32 bits are rejected",
+            "Adjacency list by petgraph::stable_graph::StableGraph",
+            "petgraph::graphmap::DiGraphMap keyed by node indices; graphs with parallel edges are rejected",
+            "Compressed sparse row by petgraph::csr::Csr; graphs with parallel edges are rejected",
+            "Delta encoded sorted heads & separately packed weights as variable-length integers\n\
+            decoded on the fly while iterating over the out edges",
+            "Compressed sparse row with separate u32 heads & 64-bit weights arrays and u32 offsets",
         ];
         cli::print_table_get_choices(Self::name_factor(), &available_levels, definitions, 0)
     }
 }
-impl Default for FactorGraphRepresentation {
-    fn default() -> Self {
-        Self::AdjListJaggedVec
-    }
-}
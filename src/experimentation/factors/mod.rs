@@ -1,6 +1,16 @@
 pub mod algorithm;
 pub mod algorithm_data;
+pub mod delta;
 pub mod factor;
 pub mod graph_data;
 pub mod graph_representation;
+pub mod node_ordering;
+pub mod num_paths;
+pub mod num_threads;
 pub mod pairs;
+pub mod partition_size;
+pub mod queries;
+pub mod sources;
+pub mod tables;
+pub mod target_sets;
+pub mod weight_type;
@@ -0,0 +1,45 @@
+use super::factor::Factor;
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Default, EnumIter, Serialize, Deserialize)]
+pub enum FactorNodeOrdering {
+    #[default]
+    Identity,
+    Bfs,
+    Dfs,
+    ReverseCuthillMcKee,
+    Hilbert,
+}
+
+impl FactorNodeOrdering {
+    pub fn requires_coordinates(&self) -> bool {
+        matches!(self, Self::Hilbert)
+    }
+}
+
+impl Factor for FactorNodeOrdering {
+    fn query_header_index() -> usize {
+        4
+    }
+    fn name_factor() -> &'static str {
+        "Node Ordering"
+    }
+    fn definition_factor() -> &'static str {
+        "Renumbering of the nodes before the graph is built; queries are mapped through the same permutation."
+    }
+    fn query_levels_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = FactorNodeOrdering::iter().collect();
+        let definitions = &[
+            "Node ids of the graph data",
+            "Breadth-first traversal order ignoring edge directions",
+            "Depth-first traversal preorder ignoring edge directions",
+            "Reverse Cuthill-McKee order",
+            "Order along the Hilbert curve over the node coordinates\n\
+            Requires coordinates which are only available for Dimacs9th graphs.",
+        ];
+        cli::print_table_get_choices(Self::name_factor(), &available_levels, definitions, 0)
+    }
+}
@@ -0,0 +1,56 @@
+use super::factor::Factor;
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Number of paths k found for each query by the k-shortest paths algorithms;
+/// the default single path is the shortest path found by all algorithms.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct FactorNumPaths(pub usize);
+
+impl FactorNumPaths {
+    pub fn k(&self) -> usize {
+        self.0
+    }
+    pub fn is_single_path(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl Factor for FactorNumPaths {
+    fn query_header_index() -> usize {
+        8
+    }
+    fn name_factor() -> &'static str {
+        "Number of Paths"
+    }
+    fn definition_factor() -> &'static str {
+        "Number of paths k found for each query by Yen's k-shortest loopless paths; all other algorithms find the single shortest path."
+    }
+    fn query_levels_from_cli() -> Vec<Self> {
+        cli::print_scalar_query_get_answers("number of paths k", DEFAULT_K, |k| k >= &1)
+            .into_iter()
+            .map(Self)
+            .collect()
+    }
+    fn validate(&self) -> Result<(), String> {
+        match self.0 {
+            0 => Err(String::from(
+                "at least one path must be found for each query",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+impl Default for FactorNumPaths {
+    fn default() -> Self {
+        Self(DEFAULT_K)
+    }
+}
+impl Debug for FactorNumPaths {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+const DEFAULT_K: usize = 1;
@@ -0,0 +1,54 @@
+use super::factor::Factor;
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Number of threads sharing the relaxations of the parallel algorithms such as delta-stepping;
+/// the default single thread is the sequential run of all algorithms.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct FactorNumThreads(pub usize);
+
+impl FactorNumThreads {
+    pub fn num_threads(&self) -> usize {
+        self.0
+    }
+    pub fn is_sequential(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl Factor for FactorNumThreads {
+    fn query_header_index() -> usize {
+        10
+    }
+    fn name_factor() -> &'static str {
+        "Number of Threads"
+    }
+    fn definition_factor() -> &'static str {
+        "Number of threads the relaxations of delta-stepping are split among; all other algorithms run on a single thread."
+    }
+    fn query_levels_from_cli() -> Vec<Self> {
+        cli::print_scalar_query_get_answers("number of threads", DEFAULT_NUM_THREADS, |t| t >= &1)
+            .into_iter()
+            .map(Self)
+            .collect()
+    }
+    fn validate(&self) -> Result<(), String> {
+        match self.0 {
+            0 => Err(String::from("at least one thread is required")),
+            _ => Ok(()),
+        }
+    }
+}
+impl Default for FactorNumThreads {
+    fn default() -> Self {
+        Self(DEFAULT_NUM_THREADS)
+    }
+}
+impl Debug for FactorNumThreads {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+const DEFAULT_NUM_THREADS: usize = 1;
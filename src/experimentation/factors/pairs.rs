@@ -1,13 +1,16 @@
-use super::factor::Factor;
 use crate::utils::cli;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct FactorPairs {
     pub seed: u64,
     pub num_pairs: usize,
+    /// whether the shortest paths are reconstructed in addition to the shortest distances
+    #[serde(default)]
+    pub paths: bool,
 }
 
 impl FactorPairs {
@@ -21,39 +24,34 @@ impl FactorPairs {
             })
             .collect()
     }
-}
-
-impl Factor for FactorPairs {
-    fn query_header_index() -> usize {
-        6
-    }
-    fn name_factor() -> &'static str {
-        "Source-Sink Pairs"
-    }
-    fn definition_factor() -> &'static str {
-        "Source-sink pairs shortest distances of which \
-        will be computed in the experiment."
-    }
 
-    fn query_levels_from_cli() -> Vec<Self> {
+    pub fn level_from_cli() -> Vec<Self> {
+        cli::print_subheader(1, "Source-Sink Pairs");
         let seed = cli::print_scalar_query_get_answers("random seed", DEFAULT_SEED, |_| true);
         let num_pairs =
             cli::print_scalar_query_get_answers("number of (s,t) pairs", DEFAULT_NUM_NODES, |n| {
                 n >= &1
             });
+        let paths =
+            cli::print_scalar_query_get_answers("reconstruct shortest paths", false, |_| true);
         let mut combinations = vec![];
         for &seed in &seed {
             for &num_pairs in &num_pairs {
-                combinations.push(Self { seed, num_pairs })
+                for &paths in &paths {
+                    combinations.push(Self {
+                        seed,
+                        num_pairs,
+                        paths,
+                    })
+                }
             }
         }
         combinations
     }
-
-    fn to_cell_string(&self) -> String {
+    pub fn to_cell_string(self) -> String {
         format!(
-            "* seed      : {}\n* num_pairs : {}",
-            self.seed, self.num_pairs
+            "* seed      : {}\n* num_pairs : {}\n* paths     : {}",
+            self.seed, self.num_pairs, self.paths
         )
     }
 }
@@ -62,6 +60,7 @@ impl Default for FactorPairs {
         Self {
             seed: DEFAULT_SEED,
             num_pairs: DEFAULT_NUM_NODES,
+            paths: false,
         }
     }
 }
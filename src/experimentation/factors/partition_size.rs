@@ -0,0 +1,54 @@
+use super::factor::Factor;
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Number of cells the nodes are partitioned into by the preprocessing of partition based algorithms such as arc flags;
+/// the default single cell is the unpartitioned graph.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct FactorPartitionSize(pub usize);
+
+impl FactorPartitionSize {
+    pub fn num_cells(&self) -> usize {
+        self.0
+    }
+    pub fn is_partitioned(&self) -> bool {
+        self.0 > 1
+    }
+}
+
+impl Factor for FactorPartitionSize {
+    fn query_header_index() -> usize {
+        7
+    }
+    fn name_factor() -> &'static str {
+        "Partition Size"
+    }
+    fn definition_factor() -> &'static str {
+        "Number of cells the nodes are partitioned into by arc flags; all other algorithms run on the unpartitioned graph of a single cell."
+    }
+    fn query_levels_from_cli() -> Vec<Self> {
+        cli::print_scalar_query_get_answers("number of cells", DEFAULT_NUM_CELLS, |k| k >= &1)
+            .into_iter()
+            .map(Self)
+            .collect()
+    }
+    fn validate(&self) -> Result<(), String> {
+        match self.0 {
+            0 => Err(String::from("the partition requires at least one cell")),
+            _ => Ok(()),
+        }
+    }
+}
+impl Default for FactorPartitionSize {
+    fn default() -> Self {
+        Self(DEFAULT_NUM_CELLS)
+    }
+}
+impl Debug for FactorPartitionSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+const DEFAULT_NUM_CELLS: usize = 1;
@@ -0,0 +1,108 @@
+use super::{
+    factor::Factor, pairs::FactorPairs, sources::FactorSources, tables::FactorTables,
+    target_sets::FactorTargetSets,
+};
+use crate::utils::cli;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+/// Queries of a treatment; levels are distinguished by their fields so that
+/// experiment files listing only source-sink pairs remain valid.
+///
+/// Variants are tried in order; hence, tables with both sources and targets precede sources.
+/// Unknown fields are rejected so that a misspelled field fails instead of matching another level.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FactorQueries {
+    OneToOne(FactorPairs),
+    ManyToMany(FactorTables),
+    OneToAll(FactorSources),
+    NearestTarget(FactorTargetSets),
+}
+
+impl Factor for FactorQueries {
+    fn query_header_index() -> usize {
+        12
+    }
+    fn name_factor() -> &'static str {
+        "Queries"
+    }
+    fn definition_factor() -> &'static str {
+        "Source-sink pairs shortest distances of which, sources shortest distances \
+        from which to all nodes, source & target sets the distance table between which, \
+        or sources with target sets distances to the nearest target of which \
+        will be computed in the experiment."
+    }
+
+    fn query_levels_from_cli() -> Vec<Self> {
+        PlainQueries::level_from_cli()
+            .into_iter()
+            .flat_map(|queries| match queries {
+                PlainQueries::OneToOne => FactorPairs::level_from_cli()
+                    .into_iter()
+                    .map(Self::OneToOne)
+                    .collect_vec(),
+                PlainQueries::OneToAll => FactorSources::level_from_cli()
+                    .into_iter()
+                    .map(Self::OneToAll)
+                    .collect_vec(),
+                PlainQueries::ManyToMany => FactorTables::level_from_cli()
+                    .into_iter()
+                    .map(Self::ManyToMany)
+                    .collect_vec(),
+                PlainQueries::NearestTarget => FactorTargetSets::level_from_cli()
+                    .into_iter()
+                    .map(Self::NearestTarget)
+                    .collect_vec(),
+            })
+            .collect()
+    }
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::ManyToMany(tables) => tables.validate(),
+            Self::NearestTarget(target_sets) => target_sets.validate(),
+            Self::OneToOne(_) | Self::OneToAll(_) => Ok(()),
+        }
+    }
+    fn to_cell_string(&self) -> String {
+        match self {
+            Self::OneToOne(pairs) => pairs.to_cell_string(),
+            Self::OneToAll(sources) => sources.to_cell_string(),
+            Self::ManyToMany(tables) => tables.to_cell_string(),
+            Self::NearestTarget(target_sets) => target_sets.to_cell_string(),
+        }
+    }
+}
+impl Default for FactorQueries {
+    fn default() -> Self {
+        Self::OneToOne(FactorPairs::default())
+    }
+}
+
+// plain
+#[derive(Clone, Debug, EnumIter)]
+enum PlainQueries {
+    OneToOne,
+    OneToAll,
+    ManyToMany,
+    NearestTarget,
+}
+impl PlainQueries {
+    fn level_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = PlainQueries::iter().collect();
+        let definitions = &[
+            "randomly sampled (s,t) pairs; shortest distance from s to t",
+            "randomly sampled sources s; shortest distances from s to all nodes",
+            "randomly sampled sources S & targets T; table of shortest distances from S to T",
+            "randomly sampled sources s & target sets T; shortest distance from s to the nearest t in T",
+        ];
+        cli::print_table_get_choices(
+            FactorQueries::name_factor(),
+            &available_levels,
+            definitions,
+            0,
+        )
+    }
+}
@@ -0,0 +1,46 @@
+use crate::utils::cli;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FactorSources {
+    pub seed: u64,
+    pub num_sources: usize,
+}
+
+impl FactorSources {
+    pub fn create_sources(&self, num_nodes: usize) -> Vec<usize> {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        (0..self.num_sources)
+            .map(|_| rng.gen_range(0..num_nodes))
+            .collect()
+    }
+
+    pub fn level_from_cli() -> Vec<Self> {
+        cli::print_subheader(1, "Sources");
+        let seed = cli::print_scalar_query_get_answers("random seed", DEFAULT_SEED, |_| true);
+        let num_sources =
+            cli::print_scalar_query_get_answers("number of sources", DEFAULT_NUM_SOURCES, |n| {
+                n >= &1
+            });
+        let mut combinations = vec![];
+        for &seed in &seed {
+            for &num_sources in &num_sources {
+                combinations.push(Self { seed, num_sources })
+            }
+        }
+        combinations
+    }
+    pub fn to_cell_string(self) -> String {
+        format!(
+            "* seed        : {}\n* num_sources : {}",
+            self.seed, self.num_sources
+        )
+    }
+}
+
+// defaults
+const DEFAULT_SEED: u64 = 465477;
+const DEFAULT_NUM_SOURCES: usize = 10;
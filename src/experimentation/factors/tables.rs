@@ -0,0 +1,70 @@
+use crate::utils::cli;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FactorTables {
+    pub seed: u64,
+    pub num_sources: usize,
+    pub num_targets: usize,
+}
+
+impl FactorTables {
+    pub fn create_sources_and_targets(&self, num_nodes: usize) -> (Vec<usize>, Vec<usize>) {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        let sources = (0..self.num_sources)
+            .map(|_| rng.gen_range(0..num_nodes))
+            .collect();
+        let targets = (0..self.num_targets)
+            .map(|_| rng.gen_range(0..num_nodes))
+            .collect();
+        (sources, targets)
+    }
+
+    pub fn level_from_cli() -> Vec<Self> {
+        cli::print_subheader(1, "Distance Tables");
+        let seed = cli::print_scalar_query_get_answers("random seed", DEFAULT_SEED, |_| true);
+        let num_sources =
+            cli::print_scalar_query_get_answers("number of sources", DEFAULT_NUM_SOURCES, |n| {
+                n >= &1
+            });
+        let num_targets =
+            cli::print_scalar_query_get_answers("number of targets", DEFAULT_NUM_TARGETS, |n| {
+                n >= &1
+            });
+        let mut combinations = vec![];
+        for &seed in &seed {
+            for &num_sources in &num_sources {
+                for &num_targets in &num_targets {
+                    combinations.push(Self {
+                        seed,
+                        num_sources,
+                        num_targets,
+                    })
+                }
+            }
+        }
+        combinations
+    }
+    pub fn validate(&self) -> Result<(), String> {
+        if self.num_sources == 0 || self.num_targets == 0 {
+            return Err(String::from(
+                "distance tables require at least one source and one target",
+            ));
+        }
+        Ok(())
+    }
+    pub fn to_cell_string(self) -> String {
+        format!(
+            "* seed        : {}\n* num_sources : {}\n* num_targets : {}",
+            self.seed, self.num_sources, self.num_targets
+        )
+    }
+}
+
+// defaults
+const DEFAULT_SEED: u64 = 465477;
+const DEFAULT_NUM_SOURCES: usize = 100;
+const DEFAULT_NUM_TARGETS: usize = 100;
@@ -0,0 +1,75 @@
+use crate::utils::cli;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+/// Sources each with a set of candidate targets, such as depots or hospitals;
+/// the query is the shortest distance from the source to the nearest of its targets.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FactorTargetSets {
+    pub seed: u64,
+    pub num_queries: usize,
+    pub target_set_size: usize,
+}
+
+impl FactorTargetSets {
+    pub fn create_queries(&self, num_nodes: usize) -> Vec<(usize, Vec<usize>)> {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        (0..self.num_queries)
+            .map(|_| {
+                let s = rng.gen_range(0..num_nodes);
+                let targets = (0..self.target_set_size)
+                    .map(|_| rng.gen_range(0..num_nodes))
+                    .collect();
+                (s, targets)
+            })
+            .collect()
+    }
+
+    pub fn level_from_cli() -> Vec<Self> {
+        cli::print_subheader(1, "Nearest of Target Sets");
+        let seed = cli::print_scalar_query_get_answers("random seed", DEFAULT_SEED, |_| true);
+        let num_queries =
+            cli::print_scalar_query_get_answers("number of queries", DEFAULT_NUM_QUERIES, |n| {
+                n >= &1
+            });
+        let target_set_size = cli::print_scalar_query_get_answers(
+            "number of targets in each set",
+            DEFAULT_TARGET_SET_SIZE,
+            |n| n >= &1,
+        );
+        let mut combinations = vec![];
+        for &seed in &seed {
+            for &num_queries in &num_queries {
+                for &target_set_size in &target_set_size {
+                    combinations.push(Self {
+                        seed,
+                        num_queries,
+                        target_set_size,
+                    })
+                }
+            }
+        }
+        combinations
+    }
+    pub fn validate(&self) -> Result<(), String> {
+        if self.num_queries == 0 || self.target_set_size == 0 {
+            return Err(String::from(
+                "nearest target queries require at least one query and one target in each set",
+            ));
+        }
+        Ok(())
+    }
+    pub fn to_cell_string(self) -> String {
+        format!(
+            "* seed            : {}\n* num_queries     : {}\n* target_set_size : {}",
+            self.seed, self.num_queries, self.target_set_size
+        )
+    }
+}
+
+// defaults
+const DEFAULT_SEED: u64 = 465477;
+const DEFAULT_NUM_QUERIES: usize = 100;
+const DEFAULT_TARGET_SET_SIZE: usize = 10;
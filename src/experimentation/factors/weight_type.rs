@@ -0,0 +1,53 @@
+use super::factor::Factor;
+use crate::utils::cli;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+/// Type of the edge weights; `Native` is the crate wide `Weight`, u64 or i64 with the `signed-weights` feature,
+/// such that experiment files are shared by both builds.
+///
+/// Other weight types are only available for dijkstra with the comparison based priority queues
+/// on the Vec adjacency lists; all other (graph representation, algorithm) pairs are declined.
+#[derive(Clone, Copy, Debug, Default, EnumIter, Serialize, Deserialize)]
+pub enum FactorWeightType {
+    #[default]
+    #[serde(alias = "U64", alias = "I64")]
+    Native,
+    U32,
+    F32,
+    F64,
+}
+
+impl FactorWeightType {
+    /// Whether the crate wide `Weight` is used.
+    pub fn is_native(&self) -> bool {
+        matches!(self, Self::Native)
+    }
+}
+
+impl Factor for FactorWeightType {
+    fn query_header_index() -> usize {
+        5
+    }
+    fn name_factor() -> &'static str {
+        "Weight Type"
+    }
+    fn definition_factor() -> &'static str {
+        "Type of the edge weights, distances & queue keys; weights of the graph data are converted when the graph is built."
+    }
+    fn query_levels_from_cli() -> Vec<Self> {
+        let available_levels: Vec<_> = FactorWeightType::iter().collect();
+        let definitions = &[
+            "Weight type of the crate used by all algorithms\n\
+            u64, or i64 when built with the signed-weights feature",
+            "u32 weights; graphs with weights beyond u32::MAX are rejected\n\
+            Available for dijkstra with comparison based queues on AdjListJaggedVec & AdjListFlatVec.",
+            "f32 weights totally ordered with total_cmp; weights without an exact f32, such as odd weights above 2^24, are rejected\n\
+            Available for dijkstra with comparison based queues on AdjListJaggedVec & AdjListFlatVec.",
+            "f64 weights totally ordered with total_cmp; weights without an exact f64, such as odd weights above 2^53, are rejected\n\
+            Available for dijkstra with comparison based queues on AdjListJaggedVec & AdjListFlatVec.",
+        ];
+        cli::print_table_get_choices(Self::name_factor(), &available_levels, definitions, 0)
+    }
+}
@@ -2,46 +2,152 @@ use super::{
     experiment::Experiment,
     factors::{
         algorithm::factor_algorithm::FactorAlgorithm, algorithm_data::FactorAlgorithmData,
-        factor::Factor, graph_data::factor_graph_data::FactorGraphData,
-        graph_representation::FactorGraphRepresentation, pairs::FactorPairs,
+        delta::FactorDelta, factor::Factor, graph_data::factor_graph_data::FactorGraphData,
+        graph_representation::FactorGraphRepresentation, node_ordering::FactorNodeOrdering,
+        num_paths::FactorNumPaths, num_threads::FactorNumThreads,
+        partition_size::FactorPartitionSize, queries::FactorQueries, weight_type::FactorWeightType,
     },
 };
 use crate::{
     algorithm::{
-        measure::{run_measure::Measure, treatment_measure::core::TreatmentMeasure},
+        apsp_query::ApspQuery,
+        arc_flags::ArcFlags,
+        arc_flags_dijkstra::ArcFlagsDijkstra,
+        astar::AStar,
+        bellman_ford::BellmanFord,
+        bfs::Bfs,
+        bidirectional_astar::BiAStar,
+        bidirectional_dijkstra::BiDijkstra,
+        bucket_queue::BucketQueue,
+        ch_query::ChQuery,
+        contraction_hierarchy::ContractionHierarchy,
+        coordinate_potential::CoordinatePotential,
+        delta_stepping::DeltaStepping,
+        dijkstra_queue::DijkstraQueue,
+        distance_matrix::DistanceMatrix,
+        fibonacci_heap::FibonacciHeap,
+        hub_label_query::HubLabelQuery,
+        hub_labels::HubLabels,
+        label_setting::LabelSetting,
+        landmarks::Landmarks,
+        measure::{
+            preprocessing::{Preprocessing, PreprocessingStats},
+            run_measure::Measure,
+            treatment_measure::core::TreatmentMeasure,
+        },
         orx_pq_dijkstra::OrxPqDijkstra,
         orx_pqdeckey_dijkstra::OrxPqDecKeyDijkstra,
+        pairing_heap::PairingHeap,
+        partition::Partition,
+        petgraph_astar::PetgraphAStar,
+        petgraph_bellman_ford::PetgraphBellmanFord,
         petgraph_dijkstra::PetgraphDijsktra,
         priority_queue_priority_queue::PriorityQueuePqDecKeyDijkstra,
+        queue_dijkstra::QueueDijkstra,
+        radix_heap::RadixHeap,
         run_attempt::RunAttempt,
-        sd_algorithm::ShortestDistanceAlgorithm,
+        sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
         solution::Solution,
-        std_pq_dijkstra::StdPqDijkstra,
+        spfa::Spfa,
+        std_pq_dijkstra::{State, StdPqDijkstra},
+        td_dijkstra::TdDijkstra,
+        yen::Yen,
+        zero_one_bfs::ZeroOneBfs,
     },
     experimentation::factors::algorithm::{
-        dary::Dary, dijkstra::Dijkstra, priority_queue::PriorityQueue,
+        all_pairs::AllPairs,
+        alt::{Alt, LandmarkSelection},
+        arc_flags::{self, Partitioner},
+        astar_bound::AStarBound,
+        bellman_ford,
+        bidirectional_astar::BidirectionalAStar,
+        bidirectional_dijkstra::BidirectionalDijkstra,
+        breadth_first_search::BreadthFirstSearch,
+        dary::Dary,
+        dijkstra::Dijkstra,
+        hub_labels::{self, HubOrdering},
+        priority_queue::PriorityQueue,
         priority_queue_deckey::PriorityQueueDecKey,
     },
     graph::{
-        adjlist_flat_vec::AdjListFlatVecBuilder, adjlist_jagged_vec::AdjListJaggedVec,
-        adjlist_petgraph::AdjListPetgraph, sp_graph::SpGraph, sp_graph_builder::SpGraphBuilder,
+        adjlist_compressed::AdjListCompressedBuilder,
+        adjlist_csr::AdjListCsrBuilder,
+        adjlist_flat_vec::AdjListFlatVecBuilder,
+        adjlist_jagged_vec::AdjListJaggedVec,
+        adjlist_petgraph::AdjListPetgraph,
+        adjlist_petgraph_csr::AdjListPetgraphCsrBuilder,
+        adjlist_petgraph_map::AdjListPetgraphMapBuilder,
+        adjlist_petgraph_stable::AdjListPetgraphStable,
+        coordinates::Coordinates,
+        node_permutation::NodePermutation,
+        out_edges_csr::CsrWeight64,
+        petgraph_graph::PetgraphGraph,
+        resource_adjlist::ResourceAdjListBuilder,
+        resource_graph_builder::ResourceGraphBuilder,
+        sp_graph::SpGraph,
+        sp_graph_builder::SpGraphBuilder,
+        td_adjlist::TdAdjListBuilder,
+        td_graph_builder::TdGraphBuilder,
+        weight_type::{self, TotalF32, TotalF64, WeightType},
     },
     Weight,
 };
 use itertools::Itertools;
 use orx_priority_queue::{DaryHeap, DaryHeapOfIndices, DaryHeapWithMap};
 use prettytable::{Cell, Row, Table};
+use std::{cell::OnceCell, cmp::Reverse, collections::BinaryHeap, rc::Rc};
 
 #[derive(Clone)]
 pub struct Treatment {
     pub graph_data: FactorGraphData,
     pub graph_representation: FactorGraphRepresentation,
+    pub node_ordering: FactorNodeOrdering,
+    pub weight_type: FactorWeightType,
     pub algorithm: FactorAlgorithm,
+    pub partition_size: FactorPartitionSize,
+    pub num_paths: FactorNumPaths,
+    pub delta: FactorDelta,
+    pub num_threads: FactorNumThreads,
     pub algorithm_data: FactorAlgorithmData,
-    pub pairs: FactorPairs,
+    pub queries: FactorQueries,
+    // set while building the graph unless the node ordering is the identity
+    node_permutation: OnceCell<NodePermutation>,
+    // graph of the graph data without reordering; only built for measures with reference solutions & graph checks
+    original_graph: OnceCell<AdjListJaggedVec>,
 }
 
 impl Treatment {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        graph_data: FactorGraphData,
+        graph_representation: FactorGraphRepresentation,
+        node_ordering: FactorNodeOrdering,
+        weight_type: FactorWeightType,
+        algorithm: FactorAlgorithm,
+        partition_size: FactorPartitionSize,
+        num_paths: FactorNumPaths,
+        delta: FactorDelta,
+        num_threads: FactorNumThreads,
+        algorithm_data: FactorAlgorithmData,
+        queries: FactorQueries,
+    ) -> Self {
+        Self {
+            graph_data,
+            graph_representation,
+            node_ordering,
+            weight_type,
+            algorithm,
+            partition_size,
+            num_paths,
+            delta,
+            num_threads,
+            algorithm_data,
+            queries,
+            node_permutation: OnceCell::new(),
+            original_graph: OnceCell::new(),
+        }
+    }
+
     pub(crate) fn add_table_header(table: &mut Table, experiment: &Experiment) {
         fn add_cell<F: Factor>(cells: &mut Vec<&str>, num_exp_levels: usize) {
             if num_exp_levels > 1 {
@@ -53,9 +159,15 @@ impl Treatment {
         let xp = experiment;
         add_cell::<FactorGraphData>(&mut cells, xp.graph_data.len());
         add_cell::<FactorGraphRepresentation>(&mut cells, xp.graph_representation.len());
+        add_cell::<FactorNodeOrdering>(&mut cells, xp.node_ordering.len());
+        add_cell::<FactorWeightType>(&mut cells, xp.weight_type.len());
         add_cell::<FactorAlgorithm>(&mut cells, xp.algorithm.len());
+        add_cell::<FactorPartitionSize>(&mut cells, xp.partition_size.len());
+        add_cell::<FactorNumPaths>(&mut cells, xp.num_paths.len());
+        add_cell::<FactorDelta>(&mut cells, xp.delta.len());
+        add_cell::<FactorNumThreads>(&mut cells, xp.num_threads.len());
         add_cell::<FactorAlgorithmData>(&mut cells, xp.algorithm_data.len());
-        add_cell::<FactorPairs>(&mut cells, xp.pairs.len());
+        add_cell::<FactorQueries>(&mut cells, xp.queries.len());
 
         let pretty_cells = cells.iter().map(|x| Cell::new(x)).collect_vec();
         table.add_row(Row::new(pretty_cells));
@@ -75,9 +187,15 @@ impl Treatment {
             xp.graph_representation.len(),
             &self.graph_representation,
         );
+        add_cell(&mut cells, xp.node_ordering.len(), &self.node_ordering);
+        add_cell(&mut cells, xp.weight_type.len(), &self.weight_type);
         add_cell(&mut cells, xp.algorithm.len(), &self.algorithm);
+        add_cell(&mut cells, xp.partition_size.len(), &self.partition_size);
+        add_cell(&mut cells, xp.num_paths.len(), &self.num_paths);
+        add_cell(&mut cells, xp.delta.len(), &self.delta);
+        add_cell(&mut cells, xp.num_threads.len(), &self.num_threads);
         add_cell(&mut cells, xp.algorithm_data.len(), &self.algorithm_data);
-        add_cell(&mut cells, xp.pairs.len(), &self.pairs);
+        add_cell(&mut cells, xp.queries.len(), &self.queries);
 
         let pretty_cells = cells.iter().map(|x| Cell::new(x.as_str())).collect_vec();
         table.add_row(Row::new(pretty_cells));
@@ -96,9 +214,15 @@ impl Treatment {
             xp.graph_representation.len(),
             &self.graph_representation,
         );
+        add(&mut def, xp.node_ordering.len(), &self.node_ordering);
+        add(&mut def, xp.weight_type.len(), &self.weight_type);
         add(&mut def, xp.algorithm.len(), &self.algorithm);
+        add(&mut def, xp.partition_size.len(), &self.partition_size);
+        add(&mut def, xp.num_paths.len(), &self.num_paths);
+        add(&mut def, xp.delta.len(), &self.delta);
+        add(&mut def, xp.num_threads.len(), &self.num_threads);
         add(&mut def, xp.algorithm_data.len(), &self.algorithm_data);
-        add(&mut def, xp.pairs.len(), &self.pairs);
+        add(&mut def, xp.queries.len(), &self.queries);
 
         def.join(" | ")
     }
@@ -115,43 +239,324 @@ impl Treatment {
         }
 
         match self.graph_representation {
-            FactorGraphRepresentation::AdjListJaggedVec => self
-                .run_with_graph_builder(self.graph_data.create_graph_builder::<AdjListJaggedVec>()),
-            FactorGraphRepresentation::AdjListFlatVec => self.run_with_graph_builder(
-                self.graph_data
-                    .create_graph_builder::<AdjListFlatVecBuilder>(),
+            FactorGraphRepresentation::AdjListJaggedVec => {
+                self.run_with_graph_builder(self.create_graph_builder::<AdjListJaggedVec>())
+            }
+            FactorGraphRepresentation::AdjListFlatVec => {
+                self.run_with_graph_builder(self.create_graph_builder::<AdjListFlatVecBuilder>())
+            }
+            FactorGraphRepresentation::AdjListPetgraph => {
+                self.run_with_graph_builder(self.create_graph_builder::<AdjListPetgraph>())
+            }
+            FactorGraphRepresentation::AdjListCsr => {
+                self.run_with_graph_builder(self.create_graph_builder::<AdjListCsrBuilder>())
+            }
+            FactorGraphRepresentation::AdjListPetgraphStable => {
+                self.run_with_graph_builder(self.create_graph_builder::<AdjListPetgraphStable>())
+            }
+            FactorGraphRepresentation::AdjListPetgraphMap => self
+                .run_with_graph_builder(self.create_graph_builder::<AdjListPetgraphMapBuilder>()),
+            FactorGraphRepresentation::AdjListPetgraphCsr => self
+                .run_with_graph_builder(self.create_graph_builder::<AdjListPetgraphCsrBuilder>()),
+            FactorGraphRepresentation::AdjListCompressed => {
+                self.run_with_graph_builder(self.create_graph_builder::<AdjListCompressedBuilder>())
+            }
+            FactorGraphRepresentation::AdjListCsr64 => self.run_with_graph_builder(
+                self.create_graph_builder::<AdjListCsrBuilder<CsrWeight64>>(),
             ),
-            FactorGraphRepresentation::AdjListPetgraph => self
-                .run_with_graph_builder(self.graph_data.create_graph_builder::<AdjListPetgraph>()),
         }
     }
 
+    // node ordering
+    fn create_graph_builder<B: SpGraphBuilder>(&self) -> B {
+        if let FactorNodeOrdering::Identity = self.node_ordering {
+            return self.graph_data.create_graph_builder::<B>();
+        }
+        let graph = self
+            .graph_data
+            .create_graph_builder::<AdjListJaggedVec>()
+            .build();
+        let permutation = match self.node_ordering {
+            FactorNodeOrdering::Identity => NodePermutation::identity(graph.num_nodes()),
+            FactorNodeOrdering::Bfs => NodePermutation::bfs(&graph),
+            FactorNodeOrdering::Dfs => NodePermutation::dfs(&graph),
+            FactorNodeOrdering::ReverseCuthillMcKee => {
+                NodePermutation::reverse_cuthill_mckee(&graph)
+            }
+            FactorNodeOrdering::Hilbert => {
+                let coordinates = self
+                    .graph_data
+                    .create_coordinates()
+                    .expect("must've been handled with run_special");
+                NodePermutation::hilbert(&coordinates)
+            }
+        };
+        let builder = permutation.reorder_graph(&graph);
+        _ = self.node_permutation.set(permutation);
+        builder
+    }
+    fn create_coordinates(&self) -> Option<Coordinates> {
+        let coordinates = self.graph_data.create_coordinates()?;
+        Some(match self.node_permutation.get() {
+            Some(permutation) => permutation.reorder_coordinates(&coordinates),
+            None => coordinates,
+        })
+    }
+    // queries are created with the node ids of the graph data
+    fn map_node(&self, node: usize) -> usize {
+        match self.node_permutation.get() {
+            Some(permutation) => permutation.new_of_old(node),
+            None => node,
+        }
+    }
+    fn map_nodes(&self, nodes: Vec<usize>) -> Vec<usize> {
+        nodes.into_iter().map(|x| self.map_node(x)).collect()
+    }
+    fn original_graph(&self) -> &AdjListJaggedVec {
+        self.original_graph.get_or_init(|| {
+            self.graph_data
+                .create_graph_builder::<AdjListJaggedVec>()
+                .build()
+        })
+    }
+    // queries which the algorithm could not solve end the treatment as not completed
+    fn not_solved_error<G: SpGraph, M: Measure>(&self, solution: &Solution<M>) -> Option<String> {
+        if solution.distance_overflow {
+            return Some(format!(
+                "(graph, weight type) mismatch:\nshortest distances overflow the weight type {}",
+                G::W::name()
+            ));
+        }
+        if solution.query_declined {
+            let queries = match self.queries {
+                FactorQueries::OneToOne(pairs) if pairs.paths => "shortest path",
+                FactorQueries::OneToOne(_) => "one-to-one",
+                FactorQueries::ManyToMany(_) => "many-to-many",
+                FactorQueries::OneToAll(_) => "one-to-all",
+                FactorQueries::NearestTarget(_) => "nearest target",
+            };
+            return Some(format!(
+                "(algorithm, queries) mismatch:\n{:?} does not answer {} queries",
+                self.algorithm, queries
+            ));
+        }
+        None
+    }
+    // reference solutions are computed on the original graph; hence, the nodes of the queries are mapped back
+    fn set_reference<M: Measure>(&self, measure: &mut M) {
+        if !M::REQUIRES_REFERENCE {
+            return;
+        }
+        let has_negative_weights = self.graph_data.has_negative_weights();
+        measure.set_reference(
+            self.original_graph(),
+            has_negative_weights,
+            |node| match self.node_permutation.get() {
+                Some(permutation) => permutation.old_of_new(node),
+                None => node,
+            },
+        );
+    }
+
     // handle special matches
     fn run_special<E, M>(&self) -> Option<RunAttempt<M, E>>
     where
         M: Measure,
         E: TreatmentMeasure<M = M>,
     {
-        #[allow(irrefutable_let_patterns)]
-        if let FactorAlgorithm::Dijkstra(dijkstra) = &self.algorithm {
-            if matches!(dijkstra, Dijkstra::Petgraph) {
-                let attempt = match self.graph_representation {
-                    FactorGraphRepresentation::AdjListPetgraph => {
-                        let graph = self.graph_data.create_graph_builder::<AdjListPetgraph>();
-
-                        #[cfg(feature = "dhat-heap")]
-                        let _profiler = dhat::Profiler::new_heap();
-
-                        let graph = graph.build();
-                        let algorithm = PetgraphDijsktra::new(&graph);
-                        self.run_with_graph_alg(graph, algorithm)
-                    }
-                    _ => {
-                        let err = String::from("(graph, algorithm) mismatch:\npetgraph::algo::Dijkstra can only be run with petgraph::graph::Graph");
-                        RunAttempt::NotCompleted(err)
-                    }
-                };
-                return Some(attempt);
+        if self.graph_data.has_negative_weights() {
+            if cfg!(not(feature = "signed-weights")) {
+                let err = String::from("negative weights require the signed-weights feature:\ncargo run --release --features signed-weights");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+            if !self.algorithm.allows_negative_weights() {
+                let err = String::from("(graph, algorithm) mismatch:\nonly Bellman-Ford variants & all-pairs algorithms allow negative weights");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+        }
+        if let FactorQueries::OneToOne(pairs) = &self.queries {
+            if pairs.paths && !self.algorithm.reconstructs_paths() {
+                let err = String::from("(algorithm, queries) mismatch:\nshortest paths are not reconstructed by bidirectional dijkstra, contraction hierarchies, delta-stepping, all-pairs algorithms, hub labels, bidirectional A*, time-dependent dijkstra & resource-constrained label-setting");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+        }
+        if self.node_ordering.requires_coordinates() && !self.graph_data.has_coordinates() {
+            let err = String::from("(graph, node ordering) mismatch:\nhilbert ordering requires node coordinates which are only available for Dimacs9th graphs");
+            return Some(RunAttempt::NotCompleted(err));
+        }
+        if !matches!(self.node_ordering, FactorNodeOrdering::Identity)
+            && (self.algorithm.is_time_dependent()
+                || matches!(self.algorithm, FactorAlgorithm::ResourceConstrained(_)))
+        {
+            let err = String::from("(node ordering, algorithm) mismatch:\ntime-dependent dijkstra & resource-constrained label-setting only run with the identity node ordering");
+            return Some(RunAttempt::NotCompleted(err));
+        }
+        if self.partition_size.is_partitioned()
+            && !matches!(self.algorithm, FactorAlgorithm::ArcFlags(_))
+        {
+            let err = String::from("(algorithm, partition size) mismatch:\nonly arc flags partition the graph into more than one cell");
+            return Some(RunAttempt::NotCompleted(err));
+        }
+        if !self.num_paths.is_single_path()
+            && !matches!(self.algorithm, FactorAlgorithm::KShortestPaths)
+        {
+            let err = String::from("(algorithm, number of paths) mismatch:\nonly k-shortest paths find more than one path for each query");
+            return Some(RunAttempt::NotCompleted(err));
+        }
+        if !self.delta.is_default() && !matches!(self.algorithm, FactorAlgorithm::DeltaStepping) {
+            let err = String::from("(algorithm, delta) mismatch:\nonly delta-stepping buckets the nodes by a width delta");
+            return Some(RunAttempt::NotCompleted(err));
+        }
+        if !self.num_threads.is_sequential()
+            && !matches!(self.algorithm, FactorAlgorithm::DeltaStepping)
+        {
+            let err = String::from("(algorithm, number of threads) mismatch:\nonly delta-stepping runs on more than one thread");
+            return Some(RunAttempt::NotCompleted(err));
+        }
+        if self.graph_data.is_time_dependent() && !self.algorithm.is_time_dependent() {
+            let err = String::from("(graph, algorithm) mismatch:\nrush-hour travel times can only be solved by time-dependent dijkstra");
+            return Some(RunAttempt::NotCompleted(err));
+        }
+        if !self.weight_type.is_native() {
+            if !self.algorithm.allows_weight_types() {
+                let err = String::from("(weight type, algorithm) mismatch:\nweight types other than the crate's Weight are only available for dijkstra with comparison based queues; bucket queue & radix heap are keyed by the integer Weight");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+            if !self.graph_representation.allows_weight_types() {
+                let err = String::from("(graph representation, weight type) mismatch:\nweight types other than the crate's Weight are only available for AdjListJaggedVec & AdjListFlatVec");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+            let fits = match self.weight_type {
+                FactorWeightType::U32 => {
+                    weight_type::check_weights::<u32, _>(self.original_graph())
+                }
+                FactorWeightType::F32 => {
+                    weight_type::check_weights::<TotalF32, _>(self.original_graph())
+                }
+                FactorWeightType::F64 => {
+                    weight_type::check_weights::<TotalF64, _>(self.original_graph())
+                }
+                FactorWeightType::Native => Ok(()),
+            };
+            if let Err(err) = fits {
+                let err = format!("(graph, weight type) mismatch:\n{}", err);
+                return Some(RunAttempt::NotCompleted(err));
+            }
+            let attempt = match self.weight_type {
+                FactorWeightType::U32 => self.run_with_weight_type::<E, M, u32>(),
+                FactorWeightType::F32 => self.run_with_weight_type::<E, M, TotalF32>(),
+                FactorWeightType::F64 => self.run_with_weight_type::<E, M, TotalF64>(),
+                FactorWeightType::Native => {
+                    panic!("native weight type runs with the crate's Weight")
+                }
+            };
+            return Some(attempt);
+        }
+        if let FactorAlgorithm::TimeDependentDijkstra(td) = &self.algorithm {
+            if !matches!(
+                self.graph_representation,
+                FactorGraphRepresentation::AdjListJaggedVec
+            ) {
+                let err = String::from("(graph representation, algorithm) mismatch:\ntime-dependent dijkstra runs on its own travel time adjacency list; use the default AdjListJaggedVec level");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+            let attempt = match self.queries {
+                FactorQueries::OneToOne(_) => {
+                    let graph = self
+                        .graph_data
+                        .create_td_graph_builder::<TdAdjListBuilder>();
+
+                    #[cfg(feature = "dhat-heap")]
+                    let _profiler = dhat::Profiler::new_heap();
+
+                    let graph = graph.build();
+                    self.run_with_graph_alg(graph, |g| TdDijkstra::new(g, td.departure_time))
+                }
+                _ => {
+                    let err = String::from("(algorithm, queries) mismatch:\ntime-dependent dijkstra only answers one-to-one queries");
+                    RunAttempt::NotCompleted(err)
+                }
+            };
+            return Some(attempt);
+        }
+        if let FactorAlgorithm::ResourceConstrained(rc) = &self.algorithm {
+            if !self.graph_data.has_resources() {
+                let err = String::from("(graph, algorithm) mismatch:\nresource-constrained shortest paths require a random graph with edge resources");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+            if !matches!(
+                self.graph_representation,
+                FactorGraphRepresentation::AdjListJaggedVec
+            ) {
+                let err = String::from("(graph representation, algorithm) mismatch:\nresource-constrained label-setting runs on its own resource adjacency list; use the default AdjListJaggedVec level");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+            let attempt = match self.queries {
+                FactorQueries::OneToOne(_) => {
+                    let graph = self
+                        .graph_data
+                        .create_resource_graph_builder::<ResourceAdjListBuilder>();
+
+                    #[cfg(feature = "dhat-heap")]
+                    let _profiler = dhat::Profiler::new_heap();
+
+                    let graph = graph.build();
+                    self.run_with_graph_alg(graph, |g| LabelSetting::new(g, rc.budget))
+                }
+                _ => {
+                    let err = String::from("(algorithm, queries) mismatch:\nresource-constrained label-setting only answers one-to-one queries");
+                    RunAttempt::NotCompleted(err)
+                }
+            };
+            return Some(attempt);
+        }
+        if let FactorAlgorithm::AStar(_)
+        | FactorAlgorithm::PetgraphAStar(_)
+        | FactorAlgorithm::BidirectionalAStar(BidirectionalAStar::Coordinates(_)) =
+            &self.algorithm
+        {
+            if !self.graph_data.has_coordinates() {
+                let err = String::from("(graph, algorithm) mismatch:\nA* & bidirectional A* with coordinate bounds require node coordinates which are only available for Dimacs9th graphs");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+        }
+        if self.algorithm.uses_petgraph() {
+            let attempt = match self.graph_representation {
+                FactorGraphRepresentation::AdjListPetgraph => {
+                    self.run_with_petgraph_builder(self.create_graph_builder::<AdjListPetgraph>())
+                }
+                FactorGraphRepresentation::AdjListPetgraphStable => self.run_with_petgraph_builder(
+                    self.create_graph_builder::<AdjListPetgraphStable>(),
+                ),
+                FactorGraphRepresentation::AdjListPetgraphMap => self.run_with_petgraph_builder(
+                    self.create_graph_builder::<AdjListPetgraphMapBuilder>(),
+                ),
+                FactorGraphRepresentation::AdjListPetgraphCsr => self.run_with_petgraph_builder(
+                    self.create_graph_builder::<AdjListPetgraphCsrBuilder>(),
+                ),
+                _ => {
+                    let err = String::from("(graph, algorithm) mismatch:\npetgraph::algo algorithms can only be run with petgraph graph representations");
+                    RunAttempt::NotCompleted(err)
+                }
+            };
+            return Some(attempt);
+        }
+        if let FactorAlgorithm::ArcFlags(arc_flags) = &self.algorithm {
+            if matches!(arc_flags.partitioner, Partitioner::Grid)
+                && !self.graph_data.has_coordinates()
+            {
+                let err = String::from("(graph, algorithm) mismatch:\ngrid partitioning requires node coordinates which are only available for Dimacs9th graphs");
+                return Some(RunAttempt::NotCompleted(err));
+            }
+        }
+        if let FactorAlgorithm::BreadthFirstSearch(bfs) = &self.algorithm {
+            let compatible = match bfs {
+                BreadthFirstSearch::Unit => self.graph_data.has_unit_weights(),
+                BreadthFirstSearch::ZeroOne => self.graph_data.has_zero_one_weights(),
+            };
+            if !compatible {
+                let err = String::from("(graph, algorithm) mismatch:\nbreadth-first search requires a random graph with unit weights, or unit or 0-1 weights for 0-1 BFS");
+                return Some(RunAttempt::NotCompleted(err));
             }
         }
         None
@@ -160,10 +565,14 @@ impl Treatment {
     fn run_with_graph_builder<E, M, G, B>(&self, graph_builder: B) -> RunAttempt<M, E>
     where
         M: Measure,
-        G: SpGraph,
+        G: SpGraph<W = Weight> + Sync,
         B: SpGraphBuilder<G = G>,
         E: TreatmentMeasure<M = M>,
     {
+        if let Err(err) = graph_builder.validate() {
+            return RunAttempt::NotCompleted(err);
+        }
+
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
@@ -174,52 +583,172 @@ impl Treatment {
                 Dijkstra::PriorityQueueDecKey(pqdk) => self.run_with_graph_pqdk(pqdk, graph),
                 Dijkstra::Petgraph => panic!("must've been handled with run_special"),
             },
+            FactorAlgorithm::BidirectionalDijkstra(dijkstra) => match dijkstra {
+                BidirectionalDijkstra::PriorityQueue(pq) => {
+                    self.run_with_graph_bidirectional_pq(pq, graph)
+                }
+                BidirectionalDijkstra::PriorityQueueDecKey(pqdk) => {
+                    self.run_with_graph_bidirectional_pqdk(pqdk, graph)
+                }
+            },
+            FactorAlgorithm::AStar(bound) => self.run_with_graph_astar(bound, graph),
+            FactorAlgorithm::Alt(alt) => self.run_with_graph_alt(alt, graph),
+            FactorAlgorithm::BidirectionalAStar(bidirectional) => {
+                self.run_with_graph_bidirectional_astar(bidirectional, graph)
+            }
+            FactorAlgorithm::ContractionHierarchies => self.run_with_graph_ch(graph),
+            FactorAlgorithm::DeltaStepping => self.run_with_graph_alg(graph, |g| {
+                DeltaStepping::new(g, self.delta.width(), self.num_threads.num_threads())
+            }),
+            FactorAlgorithm::BellmanFord(variant) => match variant {
+                bellman_ford::BellmanFord::Rounds => {
+                    self.run_with_graph_alg(graph, BellmanFord::new)
+                }
+                bellman_ford::BellmanFord::Spfa => self.run_with_graph_alg(graph, Spfa::new),
+                bellman_ford::BellmanFord::Petgraph => {
+                    panic!("must've been handled with run_special")
+                }
+            },
+            FactorAlgorithm::KShortestPaths => {
+                self.run_with_graph_alg(graph, |g| Yen::new(g, self.num_paths.k()))
+            }
+            FactorAlgorithm::AllPairs(all_pairs) => self.run_with_graph_all_pairs(all_pairs, graph),
+            FactorAlgorithm::BreadthFirstSearch(bfs) => match bfs {
+                BreadthFirstSearch::Unit => self.run_with_graph_alg(graph, Bfs::new),
+                BreadthFirstSearch::ZeroOne => self.run_with_graph_alg(graph, ZeroOneBfs::new),
+            },
+            FactorAlgorithm::ArcFlags(arc_flags) => self.run_with_graph_arc_flags(arc_flags, graph),
+            FactorAlgorithm::HubLabels(hub_labels) => {
+                self.run_with_graph_hub_labels(hub_labels, graph)
+            }
+            FactorAlgorithm::TimeDependentDijkstra(_)
+            | FactorAlgorithm::ResourceConstrained(_)
+            | FactorAlgorithm::PetgraphAStar(_) => {
+                panic!("must've been handled with run_special")
+            }
         }
     }
-    fn run_with_graph_pq<E, M, G>(&self, pq: &PriorityQueue, graph: G) -> RunAttempt<M, E>
+    // graphs with other weight types
+    fn run_with_weight_type<E, M, W>(&self) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        W: WeightType,
+        E: TreatmentMeasure<M = M>,
+    {
+        match self.graph_representation {
+            FactorGraphRepresentation::AdjListJaggedVec => self
+                .run_with_typed_graph_builder(self.create_graph_builder::<AdjListJaggedVec<W>>()),
+            FactorGraphRepresentation::AdjListFlatVec => self.run_with_typed_graph_builder(
+                self.create_graph_builder::<AdjListFlatVecBuilder<W>>(),
+            ),
+            _ => panic!("must've been handled with run_special"),
+        }
+    }
+    fn run_with_typed_graph_builder<E, M, G, B>(&self, graph_builder: B) -> RunAttempt<M, E>
     where
         M: Measure,
         G: SpGraph,
+        B: SpGraphBuilder<G = G>,
         E: TreatmentMeasure<M = M>,
     {
-        type OrxBinary = DaryHeap<usize, Weight, 2>;
-        type OrxQuaternary = DaryHeap<usize, Weight, 4>;
-        type OrxOctonary = DaryHeap<usize, Weight, 8>;
-        type OrxD16 = DaryHeap<usize, Weight, 16>;
-        type OrxD32 = DaryHeap<usize, Weight, 32>;
-        type OrxD64 = DaryHeap<usize, Weight, 64>;
+        if let Err(err) = graph_builder.validate() {
+            return RunAttempt::NotCompleted(err);
+        }
+
+        #[cfg(feature = "dhat-heap")]
+        let _profiler = dhat::Profiler::new_heap();
 
+        let graph = graph_builder.build();
+        match &self.algorithm {
+            FactorAlgorithm::Dijkstra(Dijkstra::PriorityQueue(pq)) => {
+                self.run_with_graph_heap(pq, graph)
+            }
+            FactorAlgorithm::Dijkstra(Dijkstra::PriorityQueueDecKey(pqdk)) => {
+                self.run_with_graph_pqdk(pqdk, graph)
+            }
+            _ => panic!("must've been handled with run_special"),
+        }
+    }
+    // petgraph graphs
+    fn run_with_petgraph_builder<E, M, G, B>(&self, graph_builder: B) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: PetgraphGraph,
+        B: SpGraphBuilder<G = G>,
+        E: TreatmentMeasure<M = M>,
+    {
+        if let Err(err) = graph_builder.validate() {
+            return RunAttempt::NotCompleted(err);
+        }
+
+        #[cfg(feature = "dhat-heap")]
+        let _profiler = dhat::Profiler::new_heap();
+
+        let graph = graph_builder.build();
+        match &self.algorithm {
+            FactorAlgorithm::Dijkstra(Dijkstra::Petgraph) => {
+                self.run_with_graph_alg(graph, PetgraphDijsktra::new)
+            }
+            FactorAlgorithm::BellmanFord(bellman_ford::BellmanFord::Petgraph) => {
+                self.run_with_graph_alg(graph, PetgraphBellmanFord::new)
+            }
+            FactorAlgorithm::PetgraphAStar(bound) => {
+                let potential = Rc::new(self.create_coordinate_potential(bound, &graph));
+                self.run_with_graph_alg(graph, |g| PetgraphAStar::new(g, potential.clone()))
+            }
+            _ => panic!("only petgraph algorithms are run with run_with_petgraph_builder"),
+        }
+    }
+    fn run_with_graph_pq<E, M, G>(&self, pq: &PriorityQueue, graph: G) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph<W = Weight>,
+        E: TreatmentMeasure<M = M>,
+    {
         match pq {
-            PriorityQueue::StdBinaryHeap => {
-                let algorithm = StdPqDijkstra::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+            PriorityQueue::BucketQueue => {
+                self.run_with_graph_alg(graph, QueueDijkstra::<BucketQueue>::new)
+            }
+            PriorityQueue::RadixHeap => {
+                self.run_with_graph_alg(graph, QueueDijkstra::<RadixHeap>::new)
             }
+            PriorityQueue::StdBinaryHeap | PriorityQueue::OrxDaryHeap(_) => {
+                self.run_with_graph_heap(pq, graph)
+            }
+        }
+    }
+    // comparison based queues keyed by the weight type of the graph
+    fn run_with_graph_heap<E, M, G>(&self, pq: &PriorityQueue, graph: G) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        match pq {
+            PriorityQueue::StdBinaryHeap => self.run_with_graph_alg(graph, StdPqDijkstra::new),
             PriorityQueue::OrxDaryHeap(dary) => match dary {
                 Dary::Binary => {
-                    let algorithm = OrxPqDijkstra::<OrxBinary>::new(&graph);
-                    self.run_with_graph_alg(graph, algorithm)
+                    self.run_with_graph_alg(graph, OrxPqDijkstra::<DaryHeap<_, _, 2>, _>::new)
                 }
                 Dary::Quaternary => {
-                    let algorithm = OrxPqDijkstra::<OrxQuaternary>::new(&graph);
-                    self.run_with_graph_alg(graph, algorithm)
+                    self.run_with_graph_alg(graph, OrxPqDijkstra::<DaryHeap<_, _, 4>, _>::new)
                 }
                 Dary::Octonary => {
-                    let algorithm = OrxPqDijkstra::<OrxOctonary>::new(&graph);
-                    self.run_with_graph_alg(graph, algorithm)
+                    self.run_with_graph_alg(graph, OrxPqDijkstra::<DaryHeap<_, _, 8>, _>::new)
                 }
                 Dary::D16 => {
-                    let algorithm = OrxPqDijkstra::<OrxD16>::new(&graph);
-                    self.run_with_graph_alg(graph, algorithm)
+                    self.run_with_graph_alg(graph, OrxPqDijkstra::<DaryHeap<_, _, 16>, _>::new)
                 }
                 Dary::D32 => {
-                    let algorithm = OrxPqDijkstra::<OrxD32>::new(&graph);
-                    self.run_with_graph_alg(graph, algorithm)
+                    self.run_with_graph_alg(graph, OrxPqDijkstra::<DaryHeap<_, _, 32>, _>::new)
                 }
                 Dary::D64 => {
-                    let algorithm = OrxPqDijkstra::<OrxD64>::new(&graph);
-                    self.run_with_graph_alg(graph, algorithm)
+                    self.run_with_graph_alg(graph, OrxPqDijkstra::<DaryHeap<_, _, 64>, _>::new)
                 }
             },
+            PriorityQueue::BucketQueue | PriorityQueue::RadixHeap => {
+                panic!("must've been handled with run_special")
+            }
         }
     }
     fn run_with_graph_pqdk<E, M, G>(&self, pqdk: &PriorityQueueDecKey, graph: G) -> RunAttempt<M, E>
@@ -236,8 +765,13 @@ impl Treatment {
                 self.run_with_graph_pqdk_map(dary, graph)
             }
             PriorityQueueDecKey::PriorityQueuePriorityQueue => {
-                let algorithm = PriorityQueuePqDecKeyDijkstra::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+                self.run_with_graph_alg(graph, PriorityQueuePqDecKeyDijkstra::new)
+            }
+            PriorityQueueDecKey::FibonacciHeap => {
+                self.run_with_graph_alg(graph, QueueDijkstra::<FibonacciHeap<_>, _>::new)
+            }
+            PriorityQueueDecKey::PairingHeap => {
+                self.run_with_graph_alg(graph, QueueDijkstra::<PairingHeap<_>, _>::new)
             }
         }
     }
@@ -247,97 +781,463 @@ impl Treatment {
         G: SpGraph,
         E: TreatmentMeasure<M = M>,
     {
-        type OrxIdxBinary = DaryHeapOfIndices<usize, Weight, 2>;
-        type OrxIdxQuaternary = DaryHeapOfIndices<usize, Weight, 4>;
-        type OrxIdxOctonary = DaryHeapOfIndices<usize, Weight, 8>;
-        type OrxIdxD16 = DaryHeapOfIndices<usize, Weight, 16>;
-        type OrxIdxD32 = DaryHeapOfIndices<usize, Weight, 32>;
-        type OrxIdxD64 = DaryHeapOfIndices<usize, Weight, 64>;
-
         match dary {
-            Dary::Binary => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxIdxBinary>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
-            }
-            Dary::Quaternary => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxIdxQuaternary>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
-            }
-            Dary::Octonary => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxIdxOctonary>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
-            }
-            Dary::D16 => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxIdxD16>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+            Dary::Binary => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapOfIndices<_, _, 2>, _>::new,
+            ),
+            Dary::Quaternary => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapOfIndices<_, _, 4>, _>::new,
+            ),
+            Dary::Octonary => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapOfIndices<_, _, 8>, _>::new,
+            ),
+            Dary::D16 => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapOfIndices<_, _, 16>, _>::new,
+            ),
+            Dary::D32 => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapOfIndices<_, _, 32>, _>::new,
+            ),
+            Dary::D64 => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapOfIndices<_, _, 64>, _>::new,
+            ),
+        }
+    }
+    fn run_with_graph_pqdk_map<E, M, G>(&self, dary: &Dary, graph: G) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        match dary {
+            Dary::Binary => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapWithMap<_, _, 2>, _>::new,
+            ),
+            Dary::Quaternary => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapWithMap<_, _, 4>, _>::new,
+            ),
+            Dary::Octonary => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapWithMap<_, _, 8>, _>::new,
+            ),
+            Dary::D16 => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapWithMap<_, _, 16>, _>::new,
+            ),
+            Dary::D32 => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapWithMap<_, _, 32>, _>::new,
+            ),
+            Dary::D64 => self.run_with_graph_alg(
+                graph,
+                OrxPqDecKeyDijkstra::<DaryHeapWithMap<_, _, 64>, _>::new,
+            ),
+        }
+    }
+    fn run_with_graph_bidirectional_pq<E, M, G>(
+        &self,
+        pq: &PriorityQueue,
+        graph: G,
+    ) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        type OrxBinary = DaryHeap<usize, Weight, 2>;
+        type OrxQuaternary = DaryHeap<usize, Weight, 4>;
+        type OrxOctonary = DaryHeap<usize, Weight, 8>;
+        type OrxD16 = DaryHeap<usize, Weight, 16>;
+        type OrxD32 = DaryHeap<usize, Weight, 32>;
+        type OrxD64 = DaryHeap<usize, Weight, 64>;
+
+        match pq {
+            PriorityQueue::StdBinaryHeap => {
+                self.run_with_graph_bidirectional::<BinaryHeap<State>, _, _, _>(graph)
             }
-            Dary::D32 => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxIdxD32>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+            PriorityQueue::BucketQueue => {
+                self.run_with_graph_bidirectional::<BucketQueue, _, _, _>(graph)
             }
-            Dary::D64 => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxIdxD64>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+            PriorityQueue::RadixHeap => {
+                self.run_with_graph_bidirectional::<RadixHeap, _, _, _>(graph)
             }
+            PriorityQueue::OrxDaryHeap(dary) => match dary {
+                Dary::Binary => self.run_with_graph_bidirectional::<OrxBinary, _, _, _>(graph),
+                Dary::Quaternary => {
+                    self.run_with_graph_bidirectional::<OrxQuaternary, _, _, _>(graph)
+                }
+                Dary::Octonary => self.run_with_graph_bidirectional::<OrxOctonary, _, _, _>(graph),
+                Dary::D16 => self.run_with_graph_bidirectional::<OrxD16, _, _, _>(graph),
+                Dary::D32 => self.run_with_graph_bidirectional::<OrxD32, _, _, _>(graph),
+                Dary::D64 => self.run_with_graph_bidirectional::<OrxD64, _, _, _>(graph),
+            },
         }
     }
-    fn run_with_graph_pqdk_map<E, M, G>(&self, dary: &Dary, graph: G) -> RunAttempt<M, E>
+    fn run_with_graph_bidirectional_pqdk<E, M, G>(
+        &self,
+        pqdk: &PriorityQueueDecKey,
+        graph: G,
+    ) -> RunAttempt<M, E>
     where
         M: Measure,
         G: SpGraph,
         E: TreatmentMeasure<M = M>,
     {
+        type OrxIdxBinary = DaryHeapOfIndices<usize, Weight, 2>;
+        type OrxIdxQuaternary = DaryHeapOfIndices<usize, Weight, 4>;
+        type OrxIdxOctonary = DaryHeapOfIndices<usize, Weight, 8>;
+        type OrxIdxD16 = DaryHeapOfIndices<usize, Weight, 16>;
+        type OrxIdxD32 = DaryHeapOfIndices<usize, Weight, 32>;
+        type OrxIdxD64 = DaryHeapOfIndices<usize, Weight, 64>;
         type OrxMapBinary = DaryHeapWithMap<usize, Weight, 2>;
         type OrxMapQuaternary = DaryHeapWithMap<usize, Weight, 4>;
         type OrxMapOctonary = DaryHeapWithMap<usize, Weight, 8>;
         type OrxMapD16 = DaryHeapWithMap<usize, Weight, 16>;
         type OrxMapD32 = DaryHeapWithMap<usize, Weight, 32>;
         type OrxMapD64 = DaryHeapWithMap<usize, Weight, 64>;
+        type PqPq = priority_queue::PriorityQueue<usize, Reverse<Weight>>;
 
-        match dary {
-            Dary::Binary => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxMapBinary>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
-            }
-            Dary::Quaternary => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxMapQuaternary>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+        match pqdk {
+            PriorityQueueDecKey::OrxDaryHeapOfIndices(dary) => match dary {
+                Dary::Binary => self.run_with_graph_bidirectional::<OrxIdxBinary, _, _, _>(graph),
+                Dary::Quaternary => {
+                    self.run_with_graph_bidirectional::<OrxIdxQuaternary, _, _, _>(graph)
+                }
+                Dary::Octonary => {
+                    self.run_with_graph_bidirectional::<OrxIdxOctonary, _, _, _>(graph)
+                }
+                Dary::D16 => self.run_with_graph_bidirectional::<OrxIdxD16, _, _, _>(graph),
+                Dary::D32 => self.run_with_graph_bidirectional::<OrxIdxD32, _, _, _>(graph),
+                Dary::D64 => self.run_with_graph_bidirectional::<OrxIdxD64, _, _, _>(graph),
+            },
+            PriorityQueueDecKey::OrxDaryHeapWithMap(dary) => match dary {
+                Dary::Binary => self.run_with_graph_bidirectional::<OrxMapBinary, _, _, _>(graph),
+                Dary::Quaternary => {
+                    self.run_with_graph_bidirectional::<OrxMapQuaternary, _, _, _>(graph)
+                }
+                Dary::Octonary => {
+                    self.run_with_graph_bidirectional::<OrxMapOctonary, _, _, _>(graph)
+                }
+                Dary::D16 => self.run_with_graph_bidirectional::<OrxMapD16, _, _, _>(graph),
+                Dary::D32 => self.run_with_graph_bidirectional::<OrxMapD32, _, _, _>(graph),
+                Dary::D64 => self.run_with_graph_bidirectional::<OrxMapD64, _, _, _>(graph),
+            },
+            PriorityQueueDecKey::PriorityQueuePriorityQueue => {
+                self.run_with_graph_bidirectional::<PqPq, _, _, _>(graph)
             }
-            Dary::Octonary => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxMapOctonary>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+            PriorityQueueDecKey::FibonacciHeap => {
+                self.run_with_graph_bidirectional::<FibonacciHeap, _, _, _>(graph)
             }
-            Dary::D16 => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxMapD16>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+            PriorityQueueDecKey::PairingHeap => {
+                self.run_with_graph_bidirectional::<PairingHeap, _, _, _>(graph)
             }
-            Dary::D32 => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxMapD32>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+        }
+    }
+    fn run_with_graph_bidirectional<Q, E, M, G>(&self, graph: G) -> RunAttempt<M, E>
+    where
+        Q: DijkstraQueue,
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        self.run_with_graph_alg(graph, BiDijkstra::<Q>::new)
+    }
+    fn run_with_graph_astar<E, M, G>(&self, bound: &AStarBound, graph: G) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        type OrxBinary = DaryHeap<usize, Weight, 2>;
+
+        let potential = Rc::new(self.create_coordinate_potential(bound, &graph));
+        self.run_with_graph_alg(graph, |g| AStar::<OrxBinary, _>::new(g, potential.clone()))
+    }
+    fn run_with_graph_alt<E, M, G>(&self, alt: &Alt, graph: G) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        type OrxBinary = DaryHeap<usize, Weight, 2>;
+
+        let (landmarks, preprocessing) = Self::create_landmarks(alt, &graph);
+        let landmarks = Rc::new(landmarks);
+        self.run_with_graph_preprocessed_alg(graph, Some(preprocessing), |g| {
+            AStar::<OrxBinary, _>::new(g, landmarks.clone())
+        })
+    }
+    fn run_with_graph_bidirectional_astar<E, M, G>(
+        &self,
+        bidirectional: &BidirectionalAStar,
+        graph: G,
+    ) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        type OrxBinary = DaryHeap<usize, Weight, 2>;
+
+        match bidirectional {
+            BidirectionalAStar::Coordinates(bound) => {
+                let potential = Rc::new(self.create_coordinate_potential(bound, &graph));
+                self.run_with_graph_alg(graph, |g| {
+                    BiAStar::<OrxBinary, _>::new(g, potential.clone())
+                })
             }
-            Dary::D64 => {
-                let algorithm = OrxPqDecKeyDijkstra::<OrxMapD64>::new(&graph);
-                self.run_with_graph_alg(graph, algorithm)
+            BidirectionalAStar::Landmarks(alt) => {
+                let (landmarks, preprocessing) = Self::create_landmarks(alt, &graph);
+                let landmarks = Rc::new(landmarks);
+                self.run_with_graph_preprocessed_alg(graph, Some(preprocessing), |g| {
+                    BiAStar::<OrxBinary, _>::new(g, landmarks.clone())
+                })
             }
         }
     }
-    fn run_with_graph_alg<E, M, G, A>(&self, graph: G, mut algorithm: A) -> RunAttempt<M, E>
+    fn create_coordinate_potential<G: SpGraph>(
+        &self,
+        bound: &AStarBound,
+        graph: &G,
+    ) -> CoordinatePotential {
+        let coordinates = self
+            .create_coordinates()
+            .expect("must've been handled with run_special");
+        match bound {
+            AStarBound::Euclidean => CoordinatePotential::euclidean(graph, coordinates),
+            AStarBound::GreatCircle => CoordinatePotential::great_circle(graph, coordinates),
+        }
+    }
+    fn create_landmarks<G: SpGraph>(alt: &Alt, graph: &G) -> (Landmarks, Preprocessing) {
+        let k = alt.num_landmarks;
+        Preprocessing::run(
+            || match alt.selection {
+                LandmarkSelection::Random => Landmarks::random(graph, k),
+                LandmarkSelection::Farthest => Landmarks::farthest(graph, k),
+                LandmarkSelection::Avoid => Landmarks::avoid(graph, k),
+            },
+            Landmarks::memory_bytes,
+        )
+    }
+    fn run_with_graph_ch<E, M, G>(&self, graph: G) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        type OrxBinary = DaryHeap<usize, Weight, 2>;
+
+        let (hierarchy, mut preprocessing) = Preprocessing::run(
+            || ContractionHierarchy::new(&graph),
+            ContractionHierarchy::memory_bytes,
+        );
+        preprocessing.stats = PreprocessingStats::Shortcuts(hierarchy.num_shortcuts());
+        let hierarchy = Rc::new(hierarchy);
+        self.run_with_graph_preprocessed_alg(graph, Some(preprocessing), |g| {
+            ChQuery::<OrxBinary>::new(g, hierarchy.clone())
+        })
+    }
+    fn run_with_graph_arc_flags<E, M, G>(
+        &self,
+        arc_flags: &arc_flags::ArcFlags,
+        graph: G,
+    ) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        type OrxBinary = DaryHeap<usize, Weight, 2>;
+
+        let coordinates = match arc_flags.partitioner {
+            Partitioner::Grid => self.create_coordinates(),
+            Partitioner::BfsGrown => None,
+        };
+        let k = self.partition_size.num_cells();
+        let (flags, preprocessing) = Preprocessing::run(
+            || {
+                let partition = match &coordinates {
+                    Some(coordinates) => Partition::grid(coordinates, k),
+                    None => Partition::bfs_grown(&graph, k),
+                };
+                ArcFlags::new(&graph, partition)
+            },
+            ArcFlags::memory_bytes,
+        );
+        let flags = Rc::new(flags);
+        self.run_with_graph_preprocessed_alg(graph, Some(preprocessing), |g| {
+            ArcFlagsDijkstra::<OrxBinary>::new(g, flags.clone())
+        })
+    }
+    fn run_with_graph_hub_labels<E, M, G>(
+        &self,
+        hub_labels: &hub_labels::HubLabels,
+        graph: G,
+    ) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        let (labels, mut preprocessing) = Preprocessing::run(
+            || match hub_labels.ordering {
+                HubOrdering::Degree => HubLabels::by_degree(&graph),
+                HubOrdering::ContractionOrder => HubLabels::by_contraction_order(&graph),
+            },
+            HubLabels::memory_bytes,
+        );
+        let (average, max) = labels.label_sizes();
+        preprocessing.stats = PreprocessingStats::LabelSizes(average, max);
+        let labels = Rc::new(labels);
+        self.run_with_graph_preprocessed_alg(graph, Some(preprocessing), |g| {
+            HubLabelQuery::new(g, labels.clone())
+        })
+    }
+    fn run_with_graph_all_pairs<E, M, G>(&self, all_pairs: &AllPairs, graph: G) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        E: TreatmentMeasure<M = M>,
+    {
+        if graph.num_nodes() > MAX_NUM_NODES_ALL_PAIRS {
+            let err = format!(
+                "(graph, algorithm) mismatch:\nall-pairs distance matrix is limited to graphs with at most {} nodes",
+                MAX_NUM_NODES_ALL_PAIRS
+            );
+            return RunAttempt::NotCompleted(err);
+        }
+
+        let (matrix, mut preprocessing) = Preprocessing::run(
+            || match *all_pairs {
+                AllPairs::FloydWarshall => DistanceMatrix::floyd_warshall(&graph),
+                AllPairs::BlockedFloydWarshall { block_size } => {
+                    DistanceMatrix::blocked_floyd_warshall(&graph, block_size)
+                }
+                AllPairs::Johnson => DistanceMatrix::johnson(&graph),
+            },
+            DistanceMatrix::memory_bytes,
+        );
+        preprocessing.stats = PreprocessingStats::DistanceMatrix;
+        let matrix = Rc::new(matrix);
+        self.run_with_graph_preprocessed_alg(graph, Some(preprocessing), |g| {
+            ApspQuery::new(g, matrix.clone())
+        })
+    }
+    fn run_with_graph_alg<E, M, G, A, F>(&self, graph: G, create_algorithm: F) -> RunAttempt<M, E>
+    where
+        M: Measure,
+        G: SpGraph,
+        A: ShortestDistanceAlgorithm<G>,
+        F: Fn(&G) -> A,
+        E: TreatmentMeasure<M = M>,
+    {
+        self.run_with_graph_preprocessed_alg(graph, None, create_algorithm)
+    }
+    fn run_with_graph_preprocessed_alg<E, M, G, A, F>(
+        &self,
+        graph: G,
+        preprocessing: Option<Preprocessing>,
+        create_algorithm: F,
+    ) -> RunAttempt<M, E>
     where
         M: Measure,
         G: SpGraph,
         A: ShortestDistanceAlgorithm<G>,
+        F: Fn(&G) -> A,
         E: TreatmentMeasure<M = M>,
     {
-        let pairs = self.pairs.create_pairs(graph.num_nodes());
+        let mut algorithm = create_algorithm(&graph);
         let mut exp_measure = E::default();
-        for (source, sink) in pairs {
-            let solution: Solution<M> = match self.algorithm_data {
-                FactorAlgorithmData::Cached => algorithm.run_cached(&graph, source, sink),
-                FactorAlgorithmData::Pure => A::run_pure(&graph, source, sink),
-            };
-            exp_measure.aggregate(solution.measure);
+        exp_measure.aggregate_graph(&graph);
+        if let Some(preprocessing) = preprocessing {
+            exp_measure.aggregate_preprocessing(preprocessing);
+        }
+        match self.queries {
+            FactorQueries::OneToOne(pairs) => {
+                let run = |algorithm: &mut A, source, sink| match pairs.paths {
+                    false => algorithm.run_cached(&graph, source, sink),
+                    true => algorithm.run_cached_path(&graph, source, sink),
+                };
+                for (source, sink) in pairs.create_pairs(graph.num_nodes()) {
+                    let (source, sink) = (self.map_node(source), self.map_node(sink));
+                    let mut solution: Solution<M> = match self.algorithm_data {
+                        FactorAlgorithmData::Cached => run(&mut algorithm, source, sink),
+                        FactorAlgorithmData::Pure => {
+                            run(&mut create_algorithm(&graph), source, sink)
+                        }
+                    };
+                    if let Some(err) = self.not_solved_error::<G, _>(&solution) {
+                        return RunAttempt::NotCompleted(err);
+                    }
+                    self.set_reference(&mut solution.measure);
+                    exp_measure.aggregate(solution.measure);
+                }
+            }
+            FactorQueries::ManyToMany(tables) => {
+                let (sources, targets) = tables.create_sources_and_targets(graph.num_nodes());
+                let (sources, targets) = (self.map_nodes(sources), self.map_nodes(targets));
+                let mut solution: Solution<M> = match self.algorithm_data {
+                    FactorAlgorithmData::Cached => {
+                        algorithm.run_cached_many_to_many(&graph, &sources, &targets)
+                    }
+                    FactorAlgorithmData::Pure => {
+                        create_algorithm(&graph).run_cached_many_to_many(&graph, &sources, &targets)
+                    }
+                };
+                if let Some(err) = self.not_solved_error::<G, _>(&solution) {
+                    return RunAttempt::NotCompleted(err);
+                }
+                self.set_reference(&mut solution.measure);
+                exp_measure.aggregate(solution.measure);
+            }
+            FactorQueries::OneToAll(sources) => {
+                for source in sources.create_sources(graph.num_nodes()) {
+                    let source = self.map_node(source);
+                    let mut solution: Solution<M> = match self.algorithm_data {
+                        FactorAlgorithmData::Cached => {
+                            algorithm.run_cached_one_to_all(&graph, source)
+                        }
+                        FactorAlgorithmData::Pure => {
+                            create_algorithm(&graph).run_cached_one_to_all(&graph, source)
+                        }
+                    };
+                    if let Some(err) = self.not_solved_error::<G, _>(&solution) {
+                        return RunAttempt::NotCompleted(err);
+                    }
+                    self.set_reference(&mut solution.measure);
+                    exp_measure.aggregate(solution.measure);
+                }
+            }
+            FactorQueries::NearestTarget(target_sets) => {
+                for (source, targets) in target_sets.create_queries(graph.num_nodes()) {
+                    let (source, targets) = (self.map_node(source), self.map_nodes(targets));
+                    let mut solution: Solution<M> = match self.algorithm_data {
+                        FactorAlgorithmData::Cached => {
+                            algorithm.run_cached_nearest(&graph, source, &targets)
+                        }
+                        FactorAlgorithmData::Pure => {
+                            create_algorithm(&graph).run_cached_nearest(&graph, source, &targets)
+                        }
+                    };
+                    if let Some(err) = self.not_solved_error::<G, _>(&solution) {
+                        return RunAttempt::NotCompleted(err);
+                    }
+                    self.set_reference(&mut solution.measure);
+                    exp_measure.aggregate(solution.measure);
+                }
+            }
         }
 
         exp_measure.into()
     }
 }
+
+// all-pairs distance matrix of 10_000 nodes requires 800 MB
+const MAX_NUM_NODES_ALL_PAIRS: usize = 10_000;
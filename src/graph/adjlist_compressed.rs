@@ -0,0 +1,236 @@
+use super::{
+    out_edges::{OutEdgeData, OutEdges},
+    out_edges_compressed::{encode_weight, write_varint, zigzag, OutEdgesCompressed},
+    sp_graph::SpGraph,
+    sp_graph_builder::SpGraphBuilder,
+};
+use crate::Weight;
+
+/// Compressed adjacency list: sorted out heads of each node are delta encoded and the weights are stored
+/// in a separate stream, both with variable-length integers of 7 bits per byte.
+/// The out edges of `node` are within `head_offsets[node]..head_offsets[node + 1]` bytes of the heads
+/// and `weight_offsets[node]..weight_offsets[node + 1]` bytes of the weights.
+pub struct AdjListCompressed {
+    heads: Vec<u8>,
+    weights: Vec<u8>,
+    head_offsets: Vec<u32>,
+    weight_offsets: Vec<u32>,
+    num_edges: usize,
+}
+
+impl SpGraph for AdjListCompressed {
+    type W = Weight;
+    type OutEdges<'a> = OutEdgesCompressed<'a>;
+    fn name() -> &'static str {
+        "AdjListCompressed"
+    }
+    fn num_nodes(&self) -> usize {
+        self.head_offsets.len() - 1
+    }
+    fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
+        let heads = self.head_offsets[node] as usize..self.head_offsets[node + 1] as usize;
+        let weights = self.weight_offsets[node] as usize..self.weight_offsets[node + 1] as usize;
+        OutEdgesCompressed::new(node, &self.heads[heads], &self.weights[weights])
+    }
+    fn num_edges(&self) -> usize {
+        self.num_edges
+    }
+    fn memory_bytes(&self) -> Option<usize> {
+        Some(
+            self.heads.len()
+                + self.weights.len()
+                + (self.head_offsets.len() + self.weight_offsets.len())
+                    * std::mem::size_of::<u32>(),
+        )
+    }
+}
+
+impl AdjListCompressed {
+    fn with_capacity(nodes_capacity: Option<usize>, edges_capacity: Option<usize>) -> Self {
+        let nodes_capacity = nodes_capacity.unwrap_or(0) + 1;
+        let edges_capacity = edges_capacity.unwrap_or(0);
+        let mut head_offsets = Vec::with_capacity(nodes_capacity);
+        let mut weight_offsets = Vec::with_capacity(nodes_capacity);
+        head_offsets.push(0);
+        weight_offsets.push(0);
+        Self {
+            heads: Vec::with_capacity(edges_capacity),
+            weights: Vec::with_capacity(edges_capacity),
+            head_offsets,
+            weight_offsets,
+            num_edges: 0,
+        }
+    }
+    // encodes the out edges of the next node, which is `tail`
+    fn push_node(&mut self, tail: usize, out_edges: &mut [(usize, Weight)]) {
+        out_edges.sort_unstable();
+        let mut previous_head = None;
+        for &(head, weight) in out_edges.iter() {
+            let delta = match previous_head {
+                Some(previous) => (head - previous) as u64,
+                None => zigzag(head as i64 - tail as i64),
+            };
+            write_varint(&mut self.heads, delta);
+            write_varint(&mut self.weights, encode_weight(weight));
+            previous_head = Some(head);
+        }
+        self.num_edges += out_edges.len();
+
+        assert!(
+            self.heads.len() <= u32::MAX as usize && self.weights.len() <= u32::MAX as usize,
+            "AdjListCompressed requires the encoded heads & weights to fit in u32 bytes"
+        );
+        self.head_offsets.push(self.heads.len() as u32);
+        self.weight_offsets.push(self.weights.len() as u32);
+    }
+}
+
+/// Encodes the out edges of a node as soon as an edge of a later tail is added; hence, the uncompressed graph
+/// is never materialized when the edges are added in ascending order of their tails as the graph data do.
+///
+/// Edges added after their tail has been encoded are kept aside and merged into the byte streams by `build`.
+pub struct AdjListCompressedBuilder {
+    graph: AdjListCompressed,
+    num_nodes: usize,
+    // nodes before `tail` are encoded; out edges of `tail` are pending
+    tail: usize,
+    pending: Vec<(usize, Weight)>,
+    late: Vec<(usize, usize, Weight)>,
+}
+impl AdjListCompressedBuilder {
+    fn encode_until(&mut self, tail: usize) {
+        while self.tail < tail {
+            self.graph.push_node(self.tail, &mut self.pending);
+            self.pending.clear();
+            self.tail += 1;
+        }
+    }
+}
+impl SpGraphBuilder for AdjListCompressedBuilder {
+    type G = AdjListCompressed;
+
+    fn new(nodes_capacity: Option<usize>, edges_capacity: Option<usize>) -> Self {
+        Self {
+            graph: AdjListCompressed::with_capacity(nodes_capacity, edges_capacity),
+            num_nodes: 0,
+            tail: 0,
+            pending: vec![],
+            late: vec![],
+        }
+    }
+    fn add_node(&mut self, node: usize, _: Option<usize>) {
+        self.num_nodes = self.num_nodes.max(node + 1);
+    }
+    fn add_edge(&mut self, tail: usize, head: usize, weight: Weight) {
+        self.num_nodes = self.num_nodes.max(tail + 1);
+        match tail < self.tail {
+            true => self.late.push((tail, head, weight)),
+            false => {
+                self.encode_until(tail);
+                self.pending.push((head, weight));
+            }
+        }
+    }
+    fn build(mut self) -> Self::G {
+        self.encode_until(self.num_nodes);
+        let mut graph = self.graph;
+
+        if !self.late.is_empty() {
+            self.late.sort_unstable();
+            let mut late = self.late.iter().peekable();
+            let mut merged = AdjListCompressed::with_capacity(
+                Some(graph.num_nodes()),
+                Some(graph.heads.len() + self.late.len()),
+            );
+            let mut out_edges = vec![];
+            for tail in 0..graph.num_nodes() {
+                out_edges.clear();
+                let mut encoded = graph.out_edges(tail);
+                while let Some(edge) = encoded.next_edge() {
+                    out_edges.push((edge.head(), edge.weight()));
+                }
+                while let Some((_, head, weight)) = late.next_if(|x| x.0 == tail) {
+                    out_edges.push((*head, *weight));
+                }
+                merged.push_node(tail, &mut out_edges);
+            }
+            graph = merged;
+        }
+
+        graph.heads.shrink_to_fit();
+        graph.weights.shrink_to_fit();
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::test_utils::{assert_same_graphs, random_graph, weights},
+        graph::adjlist_jagged_vec::AdjListJaggedVec,
+    };
+    use proptest::prelude::*;
+    use rand::{seq::SliceRandom, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    // out edges of the compressed graph are sorted by head & weight
+    fn sorted(mut graph: AdjListJaggedVec) -> AdjListJaggedVec {
+        for out_edges in &mut graph {
+            out_edges.sort_by_key(|edge| (edge.head(), edge.weight()));
+        }
+        graph
+    }
+
+    fn compressed(num_nodes: usize, edges: &[(usize, usize, Weight)]) -> AdjListCompressed {
+        let mut builder = AdjListCompressedBuilder::new(Some(num_nodes), None);
+        (0..num_nodes).for_each(|node| builder.add_node(node, None));
+        for &(tail, head, weight) in edges {
+            builder.add_edge(tail, head, weight);
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn late_edges_are_merged() {
+        let edges = [
+            (1, 3, 5),
+            (3, 0, 1 << 40),
+            (0, 2, 2),
+            (1, 0, 4),
+            (3, 3, 0),
+            (1, 3, 5),
+        ];
+        let graph = compressed(5, &edges);
+
+        let mut expected = <AdjListJaggedVec as SpGraphBuilder>::new(Some(5), None);
+        (0..5).for_each(|node| expected.add_node(node, None));
+        for (tail, head, weight) in edges {
+            expected.add_edge(tail, head, weight);
+        }
+        assert_same_graphs(&sorted(expected.build()), &graph, 0);
+    }
+
+    proptest! {
+        #[test]
+        fn compressed_matches_jagged_vec(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+            shuffle in any::<bool>(),
+        ) {
+            let expected = sorted(random_graph(seed, num_nodes, density, weights));
+            let mut edges = vec![];
+            for (tail, out_edges) in expected.iter().enumerate() {
+                edges.extend(out_edges.iter().map(|edge| (tail, edge.head(), edge.weight())));
+            }
+            // edges in random order of their tails are mostly late & merged by build
+            if shuffle {
+                edges.shuffle(&mut ChaCha8Rng::seed_from_u64(queries_seed));
+            }
+            assert_same_graphs(&expected, &compressed(num_nodes, &edges), queries_seed);
+        }
+    }
+}
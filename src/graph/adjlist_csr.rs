@@ -0,0 +1,151 @@
+use super::{
+    adjlist_jagged_vec::AdjListJaggedVec,
+    out_edges::OutEdgeData,
+    out_edges_csr::{CsrWeight, CsrWeight32, OutEdgesCsr},
+    sp_graph::SpGraph,
+    sp_graph_builder::SpGraphBuilder,
+};
+use crate::Weight;
+
+/// Compressed sparse row graph with the structure-of-arrays layout: heads & weights of the edges are
+/// stored in separate arrays, and the out edges of `node` are within `offsets[node]..offsets[node + 1]`.
+///
+/// Heads & offsets are 32-bit; weights are 32-bit by default, or 64-bit with `CsrWeight64`.
+pub struct AdjListCsr<C: CsrWeight = CsrWeight32> {
+    heads: Vec<u32>,
+    weights: Vec<C>,
+    offsets: Vec<u32>,
+}
+
+impl<C: CsrWeight> SpGraph for AdjListCsr<C> {
+    type W = Weight;
+    type OutEdges<'a> = OutEdgesCsr<'a, C>;
+    fn name() -> &'static str {
+        C::GRAPH_NAME
+    }
+    fn num_nodes(&self) -> usize {
+        self.offsets.len() - 1
+    }
+    fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
+        let begin = self.offsets[node] as usize;
+        let end = self.offsets[node + 1] as usize;
+        OutEdgesCsr::new(&self.heads[begin..end], &self.weights[begin..end])
+    }
+    fn num_edges(&self) -> usize {
+        self.heads.len()
+    }
+    fn memory_bytes(&self) -> Option<usize> {
+        Some(
+            self.heads.len() * std::mem::size_of::<u32>()
+                + self.weights.len() * std::mem::size_of::<C>()
+                + self.offsets.len() * std::mem::size_of::<u32>(),
+        )
+    }
+}
+
+pub struct AdjListCsrBuilder<C: CsrWeight = CsrWeight32>(
+    AdjListJaggedVec,
+    std::marker::PhantomData<C>,
+);
+impl<C: CsrWeight> SpGraphBuilder for AdjListCsrBuilder<C> {
+    type G = AdjListCsr<C>;
+
+    fn new(nodes_capacity: Option<usize>, edges_capacity: Option<usize>) -> Self {
+        Self(
+            <AdjListJaggedVec as SpGraphBuilder>::new(nodes_capacity, edges_capacity),
+            Default::default(),
+        )
+    }
+    fn add_node(&mut self, node: usize, out_degree_capacity: Option<usize>) {
+        self.0.add_node(node, out_degree_capacity)
+    }
+    fn add_edge(&mut self, tail: usize, head: usize, weight: Weight) {
+        self.0.add_edge(tail, head, weight)
+    }
+    fn validate(&self) -> Result<(), String> {
+        let fits = (self.0.iter().flatten()).all(|edge| C::try_from(edge.weight()).is_ok());
+        match fits {
+            true => Ok(()),
+            false => Err(format!(
+                "(graph, graph representation) mismatch:\n{} stores {}-bit weights; use AdjListCsr64 for graphs with larger weights",
+                C::GRAPH_NAME,
+                std::mem::size_of::<C>() * 8
+            )),
+        }
+    }
+    fn build(self) -> Self::G {
+        let num_edges = self.0.iter().map(|x| x.len()).sum();
+        assert!(
+            self.0.len() <= u32::MAX as usize && num_edges <= u32::MAX as usize,
+            "AdjListCsr requires the numbers of nodes & edges to fit in u32"
+        );
+
+        let mut offsets = Vec::with_capacity(self.0.len() + 1);
+        let mut heads = Vec::with_capacity(num_edges);
+        let mut weights = Vec::with_capacity(num_edges);
+        offsets.push(0);
+        for out_edges in &self.0 {
+            for edge in out_edges {
+                heads.push(edge.head() as u32);
+                let weight = C::try_from(edge.weight());
+                weights
+                    .push(weight.unwrap_or_else(|_| panic!("must've been handled with validate")));
+            }
+            offsets.push(heads.len() as u32);
+        }
+        Self::G {
+            heads,
+            weights,
+            offsets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::test_utils::{assert_same_graphs, random_graph, random_graph_builder, weights},
+        graph::{out_edges::OutEdges, out_edges_csr::CsrWeight64},
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn validate_rejects_weights_beyond_32_bits() {
+        let builder = |weight: Weight| {
+            let mut builder = <AdjListCsrBuilder<CsrWeight32> as SpGraphBuilder>::new(None, None);
+            (0..2).for_each(|node| builder.add_node(node, None));
+            builder.add_edge(0, 1, weight);
+            builder
+        };
+        let max = CsrWeight32::MAX as Weight;
+        assert!(builder(max).validate().is_ok());
+        assert!(builder(max + 1).validate().is_err());
+
+        let mut builder = <AdjListCsrBuilder<CsrWeight64> as SpGraphBuilder>::new(None, None);
+        (0..2).for_each(|node| builder.add_node(node, None));
+        builder.add_edge(0, 1, max + 1);
+        assert!(builder.validate().is_ok());
+        assert_eq!(
+            builder.build().out_edges(0).next_edge().map(|e| e.weight()),
+            Some(max + 1)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn csr_matches_jagged_vec(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+        ) {
+            let expected = random_graph(seed, num_nodes, density, weights);
+            let csr = random_graph_builder::<AdjListCsrBuilder>(seed, num_nodes, density, weights).build();
+            assert_same_graphs(&expected, &csr, queries_seed);
+            let csr64 = random_graph_builder::<AdjListCsrBuilder<CsrWeight64>>(seed, num_nodes, density, weights).build();
+            assert_same_graphs(&expected, &csr64, queries_seed);
+        }
+    }
+}
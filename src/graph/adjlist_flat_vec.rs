@@ -3,16 +3,18 @@ use super::{
     out_edges_std_vec::{OutEdge, OutEdgesStdVec},
     sp_graph::SpGraph,
     sp_graph_builder::SpGraphBuilder,
+    weight_type::WeightType,
 };
 use crate::Weight;
 
-pub struct AdjListFlatVec {
-    edges: Vec<OutEdge>,
+pub struct AdjListFlatVec<W = Weight> {
+    edges: Vec<OutEdge<W>>,
     out_edges_begin_index: Vec<usize>,
 }
 
-impl SpGraph for AdjListFlatVec {
-    type OutEdges<'a> = OutEdgesStdVec<'a>;
+impl<W: WeightType> SpGraph for AdjListFlatVec<W> {
+    type W = W;
+    type OutEdges<'a> = OutEdgesStdVec<'a, W>;
     fn name() -> &'static str {
         "AdjListFlatVec"
     }
@@ -24,14 +26,20 @@ impl SpGraph for AdjListFlatVec {
         let end = self.out_edges_begin_index[node + 1];
         self.edges[begin..end].iter()
     }
+    fn memory_bytes(&self) -> Option<usize> {
+        Some(
+            self.edges.len() * std::mem::size_of::<OutEdge<W>>()
+                + self.out_edges_begin_index.len() * std::mem::size_of::<usize>(),
+        )
+    }
 }
 
-pub struct AdjListFlatVecBuilder(AdjListJaggedVec);
-impl SpGraphBuilder for AdjListFlatVecBuilder {
-    type G = AdjListFlatVec;
+pub struct AdjListFlatVecBuilder<W = Weight>(AdjListJaggedVec<W>);
+impl<W: WeightType> SpGraphBuilder for AdjListFlatVecBuilder<W> {
+    type G = AdjListFlatVec<W>;
 
     fn new(nodes_capacity: Option<usize>, edges_capacity: Option<usize>) -> Self {
-        Self(<AdjListJaggedVec as SpGraphBuilder>::new(
+        Self(<AdjListJaggedVec<W> as SpGraphBuilder>::new(
             nodes_capacity,
             edges_capacity,
         ))
@@ -2,13 +2,14 @@ use super::{
     out_edges_std_vec::{OutEdge, OutEdgesStdVec},
     sp_graph::SpGraph,
     sp_graph_builder::SpGraphBuilder,
+    weight_type::WeightType,
 };
 use crate::Weight;
 
-pub type AdjListJaggedVec = Vec<Vec<OutEdge>>;
+pub type AdjListJaggedVec<W = Weight> = Vec<Vec<OutEdge<W>>>;
 
-impl SpGraphBuilder for AdjListJaggedVec {
-    type G = AdjListJaggedVec;
+impl<W: WeightType> SpGraphBuilder for AdjListJaggedVec<W> {
+    type G = AdjListJaggedVec<W>;
 
     fn new(nodes_capacity: Option<usize>, _edges_capacity: Option<usize>) -> Self {
         match nodes_capacity {
@@ -24,6 +25,8 @@ impl SpGraphBuilder for AdjListJaggedVec {
         });
     }
     fn add_edge(&mut self, tail: usize, head: usize, weight: Weight) {
+        let weight = W::try_from_weight(weight)
+            .unwrap_or_else(|| panic!("must've been handled with run_special"));
         self[tail].push(OutEdge::new(head, weight));
     }
     fn build(self) -> Self::G {
@@ -31,8 +34,9 @@ impl SpGraphBuilder for AdjListJaggedVec {
     }
 }
 
-impl SpGraph for AdjListJaggedVec {
-    type OutEdges<'a> = OutEdgesStdVec<'a>;
+impl<W: WeightType> SpGraph for AdjListJaggedVec<W> {
+    type W = W;
+    type OutEdges<'a> = OutEdgesStdVec<'a, W>;
 
     // type
     fn name() -> &'static str {
@@ -46,4 +50,11 @@ impl SpGraph for AdjListJaggedVec {
     fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
         self[node].iter()
     }
+    fn memory_bytes(&self) -> Option<usize> {
+        Some(
+            self.len() * std::mem::size_of::<Vec<OutEdge<W>>>()
+                + self.iter().map(|x| x.capacity()).sum::<usize>()
+                    * std::mem::size_of::<OutEdge<W>>(),
+        )
+    }
 }
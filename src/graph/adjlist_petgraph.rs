@@ -1,5 +1,6 @@
 use super::{
-    out_edges_petgraph::OutEdgesPetgraph, sp_graph::SpGraph, sp_graph_builder::SpGraphBuilder,
+    out_edges_petgraph::OutEdgesPetgraph, petgraph_graph::PetgraphGraph, sp_graph::SpGraph,
+    sp_graph_builder::SpGraphBuilder,
 };
 use crate::Weight;
 use petgraph::graph::NodeIndex;
@@ -25,6 +26,7 @@ impl SpGraphBuilder for AdjListPetgraph {
 }
 
 impl SpGraph for AdjListPetgraph {
+    type W = Weight;
     type OutEdges<'a> = OutEdgesPetgraph<'a>;
 
     // type
@@ -39,6 +41,12 @@ impl SpGraph for AdjListPetgraph {
     fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
         self.edges(NodeIndex::new(node))
     }
+    fn memory_bytes(&self) -> Option<usize> {
+        Some(
+            self.node_count() * std::mem::size_of::<petgraph::graph::Node<()>>()
+                + self.edge_count() * std::mem::size_of::<petgraph::graph::Edge<Weight>>(),
+        )
+    }
 
     // build
     // fn new(nodes_capacity: Option<usize>, edges_capacity: Option<usize>) -> Self {
@@ -52,3 +60,19 @@ impl SpGraph for AdjListPetgraph {
     //     self.add_edge(NodeIndex::new(tail), NodeIndex::new(head), weight);
     // }
 }
+
+impl PetgraphGraph for AdjListPetgraph {
+    type Petgraph<'a> = &'a Self;
+    type FloatWeighted = petgraph::graph::Graph<(), f64, petgraph::Directed>;
+    type FloatPetgraph<'a> = &'a Self::FloatWeighted;
+
+    fn petgraph(&self) -> Self::Petgraph<'_> {
+        self
+    }
+    fn float_weighted(&self) -> Self::FloatWeighted {
+        self.map(|_, _| (), |_, &weight| weight as f64)
+    }
+    fn float_petgraph(graph: &Self::FloatWeighted) -> Self::FloatPetgraph<'_> {
+        graph
+    }
+}
@@ -0,0 +1,93 @@
+use super::{
+    out_edges_petgraph::OutEdgesPetgraphCsr, petgraph_graph::PetgraphGraph, sp_graph::SpGraph,
+    sp_graph_builder::SpGraphBuilder,
+};
+use crate::Weight;
+use petgraph::{
+    csr::{Csr, NodeIndex},
+    visit::EdgeRef,
+};
+
+/// `petgraph::csr::Csr` does not allow parallel edges; only the edge with the smallest weight is kept.
+pub type AdjListPetgraphCsr = Csr<(), Weight, petgraph::Directed>;
+
+pub struct AdjListPetgraphCsrBuilder {
+    num_nodes: usize,
+    edges: Vec<(NodeIndex, NodeIndex, Weight)>,
+}
+impl SpGraphBuilder for AdjListPetgraphCsrBuilder {
+    type G = AdjListPetgraphCsr;
+
+    fn new(_: Option<usize>, edges_capacity: Option<usize>) -> Self {
+        Self {
+            num_nodes: 0,
+            edges: Vec::with_capacity(edges_capacity.unwrap_or(0)),
+        }
+    }
+    fn add_node(&mut self, node: usize, _: Option<usize>) {
+        assert_eq!(node, self.num_nodes);
+        self.num_nodes += 1;
+    }
+    fn add_edge(&mut self, tail: usize, head: usize, weight: Weight) {
+        self.edges
+            .push((tail as NodeIndex, head as NodeIndex, weight));
+    }
+    fn build(mut self) -> Self::G {
+        self.edges.sort_unstable();
+        self.edges.dedup_by_key(|(tail, head, _)| (*tail, *head));
+        from_sorted_edges(&self.edges, self.num_nodes)
+    }
+}
+
+impl SpGraph for AdjListPetgraphCsr {
+    type W = Weight;
+    type OutEdges<'a> = OutEdgesPetgraphCsr<'a>;
+    fn name() -> &'static str {
+        "AdjListPetgraphCsr"
+    }
+    fn num_nodes(&self) -> usize {
+        self.node_count()
+    }
+    fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
+        self.edges(node as NodeIndex)
+    }
+    fn memory_bytes(&self) -> Option<usize> {
+        // column indices, edge weights & row offsets of the csr
+        Some(
+            self.edge_count() * (std::mem::size_of::<NodeIndex>() + std::mem::size_of::<Weight>())
+                + (self.node_count() + 1) * std::mem::size_of::<usize>(),
+        )
+    }
+}
+
+impl PetgraphGraph for AdjListPetgraphCsr {
+    type Petgraph<'a> = &'a Self;
+    type FloatWeighted = Csr<(), f64, petgraph::Directed>;
+    type FloatPetgraph<'a> = &'a Self::FloatWeighted;
+
+    fn petgraph(&self) -> Self::Petgraph<'_> {
+        self
+    }
+    fn float_weighted(&self) -> Self::FloatWeighted {
+        let edges: Vec<_> = (0..self.node_count())
+            .flat_map(|tail| self.edges(tail as NodeIndex))
+            .map(|e| (e.source(), e.target(), *e.weight() as f64))
+            .collect();
+        from_sorted_edges(&edges, self.node_count())
+    }
+    fn float_petgraph(graph: &Self::FloatWeighted) -> Self::FloatPetgraph<'_> {
+        graph
+    }
+}
+
+// nodes without out edges beyond the largest tail or head are appended
+fn from_sorted_edges<E: Copy>(
+    edges: &[(NodeIndex, NodeIndex, E)],
+    num_nodes: usize,
+) -> Csr<(), E, petgraph::Directed> {
+    let mut graph = Csr::from_sorted_edges(edges).expect("edges are sorted & unique");
+    while graph.node_count() < num_nodes {
+        graph.add_node(());
+    }
+    graph
+}
@@ -0,0 +1,92 @@
+use super::{
+    out_edges_petgraph::OutEdgesPetgraphMap, petgraph_graph::PetgraphGraph, sp_graph::SpGraph,
+    sp_graph_builder::SpGraphBuilder,
+};
+use crate::Weight;
+use petgraph::graphmap::DiGraphMap;
+
+/// Graph keyed by the node indices; nodes are added in increasing order so that the petgraph index of a node is the node itself.
+///
+/// `GraphMap` does not allow parallel edges; only the edge with the smallest weight would be kept.
+/// Since the graph would then differ from the other representations, graphs with parallel edges are rejected,
+/// see `AdjListPetgraphMapBuilder`.
+pub type AdjListPetgraphMap = DiGraphMap<usize, Weight>;
+
+/// Builds the `AdjListPetgraphMap` while noting whether an edge was added from a tail to a head more than once.
+pub struct AdjListPetgraphMapBuilder {
+    graph: AdjListPetgraphMap,
+    has_parallel_edges: bool,
+}
+
+impl SpGraphBuilder for AdjListPetgraphMapBuilder {
+    type G = AdjListPetgraphMap;
+
+    fn new(nodes_capacity: Option<usize>, edges_capacity: Option<usize>) -> Self {
+        Self {
+            graph: AdjListPetgraphMap::with_capacity(
+                nodes_capacity.unwrap_or(0),
+                edges_capacity.unwrap_or(0),
+            ),
+            has_parallel_edges: false,
+        }
+    }
+    fn add_node(&mut self, node: usize, _: Option<usize>) {
+        assert_eq!(node, self.graph.node_count());
+        self.graph.add_node(node);
+    }
+    fn add_edge(&mut self, tail: usize, head: usize, weight: Weight) {
+        match self.graph.edge_weight_mut(tail, head) {
+            Some(existing) => {
+                *existing = weight.min(*existing);
+                self.has_parallel_edges = true;
+            }
+            None => _ = self.graph.add_edge(tail, head, weight),
+        }
+    }
+    fn validate(&self) -> Result<(), String> {
+        match self.has_parallel_edges {
+            false => Ok(()),
+            true => Err(String::from("(graph, graph representation) mismatch:\npetgraph GraphMap merges parallel edges; hence, AdjListPetgraphMap is not available for graphs with parallel edges")),
+        }
+    }
+    fn build(self) -> Self::G {
+        self.graph
+    }
+}
+
+impl SpGraph for AdjListPetgraphMap {
+    type W = Weight;
+    type OutEdges<'a> = OutEdgesPetgraphMap<'a>;
+    fn name() -> &'static str {
+        "AdjListPetgraphMap"
+    }
+    fn num_nodes(&self) -> usize {
+        self.node_count()
+    }
+    fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
+        self.edges(node)
+    }
+}
+
+impl PetgraphGraph for AdjListPetgraphMap {
+    type Petgraph<'a> = &'a Self;
+    type FloatWeighted = DiGraphMap<usize, f64>;
+    type FloatPetgraph<'a> = &'a Self::FloatWeighted;
+
+    fn petgraph(&self) -> Self::Petgraph<'_> {
+        self
+    }
+    fn float_weighted(&self) -> Self::FloatWeighted {
+        let mut graph = DiGraphMap::with_capacity(self.node_count(), self.edge_count());
+        for node in self.nodes() {
+            graph.add_node(node);
+        }
+        for (tail, head, &weight) in self.all_edges() {
+            graph.add_edge(tail, head, weight as f64);
+        }
+        graph
+    }
+    fn float_petgraph(graph: &Self::FloatWeighted) -> Self::FloatPetgraph<'_> {
+        graph
+    }
+}
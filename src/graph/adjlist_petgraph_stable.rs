@@ -0,0 +1,64 @@
+use super::{
+    out_edges_petgraph::OutEdgesPetgraphStable, petgraph_graph::PetgraphGraph, sp_graph::SpGraph,
+    sp_graph_builder::SpGraphBuilder,
+};
+use crate::Weight;
+use petgraph::stable_graph::{NodeIndex, StableGraph};
+
+pub type AdjListPetgraphStable = StableGraph<(), Weight, petgraph::Directed>;
+
+impl SpGraphBuilder for AdjListPetgraphStable {
+    type G = AdjListPetgraphStable;
+
+    fn new(nodes_capacity: Option<usize>, edges_capacity: Option<usize>) -> Self {
+        Self::with_capacity(nodes_capacity.unwrap_or(0), edges_capacity.unwrap_or(0))
+    }
+    fn add_node(&mut self, node: usize, _: Option<usize>) {
+        let idx = self.add_node(());
+        assert_eq!(node, idx.index());
+    }
+    fn add_edge(&mut self, tail: usize, head: usize, weight: Weight) {
+        self.add_edge(NodeIndex::new(tail), NodeIndex::new(head), weight);
+    }
+    fn build(self) -> Self::G {
+        self
+    }
+}
+
+impl SpGraph for AdjListPetgraphStable {
+    type W = Weight;
+    type OutEdges<'a> = OutEdgesPetgraphStable<'a>;
+    fn name() -> &'static str {
+        "AdjListPetgraphStable"
+    }
+    fn num_nodes(&self) -> usize {
+        self.node_count()
+    }
+    fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
+        self.edges(NodeIndex::new(node))
+    }
+    fn memory_bytes(&self) -> Option<usize> {
+        // vacant slots of removed nodes & edges are kept; hence, the capacities
+        let (nodes_capacity, edges_capacity) = self.capacity();
+        Some(
+            nodes_capacity * std::mem::size_of::<petgraph::graph::Node<Option<()>>>()
+                + edges_capacity * std::mem::size_of::<petgraph::graph::Edge<Option<Weight>>>(),
+        )
+    }
+}
+
+impl PetgraphGraph for AdjListPetgraphStable {
+    type Petgraph<'a> = &'a Self;
+    type FloatWeighted = StableGraph<(), f64, petgraph::Directed>;
+    type FloatPetgraph<'a> = &'a Self::FloatWeighted;
+
+    fn petgraph(&self) -> Self::Petgraph<'_> {
+        self
+    }
+    fn float_weighted(&self) -> Self::FloatWeighted {
+        self.map(|_, _| (), |_, &weight| weight as f64)
+    }
+    fn float_petgraph(graph: &Self::FloatWeighted) -> Self::FloatPetgraph<'_> {
+        graph
+    }
+}
@@ -0,0 +1,41 @@
+/// Geographical position of a node; `longitude` and `latitude` are in degrees.
+#[derive(Clone, Copy, Debug, Default, derive_new::new)]
+pub struct Point {
+    pub longitude: f64,
+    pub latitude: f64,
+}
+
+impl Point {
+    /// Straight line distance treating (longitude, latitude) as planar coordinates.
+    pub fn euclidean_distance(&self, other: &Point) -> f64 {
+        let dx = self.longitude - other.longitude;
+        let dy = self.latitude - other.latitude;
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    /// Central angle in radians between the two points on a sphere (haversine formula).
+    pub fn great_circle_distance(&self, other: &Point) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * a.sqrt().min(1.0).asin()
+    }
+}
+
+/// Positions of the nodes of a graph such that `points[i]` is the position of the i-th node.
+pub struct Coordinates {
+    points: Vec<Point>,
+}
+
+impl Coordinates {
+    pub fn new(points: Vec<Point>) -> Self {
+        Self { points }
+    }
+    pub fn num_nodes(&self) -> usize {
+        self.points.len()
+    }
+    pub fn point(&self, node: usize) -> &Point {
+        &self.points[node]
+    }
+}
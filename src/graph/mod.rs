@@ -1,8 +1,27 @@
+pub mod adjlist_compressed;
+pub mod adjlist_csr;
 pub mod adjlist_flat_vec;
 pub mod adjlist_jagged_vec;
 pub mod adjlist_petgraph;
+pub mod adjlist_petgraph_csr;
+pub mod adjlist_petgraph_map;
+pub mod adjlist_petgraph_stable;
+pub mod coordinates;
+pub mod node_permutation;
 pub mod out_edges;
+pub mod out_edges_compressed;
+pub mod out_edges_csr;
 pub mod out_edges_petgraph;
 pub mod out_edges_std_vec;
+pub mod petgraph_graph;
+pub mod resource_adjlist;
+pub mod resource_graph;
+pub mod resource_graph_builder;
+pub mod reversed_graph;
 pub mod sp_graph;
 pub mod sp_graph_builder;
+pub mod td_adjlist;
+pub mod td_graph;
+pub mod td_graph_builder;
+pub mod travel_time;
+pub mod weight_type;
@@ -0,0 +1,301 @@
+use super::{
+    coordinates::{Coordinates, Point},
+    out_edges::{OutEdgeData, OutEdges},
+    sp_graph::SpGraph,
+    sp_graph_builder::SpGraphBuilder,
+};
+use std::collections::VecDeque;
+
+/// Renumbering of the nodes of a graph such that the node `old` of the original graph is the node `new_of_old[old]`
+/// of the reordered graph.
+///
+/// Orders aim at cache locality: nodes that are close to each other in the graph are assigned close ids,
+/// so that their out edges & algorithm data are likely to be on the same cache lines.
+/// Traversal based orders ignore the directions of the edges and visit every connected component.
+#[derive(Clone)]
+pub struct NodePermutation {
+    new_of_old: Vec<usize>,
+    old_of_new: Vec<usize>,
+}
+
+impl NodePermutation {
+    pub fn identity(num_nodes: usize) -> Self {
+        Self {
+            new_of_old: (0..num_nodes).collect(),
+            old_of_new: (0..num_nodes).collect(),
+        }
+    }
+    /// Breadth-first traversal order.
+    pub fn bfs<G: SpGraph>(graph: &G) -> Self {
+        let neighbors = undirected_neighbors(graph);
+        let mut order = Vec::with_capacity(neighbors.len());
+        let mut visited = vec![false; neighbors.len()];
+        let mut queue = VecDeque::new();
+        for root in 0..neighbors.len() {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            queue.push_back(root);
+            while let Some(node) = queue.pop_front() {
+                order.push(node);
+                for &neighbor in &neighbors[node] {
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        Self::from_order(&order)
+    }
+    /// Preorder of the depth-first traversal.
+    pub fn dfs<G: SpGraph>(graph: &G) -> Self {
+        let neighbors = undirected_neighbors(graph);
+        let mut order = Vec::with_capacity(neighbors.len());
+        let mut visited = vec![false; neighbors.len()];
+        let mut stack = vec![];
+        for root in 0..neighbors.len() {
+            stack.push(root);
+            while let Some(node) = stack.pop() {
+                if visited[node] {
+                    continue;
+                }
+                visited[node] = true;
+                order.push(node);
+                // reversed so that the neighbors are visited in their adjacency order
+                for &neighbor in neighbors[node].iter().rev() {
+                    if !visited[neighbor] {
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
+        Self::from_order(&order)
+    }
+    /// Reverse Cuthill-McKee order: breadth-first traversal from a minimum degree node of each component
+    /// visiting the neighbors in increasing degrees; the resulting order is reversed.
+    pub fn reverse_cuthill_mckee<G: SpGraph>(graph: &G) -> Self {
+        let mut neighbors = undirected_neighbors(graph);
+        let degrees: Vec<_> = neighbors.iter().map(|x| x.len()).collect();
+        for node_neighbors in &mut neighbors {
+            node_neighbors.sort_by_key(|&x| degrees[x]);
+        }
+
+        let mut roots: Vec<_> = (0..neighbors.len()).collect();
+        roots.sort_by_key(|&x| degrees[x]);
+
+        let mut order = Vec::with_capacity(neighbors.len());
+        let mut visited = vec![false; neighbors.len()];
+        let mut queue = VecDeque::new();
+        for root in roots {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            queue.push_back(root);
+            while let Some(node) = queue.pop_front() {
+                order.push(node);
+                for &neighbor in &neighbors[node] {
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        order.reverse();
+        Self::from_order(&order)
+    }
+    /// Order of the nodes along the Hilbert curve over the bounding box of the coordinates.
+    pub fn hilbert(coordinates: &Coordinates) -> Self {
+        let num_nodes = coordinates.num_nodes();
+        let points = (0..num_nodes).map(|i| coordinates.point(i));
+        let (mut min, mut max) = (
+            Point::new(f64::MAX, f64::MAX),
+            Point::new(f64::MIN, f64::MIN),
+        );
+        for p in points.clone() {
+            min.longitude = min.longitude.min(p.longitude);
+            min.latitude = min.latitude.min(p.latitude);
+            max.longitude = max.longitude.max(p.longitude);
+            max.latitude = max.latitude.max(p.latitude);
+        }
+
+        let cell = |value: f64, min: f64, max: f64| match max > min {
+            true => ((value - min) / (max - min) * (HILBERT_SIDE - 1) as f64) as u64,
+            false => 0,
+        };
+        let keys: Vec<_> = points
+            .map(|p| {
+                let x = cell(p.longitude, min.longitude, max.longitude);
+                let y = cell(p.latitude, min.latitude, max.latitude);
+                hilbert_index(x, y)
+            })
+            .collect();
+
+        let mut order: Vec<_> = (0..num_nodes).collect();
+        order.sort_by_key(|&x| keys[x]);
+        Self::from_order(&order)
+    }
+
+    // map
+    pub fn num_nodes(&self) -> usize {
+        self.new_of_old.len()
+    }
+    #[inline(always)]
+    pub fn new_of_old(&self, old: usize) -> usize {
+        self.new_of_old[old]
+    }
+    #[inline(always)]
+    pub fn old_of_new(&self, new: usize) -> usize {
+        self.old_of_new[new]
+    }
+    pub fn reorder_graph<G: SpGraph, B: SpGraphBuilder>(&self, graph: &G) -> B {
+        let num_nodes = self.num_nodes();
+        let mut builder = B::new(Some(num_nodes), Some(graph.num_edges()));
+        for (new, &old) in self.old_of_new.iter().enumerate() {
+            builder.add_node(new, Some(graph.out_edges(old).count_edges()));
+        }
+        for (new, &old) in self.old_of_new.iter().enumerate() {
+            let mut out_edges = graph.out_edges(old);
+            while let Some(edge) = out_edges.next_edge() {
+                builder.add_edge(new, self.new_of_old[edge.head()], edge.weight());
+            }
+        }
+        builder
+    }
+    pub fn reorder_coordinates(&self, coordinates: &Coordinates) -> Coordinates {
+        let mut points = vec![Point::default(); self.num_nodes()];
+        for (old, &new) in self.new_of_old.iter().enumerate() {
+            points[new] = *coordinates.point(old);
+        }
+        Coordinates::new(points)
+    }
+
+    // helpers
+    fn from_order(order: &[usize]) -> Self {
+        let mut new_of_old = vec![0; order.len()];
+        for (new, &old) in order.iter().enumerate() {
+            new_of_old[old] = new;
+        }
+        Self {
+            new_of_old,
+            old_of_new: order.to_vec(),
+        }
+    }
+}
+
+fn undirected_neighbors<G: SpGraph>(graph: &G) -> Vec<Vec<usize>> {
+    let mut neighbors = vec![vec![]; graph.num_nodes()];
+    for (tail, tail_neighbors) in neighbors.iter_mut().enumerate() {
+        let mut out_edges = graph.out_edges(tail);
+        while let Some(edge) = out_edges.next_edge() {
+            tail_neighbors.push(edge.head());
+        }
+    }
+    let reversed = graph.reversed();
+    for (head, head_neighbors) in neighbors.iter_mut().enumerate() {
+        let mut in_edges = reversed.out_edges(head);
+        while let Some(edge) = in_edges.next_edge() {
+            head_neighbors.push(edge.head());
+        }
+        head_neighbors.sort_unstable();
+        head_neighbors.dedup();
+    }
+    neighbors
+}
+
+// position of the cell (x, y) along the Hilbert curve filling the HILBERT_SIDE x HILBERT_SIDE grid
+fn hilbert_index(mut x: u64, mut y: u64) -> u64 {
+    let mut index = 0;
+    let mut s = HILBERT_SIDE / 2;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        index += s * s * ((3 * rx) ^ ry);
+        // rotate the quadrant
+        if ry == 0 {
+            if rx == 1 {
+                x = HILBERT_SIDE - 1 - x;
+                y = HILBERT_SIDE - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    index
+}
+
+const HILBERT_SIDE: u64 = 1 << 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::{
+            measure::no_measurement::NoMeasurement,
+            sd_algorithm::{PureShortestDistanceAlgorithm, ShortestDistanceAlgorithm},
+            std_pq_dijkstra::StdPqDijkstra,
+            test_utils::{random_coordinates, random_graph, weights},
+        },
+        experimentation::factors::pairs::FactorPairs,
+        graph::adjlist_jagged_vec::AdjListJaggedVec,
+    };
+    use proptest::prelude::*;
+
+    fn assert_is_permutation(permutation: &NodePermutation, num_nodes: usize) {
+        assert_eq!(permutation.num_nodes(), num_nodes);
+        let mut news: Vec<_> = (0..num_nodes)
+            .map(|old| permutation.new_of_old(old))
+            .collect();
+        news.sort();
+        assert_eq!(news, (0..num_nodes).collect::<Vec<_>>());
+        for old in 0..num_nodes {
+            assert_eq!(permutation.old_of_new(permutation.new_of_old(old)), old);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn reordered_graphs_have_same_distances(
+            seed in any::<u64>(),
+            queries_seed in any::<u64>(),
+            num_nodes in 4..120usize,
+            density in 0.005f32..0.2,
+            weights in weights(),
+        ) {
+            let graph = random_graph(seed, num_nodes, density, weights);
+            let coordinates = random_coordinates(seed, num_nodes);
+            let permutations = [
+                NodePermutation::identity(num_nodes),
+                NodePermutation::bfs(&graph),
+                NodePermutation::dfs(&graph),
+                NodePermutation::reverse_cuthill_mckee(&graph),
+                NodePermutation::hilbert(&coordinates),
+            ];
+
+            let mut dijkstra = StdPqDijkstra::new(&graph);
+            let pairs = FactorPairs { seed: queries_seed, num_pairs: 50, paths: false };
+            for permutation in permutations {
+                assert_is_permutation(&permutation, num_nodes);
+
+                let reordered_coordinates = permutation.reorder_coordinates(&coordinates);
+                for old in 0..num_nodes {
+                    let (expected, found) = (coordinates.point(old), reordered_coordinates.point(permutation.new_of_old(old)));
+                    prop_assert_eq!((expected.longitude, expected.latitude), (found.longitude, found.latitude));
+                }
+
+                let reordered: AdjListJaggedVec = permutation.reorder_graph::<_, AdjListJaggedVec>(&graph).build();
+                prop_assert_eq!(reordered.num_edges(), graph.num_edges());
+                let mut reordered_dijkstra = StdPqDijkstra::new(&reordered);
+                for (source, sink) in pairs.create_pairs(num_nodes) {
+                    let expected = dijkstra.run_cached::<NoMeasurement>(&graph, source, sink);
+                    let (new_source, new_sink) = (permutation.new_of_old(source), permutation.new_of_old(sink));
+                    let found = reordered_dijkstra.run_cached::<NoMeasurement>(&reordered, new_source, new_sink);
+                    prop_assert_eq!(expected.shortest_distance, found.shortest_distance, "{}-{}", source, sink);
+                }
+            }
+        }
+    }
+}
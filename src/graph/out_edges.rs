@@ -1,8 +1,14 @@
+use super::weight_type::WeightType;
 use crate::Weight;
 
 pub trait OutEdgeData {
+    type W: WeightType;
     fn head(&self) -> usize;
-    fn weight(&self) -> Weight;
+    fn typed_weight(&self) -> Self::W;
+    #[inline(always)]
+    fn weight(&self) -> Weight {
+        self.typed_weight().to_weight()
+    }
 }
 
 pub trait OutEdges {
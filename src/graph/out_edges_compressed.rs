@@ -0,0 +1,186 @@
+use super::{out_edges::OutEdges, out_edges_std_vec::OutEdge};
+use crate::Weight;
+
+/// Out edges of a node decoded on the fly from the byte streams of the compressed graph.
+///
+/// Heads are sorted; the first head is stored as the zigzag encoded difference to the tail
+/// and each following head as the difference to the previous head.
+pub struct OutEdgesCompressed<'a> {
+    heads: &'a [u8],
+    weights: &'a [u8],
+    previous_head: Option<usize>,
+    tail: usize,
+}
+impl<'a> OutEdgesCompressed<'a> {
+    pub fn new(tail: usize, heads: &'a [u8], weights: &'a [u8]) -> Self {
+        Self {
+            heads,
+            weights,
+            previous_head: None,
+            tail,
+        }
+    }
+}
+
+impl OutEdges for OutEdgesCompressed<'_> {
+    type Edge = OutEdge;
+    #[inline(always)]
+    fn next_edge(&mut self) -> Option<Self::Edge> {
+        if self.heads.is_empty() {
+            return None;
+        }
+        let delta = read_varint(&mut self.heads);
+        let head = match self.previous_head {
+            Some(previous) => previous + delta as usize,
+            None => (self.tail as i64 + unzigzag(delta)) as usize,
+        };
+        self.previous_head = Some(head);
+        let weight = decode_weight(read_varint(&mut self.weights));
+        Some(OutEdge::new(head, weight))
+    }
+    fn count_edges(&mut self) -> usize {
+        // every varint ends with the only byte of it having the continuation bit unset
+        self.heads
+            .iter()
+            .filter(|&&byte| byte < CONTINUATION)
+            .count()
+    }
+}
+
+// encoding
+pub fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= CONTINUATION as u64 {
+        bytes.push(value as u8 | CONTINUATION);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+#[inline(always)]
+fn read_varint(bytes: &mut &[u8]) -> u64 {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = bytes[0];
+        *bytes = &bytes[1..];
+        value |= ((byte & !CONTINUATION) as u64) << shift;
+        if byte < CONTINUATION {
+            return value;
+        }
+        shift += 7;
+    }
+}
+
+pub fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+#[inline(always)]
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+#[cfg(not(feature = "signed-weights"))]
+pub fn encode_weight(weight: Weight) -> u64 {
+    weight
+}
+#[cfg(feature = "signed-weights")]
+pub fn encode_weight(weight: Weight) -> u64 {
+    zigzag(weight)
+}
+#[cfg(not(feature = "signed-weights"))]
+#[inline(always)]
+fn decode_weight(value: u64) -> Weight {
+    value
+}
+#[cfg(feature = "signed-weights")]
+#[inline(always)]
+fn decode_weight(value: u64) -> Weight {
+    unzigzag(value)
+}
+
+const CONTINUATION: u8 = 0x80;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::out_edges::OutEdgeData;
+    use proptest::prelude::*;
+
+    fn varint(value: u64) -> Vec<u8> {
+        let mut bytes = vec![];
+        write_varint(&mut bytes, value);
+        bytes
+    }
+
+    #[test]
+    fn varint_bytes() {
+        assert_eq!(varint(0), [0x00]);
+        assert_eq!(varint(127), [0x7f]);
+        assert_eq!(varint(128), [0x80, 0x01]);
+        assert_eq!(varint(300), [0xac, 0x02]);
+        assert_eq!(varint(u64::MAX).len(), 10);
+        assert_eq!(varint(u64::MAX)[9], 0x01);
+    }
+
+    #[test]
+    fn zigzag_interleaves_signs() {
+        let values = [0, -1, 1, -2, 2, i64::MAX, i64::MIN];
+        let encoded = [0, 1, 2, 3, 4, u64::MAX - 1, u64::MAX];
+        for (value, encoded) in values.into_iter().zip(encoded) {
+            assert_eq!(zigzag(value), encoded);
+            assert_eq!(unzigzag(encoded), value);
+        }
+    }
+
+    #[test]
+    fn out_edges_decode_heads_around_tail() {
+        // heads below & above the tail, with a gap needing a multi-byte varint
+        let (tail, edges) = (500, [(3, 7), (499, 0), (500, 1), (100_000, 128)]);
+        let (mut heads, mut weights) = (vec![], vec![]);
+        let mut previous_head = None;
+        for (head, weight) in edges {
+            let delta = match previous_head {
+                Some(previous) => (head - previous) as u64,
+                None => zigzag(head as i64 - tail as i64),
+            };
+            write_varint(&mut heads, delta);
+            write_varint(&mut weights, encode_weight(weight));
+            previous_head = Some(head);
+        }
+
+        assert_eq!(
+            OutEdgesCompressed::new(tail, &heads, &weights).count_edges(),
+            4
+        );
+        let mut out_edges = OutEdgesCompressed::new(tail, &heads, &weights);
+        for (head, weight) in edges {
+            let edge = out_edges.next_edge().expect("4 edges are encoded");
+            assert_eq!((edge.head(), edge.weight()), (head, weight));
+        }
+        assert!(out_edges.next_edge().is_none());
+    }
+
+    proptest! {
+        #[test]
+        fn varints_round_trip(values in prop::collection::vec(any::<u64>(), 0..100)) {
+            let mut bytes = vec![];
+            for &value in &values {
+                write_varint(&mut bytes, value);
+            }
+            let mut stream = bytes.as_slice();
+            for &value in &values {
+                prop_assert_eq!(read_varint(&mut stream), value);
+            }
+            prop_assert!(stream.is_empty());
+        }
+
+        #[test]
+        fn zigzag_round_trips(value in any::<i64>()) {
+            prop_assert_eq!(unzigzag(zigzag(value)), value);
+        }
+
+        #[test]
+        fn weights_round_trip(weight in any::<Weight>()) {
+            prop_assert_eq!(decode_weight(encode_weight(weight)), weight);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use super::out_edges::{OutEdgeData, OutEdges};
+use crate::Weight;
+use std::slice::Iter;
+
+/// Weight stored in the weights array of the structure-of-arrays CSR graph;
+/// 32-bit weights halve the memory of the array while 64-bit weights hold any `Weight`.
+pub trait CsrWeight: Copy + Into<Weight> + TryFrom<Weight> + 'static {
+    const GRAPH_NAME: &'static str;
+}
+
+#[cfg(not(feature = "signed-weights"))]
+pub type CsrWeight32 = u32;
+#[cfg(feature = "signed-weights")]
+pub type CsrWeight32 = i32;
+pub type CsrWeight64 = Weight;
+
+impl CsrWeight for CsrWeight32 {
+    const GRAPH_NAME: &'static str = "AdjListCsr";
+}
+impl CsrWeight for CsrWeight64 {
+    const GRAPH_NAME: &'static str = "AdjListCsr64";
+}
+
+pub struct OutEdgesCsr<'a, C: CsrWeight> {
+    heads: Iter<'a, u32>,
+    weights: Iter<'a, C>,
+}
+impl<'a, C: CsrWeight> OutEdgesCsr<'a, C> {
+    pub fn new(heads: &'a [u32], weights: &'a [C]) -> Self {
+        Self {
+            heads: heads.iter(),
+            weights: weights.iter(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct OutEdgeCsr<C: CsrWeight> {
+    head: u32,
+    weight: C,
+}
+impl<C: CsrWeight> OutEdgeData for OutEdgeCsr<C> {
+    type W = Weight;
+    #[inline(always)]
+    fn head(&self) -> usize {
+        self.head as usize
+    }
+    #[inline(always)]
+    fn typed_weight(&self) -> Weight {
+        self.weight.into()
+    }
+}
+
+impl<'a, C: CsrWeight> OutEdges for OutEdgesCsr<'a, C> {
+    type Edge = OutEdgeCsr<C>;
+    #[inline(always)]
+    fn next_edge(&mut self) -> Option<Self::Edge> {
+        match (self.heads.next(), self.weights.next()) {
+            (Some(&head), Some(&weight)) => Some(OutEdgeCsr { head, weight }),
+            _ => None,
+        }
+    }
+    fn count_edges(&mut self) -> usize {
+        self.heads.len()
+    }
+}
@@ -1,18 +1,25 @@
 use super::out_edges::{OutEdgeData, OutEdges};
 use crate::Weight;
 use petgraph::{
+    csr,
     graph::{EdgeReference, Edges},
+    graphmap, stable_graph,
     visit::EdgeRef,
     Directed,
 };
 
 pub type OutEdgesPetgraph<'a> = Edges<'a, Weight, Directed>;
+pub type OutEdgesPetgraphStable<'a> = stable_graph::Edges<'a, Weight, Directed>;
+pub type OutEdgesPetgraphMap<'a> = graphmap::Edges<'a, usize, Weight, Directed>;
+pub type OutEdgesPetgraphCsr<'a> = csr::Edges<'a, Weight, Directed>;
 
+// graph
 impl<'a> OutEdgeData for EdgeReference<'a, Weight> {
+    type W = Weight;
     fn head(&self) -> usize {
         self.target().index()
     }
-    fn weight(&self) -> Weight {
+    fn typed_weight(&self) -> Weight {
         *self.weight()
     }
 }
@@ -26,3 +33,66 @@ impl<'a> OutEdges for OutEdgesPetgraph<'a> {
         self.count()
     }
 }
+
+// stable graph
+impl<'a> OutEdgeData for stable_graph::EdgeReference<'a, Weight> {
+    type W = Weight;
+    fn head(&self) -> usize {
+        self.target().index()
+    }
+    fn typed_weight(&self) -> Weight {
+        *self.weight()
+    }
+}
+
+impl<'a> OutEdges for OutEdgesPetgraphStable<'a> {
+    type Edge = stable_graph::EdgeReference<'a, Weight>;
+    fn next_edge(&mut self) -> Option<Self::Edge> {
+        self.next()
+    }
+    fn count_edges(&mut self) -> usize {
+        self.count()
+    }
+}
+
+// graph map
+impl OutEdgeData for (usize, usize, &Weight) {
+    type W = Weight;
+    fn head(&self) -> usize {
+        self.1
+    }
+    fn typed_weight(&self) -> Weight {
+        *self.2
+    }
+}
+
+impl<'a> OutEdges for OutEdgesPetgraphMap<'a> {
+    type Edge = (usize, usize, &'a Weight);
+    fn next_edge(&mut self) -> Option<Self::Edge> {
+        self.next()
+    }
+    fn count_edges(&mut self) -> usize {
+        self.count()
+    }
+}
+
+// csr
+impl<'a> OutEdgeData for csr::EdgeReference<'a, Weight, Directed> {
+    type W = Weight;
+    fn head(&self) -> usize {
+        self.target() as usize
+    }
+    fn typed_weight(&self) -> Weight {
+        *self.weight()
+    }
+}
+
+impl<'a> OutEdges for OutEdgesPetgraphCsr<'a> {
+    type Edge = csr::EdgeReference<'a, Weight, Directed>;
+    fn next_edge(&mut self) -> Option<Self::Edge> {
+        self.next()
+    }
+    fn count_edges(&mut self) -> usize {
+        self.count()
+    }
+}
@@ -1,26 +1,30 @@
-use super::out_edges::{OutEdgeData, OutEdges};
+use super::{
+    out_edges::{OutEdgeData, OutEdges},
+    weight_type::WeightType,
+};
 use crate::Weight;
 
-pub type OutEdgesStdVec<'a> = std::slice::Iter<'a, OutEdge>;
+pub type OutEdgesStdVec<'a, W = Weight> = std::slice::Iter<'a, OutEdge<W>>;
 
 #[derive(Clone, derive_new::new)]
-pub struct OutEdge {
+pub struct OutEdge<W = Weight> {
     head: usize,
-    weight: Weight,
+    weight: W,
 }
-impl OutEdgeData for OutEdge {
+impl<W: WeightType> OutEdgeData for OutEdge<W> {
+    type W = W;
     #[inline(always)]
     fn head(&self) -> usize {
         self.head
     }
     #[inline(always)]
-    fn weight(&self) -> Weight {
+    fn typed_weight(&self) -> W {
         self.weight
     }
 }
 
-impl<'a> OutEdges for OutEdgesStdVec<'a> {
-    type Edge = OutEdge;
+impl<'a, W: WeightType> OutEdges for OutEdgesStdVec<'a, W> {
+    type Edge = OutEdge<W>;
     fn next_edge(&mut self) -> Option<Self::Edge> {
         self.next().cloned()
     }
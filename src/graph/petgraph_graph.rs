@@ -0,0 +1,24 @@
+use super::sp_graph::SpGraph;
+use crate::Weight;
+use petgraph::visit::{IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable, Visitable};
+use std::hash::Hash;
+
+/// Graph representation backed by a petgraph graph type such that the algorithms of `petgraph::algo` can be run on it;
+/// petgraph node ids are mapped to & from the node indices of the `SpGraph` by `NodeIndexable`.
+///
+/// `petgraph::algo::bellman_ford` requires floating point weights; hence, it runs on a copy of the graph
+/// with `f64` weights in the same petgraph type.
+pub trait PetgraphGraph: SpGraph {
+    type Petgraph<'a>: IntoEdges<EdgeWeight = Weight, NodeId: Eq + Hash> + Visitable + NodeIndexable
+    where
+        Self: 'a;
+    type FloatWeighted: 'static;
+    type FloatPetgraph<'a>: IntoEdges<EdgeWeight = f64>
+        + IntoNodeIdentifiers
+        + NodeCount
+        + NodeIndexable;
+
+    fn petgraph(&self) -> Self::Petgraph<'_>;
+    fn float_weighted(&self) -> Self::FloatWeighted;
+    fn float_petgraph(graph: &Self::FloatWeighted) -> Self::FloatPetgraph<'_>;
+}
@@ -0,0 +1,71 @@
+use super::{
+    out_edges_std_vec::{OutEdge, OutEdgesStdVec},
+    resource_graph::ResourceGraph,
+    resource_graph_builder::ResourceGraphBuilder,
+    sp_graph::SpGraph,
+};
+use crate::Weight;
+
+/// Flat adjacency list where the resources are kept parallel to the out edges.
+pub struct ResourceAdjList {
+    edges: Vec<OutEdge>,
+    resources: Vec<Weight>,
+    out_edges_begin_index: Vec<usize>,
+}
+
+impl SpGraph for ResourceAdjList {
+    type W = Weight;
+    type OutEdges<'a> = OutEdgesStdVec<'a>;
+    fn name() -> &'static str {
+        "ResourceAdjList"
+    }
+    fn num_nodes(&self) -> usize {
+        self.out_edges_begin_index.len() - 1
+    }
+    fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
+        let begin = self.out_edges_begin_index[node];
+        let end = self.out_edges_begin_index[node + 1];
+        self.edges[begin..end].iter()
+    }
+}
+impl ResourceGraph for ResourceAdjList {
+    type ResourceOutEdges<'a> = std::iter::Zip<OutEdgesStdVec<'a>, std::slice::Iter<'a, Weight>>;
+    fn resource_out_edges(&self, node: usize) -> Self::ResourceOutEdges<'_> {
+        let begin = self.out_edges_begin_index[node];
+        let end = self.out_edges_begin_index[node + 1];
+        self.edges[begin..end]
+            .iter()
+            .zip(self.resources[begin..end].iter())
+    }
+}
+
+pub struct ResourceAdjListBuilder(Vec<Vec<(OutEdge, Weight)>>);
+impl ResourceGraphBuilder for ResourceAdjListBuilder {
+    type G = ResourceAdjList;
+
+    fn new(nodes_capacity: Option<usize>, _edges_capacity: Option<usize>) -> Self {
+        Self(Vec::with_capacity(nodes_capacity.unwrap_or(0)))
+    }
+    fn add_node(&mut self, node: usize, out_degree_capacity: Option<usize>) {
+        assert_eq!(node, self.0.len());
+        self.0
+            .push(Vec::with_capacity(out_degree_capacity.unwrap_or(0)));
+    }
+    fn add_edge(&mut self, tail: usize, head: usize, weight: Weight, resource: Weight) {
+        self.0[tail].push((OutEdge::new(head, weight), resource));
+    }
+    fn build(self) -> Self::G {
+        let mut cumulative = 0;
+        let mut out_edges_begin_index = vec![cumulative];
+        for out_edges in &self.0 {
+            cumulative += out_edges.len();
+            out_edges_begin_index.push(cumulative);
+        }
+        let (edges, resources) = self.0.into_iter().flatten().unzip();
+        Self::G {
+            edges,
+            resources,
+            out_edges_begin_index,
+        }
+    }
+}
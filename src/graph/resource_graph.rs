@@ -0,0 +1,11 @@
+use super::{out_edges_std_vec::OutEdge, sp_graph::SpGraph};
+use crate::Weight;
+
+/// Graph whose edges carry a second resource, such as toll or fuel, in addition to their weights.
+pub trait ResourceGraph: SpGraph {
+    type ResourceOutEdges<'a>: Iterator<Item = (&'a OutEdge, &'a Weight)>
+    where
+        Self: 'a;
+
+    fn resource_out_edges(&self, node: usize) -> Self::ResourceOutEdges<'_>;
+}
@@ -0,0 +1,12 @@
+use super::resource_graph::ResourceGraph;
+use crate::Weight;
+
+pub trait ResourceGraphBuilder {
+    type G: ResourceGraph;
+
+    fn new(nodes_capacity: Option<usize>, edges_capacity: Option<usize>) -> Self;
+    fn add_node(&mut self, node: usize, out_degree_capacity: Option<usize>);
+    fn add_edge(&mut self, tail: usize, head: usize, weight: Weight, resource: Weight);
+
+    fn build(self) -> Self::G;
+}
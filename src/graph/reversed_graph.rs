@@ -0,0 +1,67 @@
+use super::{
+    out_edges::{OutEdgeData, OutEdges},
+    out_edges_std_vec::{OutEdge, OutEdgesStdVec},
+    sp_graph::SpGraph,
+};
+use crate::Weight;
+
+/// Graph where every edge of the original graph is reversed;
+/// out edges of a node in the reversed graph are the in edges of the node in the original graph.
+pub struct ReversedGraph {
+    edges: Vec<OutEdge>,
+    in_edges_begin_index: Vec<usize>,
+}
+
+impl ReversedGraph {
+    pub fn new<G: SpGraph>(graph: &G) -> Self {
+        let num_nodes = graph.num_nodes();
+
+        let mut in_degrees = vec![0; num_nodes];
+        for tail in 0..num_nodes {
+            let mut out_edges = graph.out_edges(tail);
+            while let Some(edge) = out_edges.next_edge() {
+                in_degrees[edge.head()] += 1;
+            }
+        }
+
+        let mut cumulative = 0;
+        let mut in_edges_begin_index = Vec::with_capacity(num_nodes + 1);
+        in_edges_begin_index.push(cumulative);
+        for in_degree in &in_degrees {
+            cumulative += in_degree;
+            in_edges_begin_index.push(cumulative);
+        }
+
+        let mut next_position = in_edges_begin_index.clone();
+        let mut edges = vec![OutEdge::new(0, 0); cumulative];
+        for tail in 0..num_nodes {
+            let mut out_edges = graph.out_edges(tail);
+            while let Some(edge) = out_edges.next_edge() {
+                let head = edge.head();
+                edges[next_position[head]] = OutEdge::new(tail, edge.weight());
+                next_position[head] += 1;
+            }
+        }
+
+        Self {
+            edges,
+            in_edges_begin_index,
+        }
+    }
+}
+
+impl SpGraph for ReversedGraph {
+    type W = Weight;
+    type OutEdges<'a> = OutEdgesStdVec<'a>;
+    fn name() -> &'static str {
+        "ReversedGraph"
+    }
+    fn num_nodes(&self) -> usize {
+        self.in_edges_begin_index.len() - 1
+    }
+    fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
+        let begin = self.in_edges_begin_index[node];
+        let end = self.in_edges_begin_index[node + 1];
+        self.edges[begin..end].iter()
+    }
+}
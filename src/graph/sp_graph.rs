@@ -1,8 +1,16 @@
-use super::out_edges::OutEdges;
-use crate::utils::log_debug::LogDebug;
+use super::{
+    out_edges::{OutEdgeData, OutEdges},
+    reversed_graph::ReversedGraph,
+    weight_type::WeightType,
+};
+use crate::{utils::log_debug::LogDebug, Weight};
 
 pub trait SpGraph: LogDebug {
-    type OutEdges<'a>: OutEdges where where Self:'a;
+    /// Type of the edge weights; the crate wide `Weight` unless the graph is built with another weight type.
+    type W: WeightType;
+    type OutEdges<'a>: OutEdges<Edge: OutEdgeData<W = Self::W>>
+    where
+        Self: 'a;
 
     // type
     fn name() -> &'static str;
@@ -17,4 +25,25 @@ pub trait SpGraph: LogDebug {
             .map(|i| self.out_edges(i).count_edges())
             .sum()
     }
+    /// Largest edge weight of the graph; zero for a graph without edges.
+    fn max_weight(&self) -> Weight {
+        let mut max_weight = Weight::default();
+        for node in 0..self.num_nodes() {
+            let mut out_edges = self.out_edges(node);
+            while let Some(edge) = out_edges.next_edge() {
+                max_weight = max_weight.max(edge.weight());
+            }
+        }
+        max_weight
+    }
+    /// Bytes of the nodes & edges of the graph; None if the representation does not report it.
+    fn memory_bytes(&self) -> Option<usize> {
+        None
+    }
+    fn reversed(&self) -> ReversedGraph
+    where
+        Self: Sized,
+    {
+        ReversedGraph::new(self)
+    }
 }
@@ -8,5 +8,10 @@ pub trait SpGraphBuilder {
     fn add_node(&mut self, node: usize, out_degree_capacity: Option<usize>);
     fn add_edge(&mut self, tail: usize, head: usize, weight: Weight);
 
+    /// Checks whether the added nodes & edges can be represented by the graph to be built;
+    /// the treatment is not completed with the returned mismatch otherwise.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
     fn build(self) -> Self::G;
 }
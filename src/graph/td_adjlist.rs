@@ -0,0 +1,78 @@
+use super::{
+    out_edges_std_vec::{OutEdge, OutEdgesStdVec},
+    sp_graph::SpGraph,
+    td_graph::TdGraph,
+    td_graph_builder::TdGraphBuilder,
+    travel_time::TravelTimeFunction,
+};
+use crate::Weight;
+
+/// Flat adjacency list where the travel time functions are kept parallel to the static out edges.
+pub struct TdAdjList {
+    edges: Vec<OutEdge>,
+    travel_times: Vec<TravelTimeFunction>,
+    out_edges_begin_index: Vec<usize>,
+}
+
+impl SpGraph for TdAdjList {
+    type W = Weight;
+    type OutEdges<'a> = OutEdgesStdVec<'a>;
+    fn name() -> &'static str {
+        "TdAdjList"
+    }
+    fn num_nodes(&self) -> usize {
+        self.out_edges_begin_index.len() - 1
+    }
+    fn out_edges(&self, node: usize) -> Self::OutEdges<'_> {
+        let begin = self.out_edges_begin_index[node];
+        let end = self.out_edges_begin_index[node + 1];
+        self.edges[begin..end].iter()
+    }
+}
+impl TdGraph for TdAdjList {
+    type TdOutEdges<'a> =
+        std::iter::Zip<OutEdgesStdVec<'a>, std::slice::Iter<'a, TravelTimeFunction>>;
+    fn td_out_edges(&self, node: usize) -> Self::TdOutEdges<'_> {
+        let begin = self.out_edges_begin_index[node];
+        let end = self.out_edges_begin_index[node + 1];
+        self.edges[begin..end]
+            .iter()
+            .zip(self.travel_times[begin..end].iter())
+    }
+}
+
+pub struct TdAdjListBuilder(Vec<Vec<(usize, TravelTimeFunction)>>);
+impl TdGraphBuilder for TdAdjListBuilder {
+    type G = TdAdjList;
+
+    fn new(nodes_capacity: Option<usize>, _edges_capacity: Option<usize>) -> Self {
+        Self(Vec::with_capacity(nodes_capacity.unwrap_or(0)))
+    }
+    fn add_node(&mut self, node: usize, out_degree_capacity: Option<usize>) {
+        assert_eq!(node, self.0.len());
+        self.0
+            .push(Vec::with_capacity(out_degree_capacity.unwrap_or(0)));
+    }
+    fn add_edge(&mut self, tail: usize, head: usize, travel_time: TravelTimeFunction) {
+        self.0[tail].push((head, travel_time));
+    }
+    fn build(self) -> Self::G {
+        let mut cumulative = 0;
+        let mut out_edges_begin_index = vec![cumulative];
+        for out_edges in &self.0 {
+            cumulative += out_edges.len();
+            out_edges_begin_index.push(cumulative);
+        }
+        let (edges, travel_times) = self
+            .0
+            .into_iter()
+            .flatten()
+            .map(|(head, f)| (OutEdge::new(head, f.min_travel_time()), f))
+            .unzip();
+        Self::G {
+            edges,
+            travel_times,
+            out_edges_begin_index,
+        }
+    }
+}
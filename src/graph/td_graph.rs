@@ -0,0 +1,11 @@
+use super::{out_edges_std_vec::OutEdge, sp_graph::SpGraph, travel_time::TravelTimeFunction};
+
+/// Graph with time-dependent travel times on its edges;
+/// as a static `SpGraph` the weight of every edge is its minimum travel time.
+pub trait TdGraph: SpGraph {
+    type TdOutEdges<'a>: Iterator<Item = (&'a OutEdge, &'a TravelTimeFunction)>
+    where
+        Self: 'a;
+
+    fn td_out_edges(&self, node: usize) -> Self::TdOutEdges<'_>;
+}
@@ -0,0 +1,37 @@
+use super::{
+    out_edges::{OutEdgeData, OutEdges},
+    sp_graph::SpGraph,
+    td_graph::TdGraph,
+    travel_time::TravelTimeFunction,
+};
+use crate::Weight;
+
+pub trait TdGraphBuilder {
+    type G: TdGraph;
+
+    fn new(nodes_capacity: Option<usize>, edges_capacity: Option<usize>) -> Self;
+    fn add_node(&mut self, node: usize, out_degree_capacity: Option<usize>);
+    fn add_edge(&mut self, tail: usize, head: usize, travel_time: TravelTimeFunction);
+
+    fn build(self) -> Self::G;
+
+    /// Builder with the edges of the static graph, where the travel time function of each edge is created from its weight.
+    fn from_static_graph<G, F>(graph: &G, mut travel_time: F) -> Self
+    where
+        Self: Sized,
+        G: SpGraph,
+        F: FnMut(Weight) -> TravelTimeFunction,
+    {
+        let mut builder = Self::new(Some(graph.num_nodes()), Some(graph.num_edges()));
+        for node in 0..graph.num_nodes() {
+            builder.add_node(node, Some(graph.out_edges(node).count_edges()));
+        }
+        for tail in 0..graph.num_nodes() {
+            let mut out_edges = graph.out_edges(tail);
+            while let Some(edge) = out_edges.next_edge() {
+                builder.add_edge(tail, edge.head(), travel_time(edge.weight()));
+            }
+        }
+        builder
+    }
+}
@@ -0,0 +1,69 @@
+use crate::Weight;
+
+/// Length of the period of the travel time functions, a day in seconds;
+/// departure times are taken modulo the period.
+pub const PERIOD: Weight = 86_400;
+
+/// Periodic piecewise-linear travel time function given by its breakpoints `(departure time, travel time)`,
+/// sorted by departure time within `[0, PERIOD)` and starting at time 0;
+/// the last breakpoint is interpolated towards the first one of the next period.
+///
+/// Functions satisfy the FIFO property: a later departure never arrives earlier,
+/// which holds if no segment decreases faster than a slope of -1.
+#[derive(Clone, Debug)]
+pub struct TravelTimeFunction {
+    points: Vec<(Weight, Weight)>,
+}
+
+impl TravelTimeFunction {
+    pub fn new(points: Vec<(Weight, Weight)>) -> Self {
+        assert!(points.first().is_some_and(|&(time, _)| time == 0));
+        assert!(points.windows(2).all(|x| x[0].0 < x[1].0));
+        assert!(points.last().is_some_and(|&(time, _)| time < PERIOD));
+        let function = Self { points };
+        assert!(function.is_fifo(), "travel time function violates FIFO");
+        function
+    }
+    pub fn constant(travel_time: Weight) -> Self {
+        Self {
+            points: vec![(0, travel_time)],
+        }
+    }
+
+    /// Travel time when departing at the given time.
+    pub fn travel_time(&self, departure_time: Weight) -> Weight {
+        let time = departure_time.rem_euclid(PERIOD);
+        let i = self.points.partition_point(|&(t, _)| t <= time) - 1;
+        let (t0, f0) = self.points[i];
+        let (t1, f1) = match self.points.get(i + 1) {
+            Some(&next) => next,
+            None => (PERIOD, self.points[0].1),
+        };
+        let (elapsed, length) = (time - t0, t1 - t0);
+        match f1 >= f0 {
+            true => f0 + (f1 - f0) * elapsed / length,
+            false => f0 - (f0 - f1) * elapsed / length,
+        }
+    }
+    /// Minimum travel time over the period, attained at a breakpoint; a lower bound for any departure.
+    pub fn min_travel_time(&self) -> Weight {
+        self.points
+            .iter()
+            .map(|&(_, f)| f)
+            .min()
+            .expect("at least one breakpoint")
+    }
+
+    fn is_fifo(&self) -> bool {
+        let next_period = (PERIOD, self.points[0].1);
+        self.points
+            .iter()
+            .zip(
+                self.points
+                    .iter()
+                    .skip(1)
+                    .chain(std::iter::once(&next_period)),
+            )
+            .all(|(&(t0, f0), &(t1, f1))| f1 + (t1 - t0) >= f0)
+    }
+}
@@ -0,0 +1,127 @@
+use super::{
+    out_edges::{OutEdgeData, OutEdges},
+    sp_graph::SpGraph,
+};
+use crate::Weight;
+use std::{cmp::Ordering, fmt::Debug};
+
+/// Type of the edge weights, distances & queue keys of a graph.
+///
+/// Graph data is always loaded with the crate wide `Weight`; graph builders convert the weights once
+/// and the distances are converted back when they are reported to the measures.
+/// The largest value, `INFINITY`, is reserved for unreached nodes.
+pub trait WeightType: Copy + Ord + Default + Debug + Send + Sync + 'static {
+    const ZERO: Self;
+    const INFINITY: Self;
+    fn name() -> &'static str;
+    /// Sum of two weights; None if the sum overflows the exactly representable range of the type.
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Returns None if the weight is not exactly representable by the type.
+    fn try_from_weight(weight: Weight) -> Option<Self>;
+    /// Floating point weights are rounded to the nearest integer; `INFINITY` is mapped to `Weight::MAX`.
+    fn to_weight(self) -> Weight;
+}
+
+// integers
+macro_rules! impl_integer_weight_type {
+    ($t:ty, $name:literal) => {
+        impl WeightType for $t {
+            const ZERO: Self = 0;
+            const INFINITY: Self = <$t>::MAX;
+            fn name() -> &'static str {
+                $name
+            }
+            #[inline(always)]
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other).filter(|x| *x != Self::INFINITY)
+            }
+            fn try_from_weight(weight: Weight) -> Option<Self> {
+                <$t>::try_from(weight).ok().filter(|x| *x != Self::INFINITY)
+            }
+            #[inline(always)]
+            fn to_weight(self) -> Weight {
+                match self {
+                    Self::INFINITY => Weight::MAX,
+                    x => x as Weight,
+                }
+            }
+        }
+    };
+}
+impl_integer_weight_type!(u32, "u32");
+impl_integer_weight_type!(u64, "u64");
+impl_integer_weight_type!(i64, "i64");
+
+// floats
+macro_rules! impl_float_weight_type {
+    ($wrapper:ident, $t:ty, $name:literal, $mantissa_digits:expr) => {
+        /// Floating point weight totally ordered by `total_cmp`; weights are never NaN or negative zero
+        /// since they are converted from integers.
+        #[derive(Clone, Copy, Debug, Default)]
+        pub struct $wrapper(pub $t);
+
+        impl PartialEq for $wrapper {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+        impl Eq for $wrapper {}
+        impl PartialOrd for $wrapper {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for $wrapper {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl WeightType for $wrapper {
+            const ZERO: Self = Self(0.0);
+            const INFINITY: Self = Self(<$t>::INFINITY);
+            fn name() -> &'static str {
+                $name
+            }
+            /// Sums beyond 2^MANTISSA_DIGITS are not exact integers anymore; hence, they are treated as overflow.
+            #[inline(always)]
+            fn checked_add(self, other: Self) -> Option<Self> {
+                let sum = self.0 + other.0;
+                (sum <= (1u64 << $mantissa_digits) as $t).then_some(Self(sum))
+            }
+            fn try_from_weight(weight: Weight) -> Option<Self> {
+                let value = weight as $t;
+                (value.is_finite() && value as Weight == weight).then_some(Self(value))
+            }
+            #[inline(always)]
+            fn to_weight(self) -> Weight {
+                match self.0.is_finite() {
+                    true => self.0.round() as Weight,
+                    false => Weight::MAX,
+                }
+            }
+        }
+    };
+}
+impl_float_weight_type!(TotalF32, f32, "f32", f32::MANTISSA_DIGITS);
+impl_float_weight_type!(TotalF64, f64, "f64", f64::MANTISSA_DIGITS);
+
+/// Fails with a description of the first edge of the graph whose weight is not exactly representable by `W`.
+pub fn check_weights<W: WeightType, G: SpGraph>(graph: &G) -> Result<(), String> {
+    for tail in 0..graph.num_nodes() {
+        let mut out_edges = graph.out_edges(tail);
+        while let Some(edge) = out_edges.next_edge() {
+            if W::try_from_weight(edge.weight()).is_none() {
+                return Err(format!(
+                    "weight {} of the edge ({}, {}) does not fit into the weight type {}",
+                    edge.weight(),
+                    tail,
+                    edge.head(),
+                    W::name()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
@@ -8,7 +8,10 @@ mod graph;
 mod operations;
 mod utils;
 
+#[cfg(not(feature = "signed-weights"))]
 pub type Weight = u64;
+#[cfg(feature = "signed-weights")]
+pub type Weight = i64;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
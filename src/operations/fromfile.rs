@@ -10,7 +10,7 @@ use crate::{
         },
         validation::Validation,
     },
-    experimentation::measurement::Measurement,
+    experimentation::{experiment::Experiment, measurement::Measurement},
     utils,
 };
 
@@ -38,7 +38,14 @@ impl Operation for FromFile {
             .to_str()
             .unwrap();
 
-        let experiment = utils::fs::read_json(&filepath);
+        let experiment: Experiment = utils::fs::read_json(&filepath);
+        if let Err(e) = experiment.validate() {
+            panic!(
+                "invalid experiment file '{}': {}",
+                filepath.to_str().unwrap(),
+                e
+            );
+        }
         utils::cli::print_experiment(&experiment);
         match experiment.measurement {
             Measurement::Time => experiment.run_fromfile::<TreatmentTime, Time>(name),
@@ -236,9 +236,15 @@ pub fn print_experiment(experiment: &Experiment) {
         &mut level_cols,
         &experiment.graph_representation,
     );
+    split(&mut constants, &mut level_cols, &experiment.node_ordering);
+    split(&mut constants, &mut level_cols, &experiment.weight_type);
     split(&mut constants, &mut level_cols, &experiment.algorithm);
+    split(&mut constants, &mut level_cols, &experiment.partition_size);
+    split(&mut constants, &mut level_cols, &experiment.num_paths);
+    split(&mut constants, &mut level_cols, &experiment.delta);
+    split(&mut constants, &mut level_cols, &experiment.num_threads);
     split(&mut constants, &mut level_cols, &experiment.algorithm_data);
-    split(&mut constants, &mut level_cols, &experiment.pairs);
+    split(&mut constants, &mut level_cols, &experiment.queries);
 
     print_header(None, &format!("EXPERIMENT - {:?}", experiment.measurement));
     print_definition_under_header(&format!(